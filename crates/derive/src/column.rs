@@ -1,24 +1,27 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use darling::{FromDeriveInput, FromField, Result, ast, util::Flag};
+use darling::{
+    FromDeriveInput, Result, ast,
+    util::{Flag, Override},
+};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Expr, Generics, Ident, Type, parse_quote};
 
 use crate::common::{
-    BindingMode, FieldIdentity, NamedIndexResolution, With, impl_generics_with_lifetime,
-    process_fields,
+    BindingMode, EnumRepresentation, FieldDerive, FieldIdentity, NamedIndexResolution,
+    VariantDerive, impl_generics_with_lifetime, process_fields, validate_enum_flags,
 };
 
 #[derive(FromDeriveInput, Debug)]
 #[darling(
     attributes(squire),
-    supports(struct_named, struct_newtype, struct_tuple)
+    supports(struct_named, struct_newtype, struct_tuple, enum_unit)
 )]
 pub struct ColumnsDerive {
     ident: Ident,
     generics: Generics,
-    data: ast::Data<(), FieldDerive>,
+    data: ast::Data<VariantDerive, FieldDerive<i32>>,
 
     named: Flag,
     sequential: Flag,
@@ -26,6 +29,11 @@ pub struct ColumnsDerive {
 
 impl ColumnsDerive {
     pub fn derive(self) -> Result<TokenStream> {
+        if let ast::Data::Enum(ref variants) = self.data {
+            validate_enum_flags(&self.named, &self.sequential)?;
+            return Ok(self.derive_enum(variants));
+        }
+
         // Step 1: Extract and validate fields
         let (fields, style) = self.fields()?;
 
@@ -46,7 +54,7 @@ impl ColumnsDerive {
         meta.generate_impl()
     }
 
-    fn fields(&self) -> Result<(Vec<&FieldDerive>, ast::Style)> {
+    fn fields(&self) -> Result<(Vec<&FieldDerive<i32>>, ast::Style)> {
         match &self.data {
             ast::Data::Struct(contents) => match contents.style {
                 ast::Style::Struct | ast::Style::Tuple => {
@@ -62,26 +70,63 @@ impl ColumnsDerive {
             ast::Data::Enum(_) => Err(darling::Error::unsupported_shape("enum")),
         }
     }
-}
 
-#[derive(FromField, Debug)]
-#[darling(attributes(squire))]
-struct FieldDerive {
-    ident: Option<Ident>,
-    ty: Type,
-
-    borrow: Flag,
-    index: Option<i32>,
-    rename: Option<Ident>,
-    skip: Flag,
-    result: Flag,
-    fetch_with: Option<With>,
-    json: Flag,
-    jsonb: Flag,
+    /// Implement `Fetch` for a C-like enum, which also makes it `Columns`.
+    fn derive_enum(&self, variants: &[VariantDerive]) -> TokenStream {
+        let ident = &self.ident;
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let impl_generics = impl_generics_with_lifetime(&self.generics, "'row");
+
+        let (value_type, scrutinee, arms): (_, _, Vec<_>) =
+            match EnumRepresentation::from_variants(variants) {
+                EnumRepresentation::Integer => (
+                    quote!(i64),
+                    quote!(value),
+                    variants
+                        .iter()
+                        .map(|variant| {
+                            let name = &variant.ident;
+                            quote! { value if value == Self::#name as i64 => Ok(Self::#name), }
+                        })
+                        .collect(),
+                ),
+                EnumRepresentation::Text => (
+                    quote!(squire::Borrowed<'row, str>),
+                    quote!(&*value),
+                    variants
+                        .iter()
+                        .map(|variant| {
+                            let name = &variant.ident;
+                            let text = variant.text();
+                            quote! { #text => Ok(Self::#name), }
+                        })
+                        .collect(),
+                ),
+            };
+
+        quote! {
+            impl #impl_generics squire::Fetch<'row> for #ident #ty_generics
+            #where_clause
+            {
+                type Value = #value_type;
+
+                fn from_value(value: Self::Value) -> squire::Result<Self> {
+                    match #scrutinee {
+                        #(#arms)*
+                        _ => Err(squire::Error::from(squire::FetchError::Parse)),
+                    }
+                }
+            }
+        }
+    }
 }
 
-impl FieldDerive {
+impl FieldDerive<i32> {
     fn build_meta(&self, field_index: usize, binding_mode: BindingMode) -> Result<Column> {
+        if self.flatten.is_present() {
+            return self.build_flatten_meta();
+        }
+
         // Determine the column identity
         let sequential = binding_mode == BindingMode::Sequential;
         let identity = FieldIdentity::from_field(
@@ -100,12 +145,43 @@ impl FieldDerive {
 
         Ok(Column {
             ident: self.ident.clone(),
-            identity,
+            source: ColumnSource::Column(identity),
             fetch_expr,
             borrow_bound,
         })
     }
 
+    fn build_flatten_meta(&self) -> Result<Column> {
+        let conflicts = [
+            ("borrow", self.borrow.is_present()),
+            ("index", self.index.is_some()),
+            ("rename", self.rename.is_some()),
+            ("result", self.result.is_present()),
+            ("fetch_with", self.fetch_with.is_some()),
+            ("json", self.json.is_present()),
+            ("jsonb", self.jsonb.is_present()),
+            ("default", self.default.is_some()),
+        ];
+
+        if let Some((name, _)) = conflicts.iter().find(|(_, present)| *present) {
+            return Err(
+                darling::Error::custom(format!("flatten cannot be used with {name}"))
+                    .with_span(&self.flatten.span()),
+            );
+        }
+
+        // The `column` variable holds the flattened type's own indexes
+        let ty = &self.ty;
+        let fetch_expr = parse_quote!(<#ty as squire::Columns<'row>>::fetch(statement, column)?);
+
+        Ok(Column {
+            ident: self.ident.clone(),
+            source: ColumnSource::Flatten(ty.clone()),
+            fetch_expr,
+            borrow_bound: None,
+        })
+    }
+
     fn build_fetch_expr(&self, _field_index: usize) -> Result<Expr> {
         // Validate mutually exclusive flags
         if self.json.is_present() && self.jsonb.is_present() {
@@ -124,6 +200,9 @@ impl FieldDerive {
         } else if self.jsonb.is_present() {
             // Wrap type in Jsonb<T> for fetch
             parse_quote!(<squire::Jsonb<#ty> as squire::Fetch<'row>>::fetch_column(statement, #column_var)?.0)
+        } else if self.fetch_with.is_some() {
+            // Fetch the type taken by the custom fetch function
+            parse_quote!(squire::Fetch::fetch_column(statement, #column_var)?)
         } else {
             parse_quote!(<#ty as squire::Fetch<'row>>::fetch_column(statement, #column_var)?)
         };
@@ -138,6 +217,22 @@ impl FieldDerive {
             expr = parse_quote!(#expr?);
         }
 
+        // Substitute a default value if the column is NULL
+        if let Some(ref default) = self.default {
+            let default: Expr = match default {
+                Override::Inherit => parse_quote!(::core::default::Default::default()),
+                Override::Explicit(expr) => expr.clone(),
+            };
+
+            expr = parse_quote! {
+                if <squire::Type as squire::Fetch<'row>>::fetch_column(statement, #column_var)?.is_null() {
+                    #default
+                } else {
+                    #expr
+                }
+            };
+        }
+
         // Wrap in Borrowed if borrow flag is set
         if self.borrow.is_present() && !matches!(&self.ty, Type::Reference(_)) {
             return Err(self.borrow_error());
@@ -145,27 +240,6 @@ impl FieldDerive {
 
         Ok(expr)
     }
-
-    fn borrow_bound(&self) -> Option<syn::Lifetime> {
-        if self.borrow.is_present() {
-            if let Type::Reference(syn::TypeReference {
-                lifetime: Some(ref lifetime),
-                ..
-            }) = self.ty
-            {
-                Some(lifetime.clone())
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    }
-
-    fn borrow_error(&self) -> darling::Error {
-        darling::Error::custom("borrow can only be used with references")
-            .with_span(&self.borrow.span())
-    }
 }
 
 /// [`ColumnsDerive`] data that has been prepared to generate the `impl` tokens.
@@ -213,38 +287,80 @@ impl Columns {
         let column_names: BTreeMap<&str, usize> = self
             .fields
             .iter()
+            .filter_map(|field| field.source.identity())
             .enumerate()
-            .filter_map(|(i, field)| field.identity.name().map(|name| (name, i)))
+            .filter_map(|(i, identity)| identity.name().map(|name| (name, i)))
+            .collect();
+
+        // Types whose columns are flattened into this one
+        let flattened: Vec<&Type> = self
+            .fields
+            .iter()
+            .filter_map(|field| field.source.flattened())
+            .collect();
+
+        // How many of this type's own named columns precede each flattened field
+        let preceding: Vec<usize> = self
+            .fields
+            .iter()
+            .scan(0, |own, field| {
+                if field.source.identity().and_then(|id| id.name()).is_some() {
+                    *own += 1;
+                }
+                Some((field.source.flattened(), *own))
+            })
+            .filter_map(|(flattened, own)| flattened.map(|_| own))
             .collect();
 
         // Validate that explicit #[squire(named)] on tuple structs has all names
-        if self.binding_mode.is_named() && column_names.len() < self.fields.len() {
+        if self.binding_mode.is_named() && column_names.len() < self.fields.len() - flattened.len()
+        {
             return Err(darling::Error::custom("not all fields have names"));
         }
 
-        let NamedIndexResolution { indexes, resolve } =
-            if self.binding_mode.is_named() && !column_names.is_empty() {
-                NamedIndexResolution::derive(
-                    &column_names,
-                    quote!(columns),
-                    quote!(squire::ColumnIndex),
-                )
-            } else {
-                NamedIndexResolution::empty()
-            };
+        let named = self.binding_mode.is_named() && !column_names.is_empty();
+        let NamedIndexResolution { indexes, resolve } = if named {
+            let mut names: Vec<(&str, usize)> =
+                column_names.iter().map(|(n, i)| (*n, *i)).collect();
+            names.sort_by_key(|(_, i)| *i);
 
-        let fetch_statements = self.generate_fetch_statements(&column_names);
+            let count = names.len();
+            let names = names.iter().map(|(name, _)| name);
 
-        Ok(quote! {
-            impl #indexes_impl_generics squire::ColumnIndexes for #ident #ty_generics
-            #indexes_where_clause
-            {
+            NamedIndexResolution {
+                indexes: quote! { type Indexes = [squire::ColumnIndex; #count]; },
+                resolve: quote! { squire::resolve_named_from(statement, &[#(#names),*], start) },
+            }
+        } else {
+            NamedIndexResolution::empty()
+        };
+
+        let resolve_impl = if named || !flattened.is_empty() {
+            let own_count = named.then_some(column_names.len());
+            Self::generate_resolve_from(own_count, indexes, resolve, &flattened, &preceding)
+        } else {
+            quote! {
                 #indexes
 
                 fn resolve<'connection>(statement: &squire::Statement<'connection>) -> Option<Self::Indexes> {
                     #resolve
                 }
             }
+        };
+
+        let unpack = match (named, flattened.is_empty()) {
+            (_, true) => None,
+            (true, false) => Some(quote! { let (indexes, flattened) = indexes; }),
+            (false, false) => Some(quote! { let (_, flattened) = indexes; }),
+        };
+        let fetch_statements = self.generate_fetch_statements(&column_names, unpack);
+
+        Ok(quote! {
+            impl #indexes_impl_generics squire::ColumnIndexes for #ident #ty_generics
+            #indexes_where_clause
+            {
+                #resolve_impl
+            }
 
             impl #columns_impl_generics squire::Columns<'row> for #ident #ty_generics
             #columns_where_clause
@@ -259,7 +375,90 @@ impl Columns {
         })
     }
 
-    fn generate_fetch_statements(&self, column_names: &BTreeMap<&str, usize>) -> TokenStream {
+    /// Generate `resolve` and `resolve_from`, which finds this type's own
+    /// named columns at or after `start`, and then resolves each flattened
+    /// type in turn after the columns of the fields declared before it.
+    fn generate_resolve_from(
+        own_count: Option<usize>,
+        indexes: TokenStream,
+        resolve: TokenStream,
+        flattened: &[&Type],
+        preceding: &[usize],
+    ) -> TokenStream {
+        let own = if own_count.is_some() {
+            quote! {
+                let own = { #resolve }?;
+                let end = own.iter().fold(start, |end, index| end.max(index.next()));
+            }
+        } else {
+            quote! {
+                let own = ();
+                let end = start;
+            }
+        };
+
+        let (indexes, result) = if flattened.is_empty() {
+            (indexes, quote! { Some((own, end)) })
+        } else {
+            let own_type = match own_count {
+                Some(count) => quote!([squire::ColumnIndex; #count]),
+                None => quote!(()),
+            };
+
+            let vars: Vec<_> = (0..flattened.len())
+                .map(|i| Ident::new(&format!("flattened_{i}"), Span::call_site()))
+                .collect();
+
+            let resolutions = flattened.iter().zip(&vars).zip(preceding).map(|((ty, var), &own)| {
+                let skip = (own_count.is_some() && own > 0).then(|| {
+                    quote! {
+                        let cursor = own[..#own].iter().fold(cursor, |end, index| end.max(index.next()));
+                    }
+                });
+
+                quote! {
+                    #skip
+                    let (#var, cursor) = <#ty as squire::ColumnIndexes>::resolve_from(statement, cursor)?;
+                }
+            });
+
+            (
+                quote! {
+                    type Indexes = (#own_type, (#(<#flattened as squire::ColumnIndexes>::Indexes,)*));
+                },
+                quote! {
+                    let cursor = start;
+                    #(#resolutions)*
+
+                    Some(((own, (#(#vars,)*)), end.max(cursor)))
+                },
+            )
+        };
+
+        quote! {
+            #indexes
+
+            fn resolve<'connection>(statement: &squire::Statement<'connection>) -> Option<Self::Indexes> {
+                Self::resolve_from(statement, squire::ColumnIndex::INITIAL).map(|(indexes, _)| indexes)
+            }
+
+            fn resolve_from<'connection>(
+                statement: &squire::Statement<'connection>,
+                start: squire::ColumnIndex,
+            ) -> Option<(Self::Indexes, squire::ColumnIndex)> {
+                #own
+                #result
+            }
+        }
+    }
+
+    fn generate_fetch_statements(
+        &self,
+        column_names: &BTreeMap<&str, usize>,
+        unpack: Option<TokenStream>,
+    ) -> TokenStream {
+        let mut flattened = (0..).map(syn::Index::from);
+
         let field_bindings: Vec<_> = self
             .fields
             .iter()
@@ -275,14 +474,18 @@ impl Columns {
                         quote!(#var_ident)
                     });
 
-                let column_expr = match &field.identity {
-                    FieldIdentity::Named(name) => {
+                let column_expr = match &field.source {
+                    ColumnSource::Column(FieldIdentity::Named(name)) => {
                         let offset = column_names.get(name.as_str()).unwrap();
                         quote! { indexes[#offset] }
                     }
-                    FieldIdentity::Sequential(index) => {
+                    ColumnSource::Column(FieldIdentity::Sequential(index)) => {
                         quote! { squire::ColumnIndex::try_from(#index)? }
                     }
+                    ColumnSource::Flatten(_) => {
+                        let k = flattened.next().unwrap();
+                        quote! { flattened.#k }
+                    }
                 };
 
                 let fetch_expr = &field.fetch_expr;
@@ -315,12 +518,14 @@ impl Columns {
         if self.fields.iter().any(|f| f.ident.is_some()) {
             // Named struct
             quote! {
+                #unpack
                 #(#field_bindings)*
                 Ok(Self { #(#field_names),* })
             }
         } else {
             // Tuple struct
             quote! {
+                #unpack
                 #(#field_bindings)*
                 Ok(Self(#(#field_names),*))
             }
@@ -331,7 +536,31 @@ impl Columns {
 /// Processed metadata for a single [field](FieldDerive) from [`ColumnsDerive`].
 struct Column {
     ident: Option<Ident>,
-    identity: FieldIdentity<i32>,
+    source: ColumnSource,
     fetch_expr: Expr,
     borrow_bound: Option<syn::Lifetime>,
 }
+
+/// Where a [`Column`] gets its value from.
+enum ColumnSource {
+    /// A single column, identified by name or index
+    Column(FieldIdentity<i32>),
+    /// All the columns of a nested type implementing `Columns`
+    Flatten(Type),
+}
+
+impl ColumnSource {
+    fn identity(&self) -> Option<&FieldIdentity<i32>> {
+        match self {
+            ColumnSource::Column(identity) => Some(identity),
+            ColumnSource::Flatten(_) => None,
+        }
+    }
+
+    fn flattened(&self) -> Option<&Type> {
+        match self {
+            ColumnSource::Column(_) => None,
+            ColumnSource::Flatten(ty) => Some(ty),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use std::{fmt::Debug, num::NonZero};
 
-use darling::{FromMeta, ast, util::Flag};
+use darling::{
+    FromField, FromMeta, FromVariant, ast,
+    util::{Flag, Override},
+};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Expr, Generics, Ident, Path};
+use syn::{Expr, Generics, Ident, Path, Type};
 
 /// Trait for types that can be used as sequential parameter/column indices.
 pub trait SequentialIndex: Copy + Debug + FromMeta {
@@ -166,6 +169,103 @@ impl FromMeta for With {
     }
 }
 
+/// A struct field deriving `Columns` or `Parameters`.
+///
+/// Both derives parse the same attributes, so that they can be derived for the
+/// same struct; each reads the attributes that apply to it.
+#[derive(FromField, Debug)]
+#[darling(attributes(squire))]
+pub struct FieldDerive<I: SequentialIndex> {
+    pub ident: Option<Ident>,
+    pub ty: Type,
+
+    pub borrow: Flag,
+    pub index: Option<I>,
+    pub rename: Option<Ident>,
+    pub skip: Flag,
+    pub result: Flag,
+    pub bind_with: Option<With>,
+    pub fetch_with: Option<With>,
+    pub json: Flag,
+    pub jsonb: Flag,
+    pub flatten: Flag,
+    pub default: Option<Override<Expr>>,
+}
+
+impl<I: SequentialIndex> FieldDerive<I> {
+    pub fn borrow_bound(&self) -> Option<syn::Lifetime> {
+        if self.borrow.is_present() {
+            if let Type::Reference(syn::TypeReference {
+                lifetime: Some(ref lifetime),
+                ..
+            }) = self.ty
+            {
+                Some(lifetime.clone())
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    pub fn borrow_error(&self) -> darling::Error {
+        darling::Error::custom("borrow can only be used with references")
+            .with_span(&self.borrow.span())
+    }
+}
+
+/// A unit variant of a C-like enum deriving `Columns` or `Parameters`.
+#[derive(FromVariant, Debug)]
+#[darling(attributes(squire))]
+pub struct VariantDerive {
+    pub ident: Ident,
+    pub rename: Option<String>,
+}
+
+/// How the variants of a C-like enum are stored in SQLite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// Store each variant as its integer discriminant
+    Integer,
+    /// Store each variant as TEXT; used if any variant is renamed
+    Text,
+}
+
+impl EnumRepresentation {
+    /// Determine the representation from the enum's variants.
+    pub fn from_variants(variants: &[VariantDerive]) -> Self {
+        if variants.iter().any(|variant| variant.rename.is_some()) {
+            EnumRepresentation::Text
+        } else {
+            EnumRepresentation::Integer
+        }
+    }
+}
+
+impl VariantDerive {
+    /// The TEXT value stored for this variant: its `rename`, or its identifier.
+    pub fn text(&self) -> String {
+        self.rename
+            .clone()
+            .unwrap_or_else(|| self.ident.to_string())
+    }
+}
+
+/// Reject `#[squire(named)]` and `#[squire(sequential)]` on enums.
+pub fn validate_enum_flags(named: &Flag, sequential: &Flag) -> darling::Result<()> {
+    for flag in [named, sequential] {
+        if flag.is_present() {
+            return Err(
+                darling::Error::custom("named and sequential cannot be used on enums")
+                    .with_span(&flag.span()),
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Generate impl generics with a lifetime parameter.
 ///
 /// For types without generics: `<'lifetime>`
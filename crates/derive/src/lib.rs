@@ -1,11 +1,13 @@
 mod column;
 mod common;
 mod param;
+mod query;
 
 use column::ColumnsDerive;
 use darling::FromDeriveInput;
 use param::ParametersDerive;
 use proc_macro::TokenStream;
+use query::QueryInput;
 
 /// Derive macro for implementing the `Parameters` trait.
 ///
@@ -16,6 +18,10 @@ use proc_macro::TokenStream;
 /// - `#[squire(bind_with = custom_function)]` - Use a custom binding function
 /// - `#[squire(index = 1)]` - Use a specific parameter index
 /// - `#[squire(rename = other_name)]` - Use a different field name for binding
+/// - `#[squire(flatten)]` - Bind all of the field's own named `Parameters`
+///
+/// On a C-like enum, implements `Bind` instead: each variant is bound as its
+/// integer discriminant, or as TEXT if any variant has `#[squire(rename = "...")]`.
 #[proc_macro_derive(Parameters, attributes(squire))]
 pub fn derive_parameters(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
@@ -35,10 +41,22 @@ pub fn derive_parameters(input: TokenStream) -> TokenStream {
 ///
 /// - `#[squire(skip)]` - Skip this field when fetching columns
 /// - `#[squire(borrow)]` - Fetch the field as `Borrowed` for zero-copy access
-/// - `#[squire(fetch_with = custom_function)]` - Use a custom fetch function
+/// - `#[squire(fetch_with = custom_function)]` - Use a custom fetch function; the
+///   column is fetched as the type of the function's argument, which need not be
+///   the field's type (a function taking and returning the field type works as
+///   before, but a function generic over its argument must name it explicitly)
 /// - `#[squire(index = 0)]` - Use a specific column index
 /// - `#[squire(rename = other_name)]` - Use a different field name for column lookup
 /// - `#[squire(result)]` - Unwrap a Result returned by the fetch expression
+/// - `#[squire(default)]` - Use `Default::default()` if the column is NULL
+/// - `#[squire(default = expr)]` - Use `expr` if the column is NULL
+/// - `#[squire(flatten)]` - Fetch the field's own `Columns` from this row; when
+///   column names repeat, each flattened field takes the next match after the
+///   columns of the fields declared before it
+///
+/// On a C-like enum, implements `Fetch` instead: each variant is fetched from
+/// its integer discriminant, or from TEXT if any variant has
+/// `#[squire(rename = "...")]`. Unknown values fail to parse.
 #[proc_macro_derive(Columns, attributes(squire))]
 pub fn derive_columns(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
@@ -51,3 +69,32 @@ pub fn derive_columns(input: TokenStream) -> TokenStream {
         Err(err) => err.write_errors().into(),
     }
 }
+
+/// Prepare a query whose parameter count is checked at compile time.
+///
+/// ```ignore
+/// let user: (i64, String) = squire::query!(
+///     connection,
+///     "SELECT id, name FROM users WHERE id = ? AND active = ?",
+///     id,
+///     true,
+/// )
+/// .one()?;
+/// ```
+///
+/// The first argument is the connection, the second is a SQL string literal,
+/// and the rest are the query's positional parameters. Compilation fails if
+/// the number of arguments doesn't match the parameters in the SQL, counted
+/// the way SQLite counts them (e.g., `?2` implies two parameters, and a
+/// repeated `:name` is one parameter).
+///
+/// Expands to a `squire::CheckedQuery`, which prepares and runs the query.
+#[proc_macro]
+pub fn query(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as QueryInput);
+
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -3,25 +3,25 @@ use std::{
     num::NonZero,
 };
 
-use darling::{FromDeriveInput, FromField, Result, ast, util::Flag};
+use darling::{FromDeriveInput, Result, ast, util::Flag};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Expr, Generics, Ident, Type, parse_quote};
 
 use crate::common::{
-    BindingMode, FieldIdentity, NamedIndexResolution, With, impl_generics_with_lifetime,
-    process_fields,
+    BindingMode, EnumRepresentation, FieldDerive, FieldIdentity, NamedIndexResolution,
+    VariantDerive, impl_generics_with_lifetime, process_fields, validate_enum_flags,
 };
 
 #[derive(FromDeriveInput, Debug)]
 #[darling(
     attributes(squire),
-    supports(struct_named, struct_newtype, struct_tuple)
+    supports(struct_named, struct_newtype, struct_tuple, enum_unit)
 )]
 pub struct ParametersDerive {
     ident: Ident,
     generics: Generics,
-    data: ast::Data<(), FieldDerive>,
+    data: ast::Data<VariantDerive, FieldDerive<NonZero<i32>>>,
 
     named: Flag,
     sequential: Flag,
@@ -29,6 +29,11 @@ pub struct ParametersDerive {
 
 impl ParametersDerive {
     pub fn derive(self) -> Result<TokenStream> {
+        if let ast::Data::Enum(ref variants) = self.data {
+            validate_enum_flags(&self.named, &self.sequential)?;
+            return Ok(self.derive_enum(variants));
+        }
+
         // Step 1: Extract and validate fields
         let (fields, style) = self.fields()?;
 
@@ -49,7 +54,7 @@ impl ParametersDerive {
         meta.generate_impl()
     }
 
-    fn fields(&self) -> Result<(Vec<&FieldDerive>, ast::Style)> {
+    fn fields(&self) -> Result<(Vec<&FieldDerive<NonZero<i32>>>, ast::Style)> {
         match &self.data {
             ast::Data::Struct(contents) => match contents.style {
                 ast::Style::Struct | ast::Style::Tuple => {
@@ -65,26 +70,48 @@ impl ParametersDerive {
             ast::Data::Enum(_) => Err(darling::Error::unsupported_shape("enum")),
         }
     }
-}
 
-#[derive(FromField, Debug)]
-#[darling(attributes(squire))]
-struct FieldDerive {
-    ident: Option<Ident>,
-    ty: Type,
-
-    borrow: Flag,
-    index: Option<NonZero<i32>>,
-    rename: Option<Ident>,
-    skip: Flag,
-    result: Flag,
-    bind_with: Option<With>,
-    json: Flag,
-    jsonb: Flag,
+    /// Implement `Bind` for a C-like enum, which also makes it `Parameters`.
+    fn derive_enum(&self, variants: &[VariantDerive]) -> TokenStream {
+        let ident = &self.ident;
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let impl_generics = impl_generics_with_lifetime(&self.generics, "'statement");
+
+        let (value_type, value) = match EnumRepresentation::from_variants(variants) {
+            EnumRepresentation::Integer => (quote!(i64), quote!(self as i64)),
+            EnumRepresentation::Text => {
+                let arms = variants.iter().map(|variant| {
+                    let name = &variant.ident;
+                    let text = variant.text();
+                    quote! { Self::#name => #text, }
+                });
+                (
+                    quote!(squire::Borrowed<'statement, str>),
+                    quote!(squire::Borrowed::new(match self { #(#arms)* })),
+                )
+            }
+        };
+
+        quote! {
+            impl #impl_generics squire::Bind<'statement> for #ident #ty_generics
+            #where_clause
+            {
+                type Value = #value_type;
+
+                fn into_bind_value(self) -> squire::Result<Self::Value> {
+                    Ok(#value)
+                }
+            }
+        }
+    }
 }
 
-impl FieldDerive {
+impl FieldDerive<NonZero<i32>> {
     fn build_meta(&self, field_index: usize, binding_mode: BindingMode) -> Result<Parameter> {
+        if self.flatten.is_present() {
+            return self.build_flatten_meta(field_index);
+        }
+
         // Determine the parameter identity
         let sequential = binding_mode == BindingMode::Sequential;
         let identity = FieldIdentity::from_field(
@@ -102,12 +129,37 @@ impl FieldDerive {
         let borrow_bound = self.borrow_bound();
 
         Ok(Parameter {
-            identity,
+            source: ParameterSource::Parameter(identity),
             bind_expr,
             borrow_bound,
         })
     }
 
+    fn build_flatten_meta(&self, field_index: usize) -> Result<Parameter> {
+        let conflicts = [
+            ("borrow", self.borrow.is_present()),
+            ("index", self.index.is_some()),
+            ("rename", self.rename.is_some()),
+            ("result", self.result.is_present()),
+            ("bind_with", self.bind_with.is_some()),
+            ("json", self.json.is_present()),
+            ("jsonb", self.jsonb.is_present()),
+        ];
+
+        if let Some((name, _)) = conflicts.iter().find(|(_, present)| *present) {
+            return Err(
+                darling::Error::custom(format!("flatten cannot be used with {name}"))
+                    .with_span(&self.flatten.span()),
+            );
+        }
+
+        Ok(Parameter {
+            source: ParameterSource::Flatten(self.ty.clone()),
+            bind_expr: self.build_bind_expr(field_index)?,
+            borrow_bound: None,
+        })
+    }
+
     fn build_bind_expr(&self, field_index: usize) -> Result<Expr> {
         // Validate mutually exclusive flags
         if self.json.is_present() && self.jsonb.is_present() {
@@ -152,27 +204,6 @@ impl FieldDerive {
 
         Ok(expr)
     }
-
-    fn borrow_bound(&self) -> Option<syn::Lifetime> {
-        if self.borrow.is_present() {
-            if let Type::Reference(syn::TypeReference {
-                lifetime: Some(ref lifetime),
-                ..
-            }) = self.ty
-            {
-                Some(lifetime.clone())
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    }
-
-    fn borrow_error(&self) -> darling::Error {
-        darling::Error::custom("borrow can only be used with references")
-            .with_span(&self.borrow.span())
-    }
 }
 
 /// [`ParametersDerive`] data that has been prepared to generate the `impl` tokens.
@@ -219,27 +250,73 @@ impl Parameters {
         let param_names: BTreeMap<&str, usize> = self
             .fields
             .iter()
+            .filter_map(|field| field.source.identity())
             .enumerate()
-            .filter_map(|(i, field)| field.identity.name().map(|name| (name, i)))
+            .filter_map(|(i, identity)| identity.name().map(|name| (name, i)))
             .collect();
 
-        if self.binding_mode.is_named() && param_names.len() < self.fields.len() {
+        // Types whose parameters are flattened into this one
+        let flattened: Vec<&Type> = self
+            .fields
+            .iter()
+            .filter_map(|field| field.source.flattened())
+            .collect();
+
+        if self.binding_mode.is_named() && param_names.len() < self.fields.len() - flattened.len() {
             return Err(darling::Error::custom("not all fields have names"));
         }
 
-        let NamedIndexResolution { indexes, resolve } =
-            if self.binding_mode.is_named() && !param_names.is_empty() {
-                NamedIndexResolution::derive(
-                    &param_names,
-                    quote!(parameters),
-                    quote!(squire::BindIndex),
-                )
+        let named = self.binding_mode.is_named() && !param_names.is_empty();
+        let NamedIndexResolution { indexes, resolve } = if named {
+            NamedIndexResolution::derive(
+                &param_names,
+                quote!(parameters),
+                quote!(squire::BindIndex),
+            )
+        } else {
+            NamedIndexResolution::empty()
+        };
+
+        let (indexes, resolve, unpack) = if flattened.is_empty() {
+            (indexes, resolve, None)
+        } else {
+            let own_type = if named {
+                let count = param_names.len();
+                quote!([squire::BindIndex; #count])
             } else {
-                NamedIndexResolution::empty()
+                quote!(())
             };
 
+            let own = if named { quote!(indexes) } else { quote!(_) };
+
+            (
+                quote! {
+                    type Indexes = (#own_type, (#(<#flattened as squire::Parameters<'statement>>::Indexes,)*));
+                },
+                quote! {
+                    // Flattened types must bind by name, or their parameter
+                    // indexes would collide with this type's own
+                    fn assert_named<'statement, T: squire::NamedParameters<'statement>>() {}
+                    #(assert_named::<'statement, #flattened>();)*
+
+                    let own = { #resolve }?;
+                    Some((own, (#(<#flattened as squire::Parameters<'statement>>::resolve(statement)?,)*)))
+                },
+                Some(quote! { let (#own, flattened) = indexes; }),
+            )
+        };
+
         let bind_statements = self.generate_bind_statements(&param_names);
 
+        let named_impl = self.binding_mode.is_named().then(|| {
+            quote! {
+                impl #impl_generics squire::NamedParameters<'statement> for #ident #ty_generics
+                #where_clause
+                {
+                }
+            }
+        });
+
         Ok(quote! {
             impl #impl_generics squire::Parameters<'statement> for #ident #ty_generics
             #where_clause
@@ -254,31 +331,42 @@ impl Parameters {
                 where
                     'connection: 'statement,
                 {
+                    #unpack
                     #(#bind_statements)*
 
                     Ok(())
                 }
             }
+
+            #named_impl
         })
     }
 
     fn generate_bind_statements(&self, param_names: &BTreeMap<&str, usize>) -> Vec<TokenStream> {
+        let mut flattened = (0..).map(syn::Index::from);
+
         self.fields
             .iter()
             .map(|field| {
-                let index_expr = match &field.identity {
-                    FieldIdentity::Named(name) => {
+                let bind_expr = &field.bind_expr;
+
+                let index_expr = match &field.source {
+                    ParameterSource::Parameter(FieldIdentity::Named(name)) => {
                         let offset = param_names.get(name.as_str()).unwrap();
                         quote! { indexes[#offset] }
                     }
-                    FieldIdentity::Sequential(index) => {
+                    ParameterSource::Parameter(FieldIdentity::Sequential(index)) => {
                         let index_val = index.get();
                         quote! { unsafe { squire::BindIndex::new_unchecked(#index_val) } }
                     }
+                    ParameterSource::Flatten(ty) => {
+                        let k = flattened.next().unwrap();
+                        return quote! {
+                            <#ty as squire::Parameters<'statement>>::bind(#bind_expr, binding, flattened.#k)?;
+                        };
+                    }
                 };
 
-                let bind_expr = &field.bind_expr;
-
                 quote! {
                     binding.set(#index_expr, #bind_expr)?;
                 }
@@ -289,7 +377,31 @@ impl Parameters {
 
 /// Processed metadata for a single field in the Parameters derive.
 struct Parameter {
-    identity: FieldIdentity<NonZero<i32>>,
+    source: ParameterSource,
     bind_expr: Expr,
     borrow_bound: Option<syn::Lifetime>,
 }
+
+/// Where a [`Parameter`] binds its value.
+enum ParameterSource {
+    /// A single parameter, identified by name or index
+    Parameter(FieldIdentity<NonZero<i32>>),
+    /// All the parameters of a nested type implementing `NamedParameters`
+    Flatten(Type),
+}
+
+impl ParameterSource {
+    fn identity(&self) -> Option<&FieldIdentity<NonZero<i32>>> {
+        match self {
+            ParameterSource::Parameter(identity) => Some(identity),
+            ParameterSource::Flatten(_) => None,
+        }
+    }
+
+    fn flattened(&self) -> Option<&Type> {
+        match self {
+            ParameterSource::Parameter(_) => None,
+            ParameterSource::Flatten(ty) => Some(ty),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Expr, LitStr, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// The input to `query!`: a connection, a SQL string literal, and arguments.
+pub struct QueryInput {
+    connection: Expr,
+    sql: LitStr,
+    arguments: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for QueryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let connection = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let sql = input.parse()?;
+
+        let arguments = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+
+        Ok(Self {
+            connection,
+            sql,
+            arguments,
+        })
+    }
+}
+
+impl QueryInput {
+    pub fn expand(&self) -> syn::Result<TokenStream> {
+        let sql = self.sql.value();
+        let expected =
+            parameter_count(&sql).map_err(|message| syn::Error::new(self.sql.span(), message))?;
+
+        let given = self.arguments.len();
+        if given != expected {
+            return Err(syn::Error::new(
+                self.sql.span(),
+                format!(
+                    "query has {expected} parameter{}, but {given} argument{} {} given",
+                    if expected == 1 { "" } else { "s" },
+                    if given == 1 { "" } else { "s" },
+                    if given == 1 { "was" } else { "were" },
+                ),
+            ));
+        }
+
+        let connection = &self.connection;
+        let literal = &self.sql;
+        let arguments = self.arguments.iter();
+
+        Ok(quote! {
+            squire::CheckedQuery::new(&#connection, #literal, (#(#arguments,)*))
+        })
+    }
+}
+
+/// Count the parameters SQLite will find in `sql`, the way
+/// `sqlite3_bind_parameter_count` does: the largest parameter index, where
+/// `?` takes the next index, `?NNN` takes index `NNN`, and each distinct
+/// `:name`, `@name`, or `$name` takes the next index.
+fn parameter_count(sql: &str) -> Result<usize, String> {
+    let bytes = sql.as_bytes();
+    let mut names = HashSet::new();
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => i = skip_quoted(bytes, i, quote),
+            b'[' => i = skip_until(bytes, i + 1, b"]"),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => i = skip_until(bytes, i + 2, b"\n"),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_until(bytes, i + 2, b"*/"),
+            b'?' => {
+                let end = scan(bytes, i + 1, |b| b.is_ascii_digit());
+                if end == i + 1 {
+                    count += 1;
+                } else {
+                    let index: usize = sql[i + 1..end]
+                        .parse()
+                        .map_err(|_| format!("invalid parameter `{}`", &sql[i..end]))?;
+                    if index == 0 {
+                        return Err("parameter indexes start at ?1".to_owned());
+                    }
+                    count = count.max(index);
+                }
+                i = end;
+            }
+            b':' | b'@' | b'$' => {
+                let end = scan(bytes, i + 1, |b| b.is_ascii_alphanumeric() || b == b'_');
+                if end > i + 1 && names.insert(&sql[i..end]) {
+                    count += 1;
+                }
+                i = end.max(i + 1);
+            }
+            _ => i += 1,
+        }
+    }
+
+    Ok(count)
+}
+
+fn scan(bytes: &[u8], start: usize, accept: impl Fn(u8) -> bool) -> usize {
+    let mut end = start;
+    while end < bytes.len() && accept(bytes[end]) {
+        end += 1;
+    }
+    end
+}
+
+/// Skip a quoted string or identifier starting at `start`, where a doubled
+/// quote is an escaped quote.
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+fn skip_until(bytes: &[u8], start: usize, terminator: &[u8]) -> usize {
+    bytes[start.min(bytes.len())..]
+        .windows(terminator.len())
+        .position(|window| window == terminator)
+        .map_or(bytes.len(), |offset| start + offset + terminator.len())
+}
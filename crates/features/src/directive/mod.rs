@@ -21,6 +21,7 @@ directives! {
     DEFAULT_WAL_SYNCHRONOUS => DefaultWalSynchronous(Synchronous),
     DQS => DoubleQuotedStrings(DoubleQuotedStrings),
     ENABLE_API_ARMOR => EnableApiArmor,
+    ENABLE_CARRAY => EnableCarray,
     ENABLE_COLUMN_METADATA => EnableColumnMetadata,
     ENABLE_DBPAGE_VTAB => EnableDatabasePageVirtualTable,
     ENABLE_DBSTAT_VTAB => EnableDatabaseStatisticsVirtualTable,
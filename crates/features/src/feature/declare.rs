@@ -174,7 +174,7 @@ macro_rules! features {
             fn from_str(value: &str) -> Result<Self, Self::Err> {
                 match Self::NAMES.binary_search(&value) {
                     Ok(i) => Ok(Self::from_index(i as u32)),
-                    Err(_) => Err(UnknownFeatureError(value.to_owned()))
+                    Err(_) => Err(UnknownFeatureError(alloc::string::String::from(value)))
                 }
             }
         }
@@ -48,12 +48,16 @@ features! {
     AutomaticVacuum +DefaultAutomaticVacuum,
     BlobIo -OmitBlobIo,
     BlobLike -OmitBlobLike,
+    Carray @"3.51" +EnableCarray,
     CaseSensitiveLike +LikeOperatorCaseSenstive,
     ColumnDeclaredType -OmitColumnDeclaredType,
     ColumnMetadata +EnableColumnMetadata,
     Complete -OmitComplete,
     DatabasePageVirtualTable +EnableDatabasePageVirtualTable,
     DatabaseStatisticsVirtualTable +EnableDatabaseStatisticsVirtualTable,
+    DbConfigDefensive @"3.26",
+    DbConfigEnableView @"3.30",
+    DbConfigTrustedSchema @"3.31",
     Deprecated -OmitDeprecated,
     ErrorOffset @"3.38",
     Fts3 +EnableFts3,
@@ -76,6 +80,7 @@ features! {
     PrepareFromDdl @"3.53",
     PrepareQuiet @"3.48",
     ProgressCallback -OmitProgressCallback,
+    Returning @"3.35",
     Rtree +EnableRtree,
     Serialize -OmitSerialize,
     Session ^PreUpdateHook +EnableSession,
@@ -92,6 +97,7 @@ features! {
     Utf8Zt @"3.53",
     VirtualTable -OmitVirtualTable,
     Wal -OmitWal,
+    WindowFunction @"3.25",
 }
 
 #[cfg(feature = "metadata")]
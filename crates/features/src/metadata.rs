@@ -86,8 +86,18 @@ impl Metadata {
             println!("cargo::rustc-cfg=sqlite_has_{name}");
         }
 
-        // Include SQLite library version
+        // Include SQLite library version, threading mode, and features
         println!("cargo::rustc-env=SQUIRE_SQLITE_VERSION={}", self.version());
+        println!(
+            "cargo::rustc-env=SQUIRE_SQLITE_THREADING={}",
+            self.threading.value()
+        );
+
+        let features: Vec<_> = self.enabled().map(|k| k.name()).collect();
+        println!(
+            "cargo::rustc-env=SQUIRE_SQLITE_FEATURES={}",
+            features.join(",")
+        );
     }
 }
 
@@ -190,12 +190,14 @@ impl fmt::Display for ParseVersionError {
     }
 }
 
+#[cfg(any(feature = "build", feature = "dynamic"))]
 pub(crate) struct Override {
     source_id: &'static str,
     reported: Version,
     actual: Version,
 }
 
+#[cfg(any(feature = "build", feature = "dynamic"))]
 impl Override {
     const KNOWN: [Self; 1] = [Self::define(
         "3.51.0",
@@ -227,6 +229,7 @@ impl Override {
     }
 }
 
+#[cfg(any(feature = "build", feature = "dynamic"))]
 const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
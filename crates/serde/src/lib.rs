@@ -6,7 +6,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std as alloc;
 
-pub use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+pub use serde::{
+    Deserialize, Deserializer, Serialize, Serializer, de, forward_to_deserialize_any, ser,
+};
 
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
@@ -107,6 +107,7 @@ fn build_bundled_sqlite(dest: &Path, mock: bool) -> Result<sqlite::Build> {
     set!(AutomaticVacuum, cfg!(feature = "auto-vacuum"));
     set!(BlobIo, cfg!(feature = "blob-io"));
     set!(BlobLike, cfg!(feature = "blob-like"));
+    set!(Carray, cfg!(feature = "carray"));
     set!(ColumnDeclaredType, cfg!(feature = "decltype"));
     set!(ColumnMetadata, cfg!(feature = "column-metadata"));
     set!(Complete, false);
@@ -156,6 +157,7 @@ fn generate_bindings(header: &Path, dest: &Path) -> Result {
         .header(header.to_str().expect("non UTF-8 sqlite3.h path"))
         .default_macro_constant_type(bindgen::MacroTypeVariation::Signed)
         .generate_cstr(true)
+        .use_core()
         .blocklist_function("sqlite3_(str_)?v[msn]*(print|append)f")
         .blocklist_type("va_list")
         .blocklist_item("^__.*")
@@ -220,6 +222,11 @@ fn generate_bindings(header: &Path, dest: &Path) -> Result {
             4,
             vec!["pStmt", "parameter", "value", "type_name", "destructor"],
         ),
+        (
+            "sqlite3_carray_bind",
+            5,
+            vec!["pStmt", "parameter", "value", "len", "flags", "destructor"],
+        ),
         // result functions - 4th parameter (index 3)
         (
             "sqlite3_result_blob",
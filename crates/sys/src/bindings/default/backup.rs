@@ -0,0 +1,47 @@
+use core::ffi::{c_char, c_int};
+
+use super::connection::sqlite3;
+
+/// An [online backup][backup] in progress, copying one database into
+/// another.
+///
+/// [backup]: https://sqlite.org/c3ref/backup.html
+#[repr(C)]
+pub struct sqlite3_backup {
+    _unused: [u8; 0],
+}
+
+unsafe extern "C" {
+    /// [Start][init] copying the `zSourceName` database of `pSource` into the
+    /// `zDestName` database of `pDest`.
+    ///
+    /// [init]: https://sqlite.org/c3ref/backup_finish.html#sqlite3backupinit
+    pub fn sqlite3_backup_init(
+        pDest: *mut sqlite3,
+        zDestName: *const c_char,
+        pSource: *mut sqlite3,
+        zSourceName: *const c_char,
+    ) -> *mut sqlite3_backup;
+
+    /// [Copy][step] up to `nPage` pages (or all remaining pages, if negative).
+    ///
+    /// [step]: https://sqlite.org/c3ref/backup_finish.html#sqlite3backupstep
+    pub fn sqlite3_backup_step(p: *mut sqlite3_backup, nPage: c_int) -> c_int;
+
+    /// [Release][finish] a backup, ending it early if it hasn’t completed.
+    ///
+    /// [finish]: https://sqlite.org/c3ref/backup_finish.html#sqlite3backupfinish
+    pub fn sqlite3_backup_finish(p: *mut sqlite3_backup) -> c_int;
+
+    /// The number of [pages remaining][remaining] to be copied, as of the last
+    /// step.
+    ///
+    /// [remaining]: https://sqlite.org/c3ref/backup_finish.html#sqlite3backupremaining
+    pub fn sqlite3_backup_remaining(p: *mut sqlite3_backup) -> c_int;
+
+    /// The [total number of pages][pagecount] in the source database, as of
+    /// the last step.
+    ///
+    /// [pagecount]: https://sqlite.org/c3ref/backup_finish.html#sqlite3backuppagecount
+    pub fn sqlite3_backup_pagecount(p: *mut sqlite3_backup) -> c_int;
+}
@@ -1,4 +1,6 @@
-use core::ffi::{c_char, c_int};
+use core::ffi::{c_char, c_int, c_uchar, c_uint};
+
+use super::types::sqlite3_int64;
 
 /// A database [connection handle][].
 ///
@@ -25,6 +27,73 @@ unsafe extern "C" {
     /// [close]: https://sqlite.org/c3ref/close.html
     /// [database connection]: https://sqlite.org/c3ref/sqlite3.html
     pub fn sqlite3_close(pDb: *mut sqlite3) -> c_int;
+
+    /// Query or change a [run-time limit][limit].
+    ///
+    /// [limit]: https://sqlite.org/c3ref/limit.html
+    pub fn sqlite3_limit(db: *mut sqlite3, id: c_int, newVal: c_int) -> c_int;
+
+    /// Change a [configuration option][config] of a connection.
+    ///
+    /// [config]: https://sqlite.org/c3ref/db_config.html
+    pub fn sqlite3_db_config(db: *mut sqlite3, op: c_int, ...) -> c_int;
+
+    /// Test whether a connection is in [autocommit mode][autocommit].
+    ///
+    /// [autocommit]: https://sqlite.org/c3ref/get_autocommit.html
+    pub fn sqlite3_get_autocommit(db: *mut sqlite3) -> c_int;
+
+    /// Determine whether a database is [read-only][readonly].
+    ///
+    /// [readonly]: https://sqlite.org/c3ref/db_readonly.html
+    pub fn sqlite3_db_readonly(db: *mut sqlite3, zDbName: *const c_char) -> c_int;
+
+    /// Return the [filename][] of a database.
+    ///
+    /// [filename]: https://sqlite.org/c3ref/db_filename.html
+    pub fn sqlite3_db_filename(db: *mut sqlite3, zDbName: *const c_char) -> *const c_char;
+
+    /// Determine the [transaction state][state] of a database.
+    ///
+    /// [state]: https://sqlite.org/c3ref/txn_state.html
+    pub fn sqlite3_txn_state(db: *mut sqlite3, zSchema: *const c_char) -> c_int;
+
+    /// Extract [metadata][] about a column of a table.
+    ///
+    /// [metadata]: https://sqlite.org/c3ref/table_column_metadata.html
+    pub fn sqlite3_table_column_metadata(
+        db: *mut sqlite3,
+        zDbName: *const c_char,
+        zTableName: *const c_char,
+        zColumnName: *const c_char,
+        pzDataType: *mut *const c_char,
+        pzCollSeq: *mut *const c_char,
+        pNotNull: *mut c_int,
+        pPrimaryKey: *mut c_int,
+        pAutoinc: *mut c_int,
+    ) -> c_int;
+
+    /// [Serialize][serialize] a database into a byte buffer.
+    ///
+    /// [serialize]: https://sqlite.org/c3ref/serialize.html
+    pub fn sqlite3_serialize(
+        db: *mut sqlite3,
+        zSchema: *const c_char,
+        piSize: *mut sqlite3_int64,
+        mFlags: c_uint,
+    ) -> *mut c_uchar;
+
+    /// [Deserialize][deserialize] a database from a byte buffer.
+    ///
+    /// [deserialize]: https://sqlite.org/c3ref/deserialize.html
+    pub fn sqlite3_deserialize(
+        db: *mut sqlite3,
+        zSchema: *const c_char,
+        pData: *mut c_uchar,
+        szDb: sqlite3_int64,
+        szBuf: sqlite3_int64,
+        mFlags: c_uint,
+    ) -> c_int;
 }
 
 pub const SQLITE_OPEN_READONLY: i32 = 0x00000001;
@@ -49,3 +118,32 @@ pub const SQLITE_OPEN_PRIVATECACHE: i32 = 0x00040000;
 pub const SQLITE_OPEN_WAL: i32 = 0x00080000;
 pub const SQLITE_OPEN_NOFOLLOW: i32 = 0x01000000;
 pub const SQLITE_OPEN_EXRESCODE: i32 = 0x02000000;
+
+pub const SQLITE_TXN_NONE: i32 = 0;
+pub const SQLITE_TXN_READ: i32 = 1;
+pub const SQLITE_TXN_WRITE: i32 = 2;
+
+pub const SQLITE_LIMIT_LENGTH: i32 = 0;
+pub const SQLITE_LIMIT_SQL_LENGTH: i32 = 1;
+pub const SQLITE_LIMIT_COLUMN: i32 = 2;
+pub const SQLITE_LIMIT_EXPR_DEPTH: i32 = 3;
+pub const SQLITE_LIMIT_COMPOUND_SELECT: i32 = 4;
+pub const SQLITE_LIMIT_VDBE_OP: i32 = 5;
+pub const SQLITE_LIMIT_FUNCTION_ARG: i32 = 6;
+pub const SQLITE_LIMIT_ATTACHED: i32 = 7;
+pub const SQLITE_LIMIT_LIKE_PATTERN_LENGTH: i32 = 8;
+pub const SQLITE_LIMIT_VARIABLE_NUMBER: i32 = 9;
+pub const SQLITE_LIMIT_TRIGGER_DEPTH: i32 = 10;
+pub const SQLITE_LIMIT_WORKER_THREADS: i32 = 11;
+
+pub const SQLITE_DBCONFIG_ENABLE_FKEY: i32 = 1002;
+pub const SQLITE_DBCONFIG_ENABLE_TRIGGER: i32 = 1003;
+pub const SQLITE_DBCONFIG_DEFENSIVE: i32 = 1010;
+pub const SQLITE_DBCONFIG_ENABLE_VIEW: i32 = 1015;
+pub const SQLITE_DBCONFIG_TRUSTED_SCHEMA: i32 = 1017;
+
+pub const SQLITE_SERIALIZE_NOCOPY: i32 = 0x001;
+
+pub const SQLITE_DESERIALIZE_FREEONCLOSE: i32 = 1;
+pub const SQLITE_DESERIALIZE_RESIZEABLE: i32 = 2;
+pub const SQLITE_DESERIALIZE_READONLY: i32 = 4;
@@ -0,0 +1,48 @@
+use core::ffi::{c_char, c_int, c_void};
+
+use super::{connection::sqlite3, types::sqlite3_int64, value::sqlite3_value};
+
+unsafe extern "C" {
+    /// Register a [pre-update hook][hook], returning the previous hook's
+    /// user data.
+    ///
+    /// [hook]: https://sqlite.org/c3ref/preupdate_blobwrite.html
+    pub fn sqlite3_preupdate_hook(
+        db: *mut sqlite3,
+        xPreUpdate: Option<
+            unsafe extern "C" fn(
+                pCtx: *mut c_void,
+                db: *mut sqlite3,
+                op: c_int,
+                zDb: *const c_char,
+                zName: *const c_char,
+                iKey1: sqlite3_int64,
+                iKey2: sqlite3_int64,
+            ),
+        >,
+        pCtx: *mut c_void,
+    ) -> *mut c_void;
+
+    /// Read a column of the row being deleted or updated, from within a
+    /// pre-update hook.
+    pub fn sqlite3_preupdate_old(
+        db: *mut sqlite3,
+        i: c_int,
+        ppValue: *mut *mut sqlite3_value,
+    ) -> c_int;
+
+    /// Read a column of the row being inserted or updated, from within a
+    /// pre-update hook.
+    pub fn sqlite3_preupdate_new(
+        db: *mut sqlite3,
+        i: c_int,
+        ppValue: *mut *mut sqlite3_value,
+    ) -> c_int;
+
+    /// The number of columns in the row being changed, from within a
+    /// pre-update hook.
+    pub fn sqlite3_preupdate_count(db: *mut sqlite3) -> c_int;
+
+    /// The trigger depth of the change, from within a pre-update hook.
+    pub fn sqlite3_preupdate_depth(db: *mut sqlite3) -> c_int;
+}
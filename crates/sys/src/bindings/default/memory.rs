@@ -11,4 +11,7 @@ unsafe extern "C" {
     pub fn sqlite3_msize(ptr: *mut c_void) -> sqlite3_uint64;
     pub fn sqlite3_memory_used() -> sqlite3_int64;
     pub fn sqlite3_memory_highwater(resetFlag: c_int) -> sqlite3_int64;
+    pub fn sqlite3_soft_heap_limit64(N: sqlite3_int64) -> sqlite3_int64;
+    pub fn sqlite3_hard_heap_limit64(N: sqlite3_int64) -> sqlite3_int64;
+    pub fn sqlite3_release_memory(N: c_int) -> c_int;
 }
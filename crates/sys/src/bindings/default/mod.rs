@@ -1,27 +1,41 @@
+mod auth;
+mod backup;
 mod column;
 mod connection;
 mod function;
+mod hook;
 mod memory;
 mod mutex;
 mod param;
 mod result;
+mod session;
+mod snapshot;
 mod statement;
+mod status;
 mod string;
 mod types;
 mod value;
 mod version;
+mod vfs;
 
+pub use auth::*;
+pub use backup::*;
 pub use column::*;
 pub use connection::*;
 pub use function::*;
+pub use hook::*;
 pub use memory::*;
 pub use mutex::*;
 pub use param::*;
 pub use result::*;
+pub use session::*;
+pub use snapshot::*;
 pub use statement::*;
+pub use status::*;
 pub use string::*;
 pub use types::*;
 pub use value::*;
 pub use version::*;
+pub use vfs::*;
 
 pub use super::destructor::sqlite3_destructor_type;
@@ -57,6 +57,14 @@ unsafe extern "C" {
         type_name: *const c_char,
         destructor: sqlite3_destructor_type,
     ) -> c_int;
+    pub fn sqlite3_carray_bind(
+        pStmt: *mut sqlite3_stmt,
+        parameter: c_int,
+        value: *mut c_void,
+        len: c_int,
+        flags: c_int,
+        destructor: sqlite3_destructor_type,
+    ) -> c_int;
     pub fn sqlite3_bind_zeroblob(pStmt: *mut sqlite3_stmt, parameter: c_int, n: c_int) -> c_int;
     pub fn sqlite3_bind_zeroblob64(
         pStmt: *mut sqlite3_stmt,
@@ -64,3 +72,9 @@ unsafe extern "C" {
         arg3: sqlite3_uint64,
     ) -> c_int;
 }
+
+pub const SQLITE_CARRAY_INT32: i32 = 0;
+pub const SQLITE_CARRAY_INT64: i32 = 1;
+pub const SQLITE_CARRAY_DOUBLE: i32 = 2;
+pub const SQLITE_CARRAY_TEXT: i32 = 3;
+pub const SQLITE_CARRAY_BLOB: i32 = 4;
@@ -151,6 +151,7 @@ pub const SQLITE_OK_SYMLINK: i32 = SQLITE_OK | 2 << 8;
 unsafe extern "C" {
     pub fn sqlite3_errcode(db: *mut sqlite3) -> c_int;
     pub fn sqlite3_extended_errcode(db: *mut sqlite3) -> c_int;
+    pub fn sqlite3_extended_result_codes(db: *mut sqlite3, onoff: c_int) -> c_int;
     pub fn sqlite3_errmsg(arg1: *mut sqlite3) -> *const c_char;
     pub fn sqlite3_errstr(arg1: c_int) -> *const c_char;
     pub fn sqlite3_error_offset(db: *mut sqlite3) -> c_int;
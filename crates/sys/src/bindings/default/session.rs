@@ -0,0 +1,101 @@
+use core::ffi::{c_char, c_int, c_void};
+
+use super::connection::sqlite3;
+
+/// A [session object][session], which records changes made to a database.
+///
+/// [session]: https://sqlite.org/session/session.html
+#[repr(C)]
+pub struct sqlite3_session {
+    _unused: [u8; 0],
+}
+
+/// An [iterator][iter] over the changes in a changeset.
+///
+/// [iter]: https://sqlite.org/session/changeset_iter.html
+#[repr(C)]
+pub struct sqlite3_changeset_iter {
+    _unused: [u8; 0],
+}
+
+pub const SQLITE_CHANGESET_DATA: i32 = 1;
+pub const SQLITE_CHANGESET_NOTFOUND: i32 = 2;
+pub const SQLITE_CHANGESET_CONFLICT: i32 = 3;
+pub const SQLITE_CHANGESET_CONSTRAINT: i32 = 4;
+pub const SQLITE_CHANGESET_FOREIGN_KEY: i32 = 5;
+
+pub const SQLITE_CHANGESET_OMIT: i32 = 0;
+pub const SQLITE_CHANGESET_REPLACE: i32 = 1;
+pub const SQLITE_CHANGESET_ABORT: i32 = 2;
+
+unsafe extern "C" {
+    /// [Create][create] a new session object, recording changes to the
+    /// `zDb` database.
+    ///
+    /// [create]: https://sqlite.org/session/sqlite3session_create.html
+    pub fn sqlite3session_create(
+        db: *mut sqlite3,
+        zDb: *const c_char,
+        ppSession: *mut *mut sqlite3_session,
+    ) -> c_int;
+
+    /// [Delete][delete] a session object.
+    ///
+    /// [delete]: https://sqlite.org/session/sqlite3session_delete.html
+    pub fn sqlite3session_delete(pSession: *mut sqlite3_session);
+
+    /// [Attach][attach] a table to a session object, or all tables if `zTab`
+    /// is null.
+    ///
+    /// [attach]: https://sqlite.org/session/sqlite3session_attach.html
+    pub fn sqlite3session_attach(pSession: *mut sqlite3_session, zTab: *const c_char) -> c_int;
+
+    /// [Enable or disable][enable] recording changes in a session object.
+    ///
+    /// [enable]: https://sqlite.org/session/sqlite3session_enable.html
+    pub fn sqlite3session_enable(pSession: *mut sqlite3_session, bEnable: c_int) -> c_int;
+
+    /// [Check][isempty] whether a session object has recorded any changes.
+    ///
+    /// [isempty]: https://sqlite.org/session/sqlite3session_isempty.html
+    pub fn sqlite3session_isempty(pSession: *mut sqlite3_session) -> c_int;
+
+    /// Generate a [changeset][] from the changes recorded by a session object.
+    ///
+    /// [changeset]: https://sqlite.org/session/sqlite3session_changeset.html
+    pub fn sqlite3session_changeset(
+        pSession: *mut sqlite3_session,
+        pnChangeset: *mut c_int,
+        ppChangeset: *mut *mut c_void,
+    ) -> c_int;
+
+    /// [Apply][apply] a changeset to a database.
+    ///
+    /// [apply]: https://sqlite.org/session/sqlite3changeset_apply.html
+    pub fn sqlite3changeset_apply(
+        db: *mut sqlite3,
+        nChangeset: c_int,
+        pChangeset: *mut c_void,
+        xFilter: Option<unsafe extern "C" fn(pCtx: *mut c_void, zTab: *const c_char) -> c_int>,
+        xConflict: Option<
+            unsafe extern "C" fn(
+                pCtx: *mut c_void,
+                eConflict: c_int,
+                p: *mut sqlite3_changeset_iter,
+            ) -> c_int,
+        >,
+        pCtx: *mut c_void,
+    ) -> c_int;
+
+    /// Read the [table and operation][op] of the current change of a
+    /// changeset iterator.
+    ///
+    /// [op]: https://sqlite.org/session/sqlite3changeset_op.html
+    pub fn sqlite3changeset_op(
+        pIter: *mut sqlite3_changeset_iter,
+        pzTab: *mut *const c_char,
+        pnCol: *mut c_int,
+        pOp: *mut c_int,
+        pbIndirect: *mut c_int,
+    ) -> c_int;
+}
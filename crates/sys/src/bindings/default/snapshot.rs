@@ -0,0 +1,47 @@
+use core::ffi::{c_char, c_int};
+
+use super::connection::sqlite3;
+
+/// A [snapshot] of the state of a WAL-mode database.
+///
+/// [snapshot]: https://sqlite.org/c3ref/snapshot.html
+#[repr(C)]
+pub struct sqlite3_snapshot {
+    _unused: [u8; 0],
+}
+
+unsafe extern "C" {
+    /// Record a [snapshot][] of the schema database's current state, as seen
+    /// by the open read transaction.
+    ///
+    /// [snapshot]: https://sqlite.org/c3ref/snapshot_get.html
+    pub fn sqlite3_snapshot_get(
+        db: *mut sqlite3,
+        zSchema: *const c_char,
+        ppSnapshot: *mut *mut sqlite3_snapshot,
+    ) -> c_int;
+
+    /// Start a read transaction on a historical [snapshot][].
+    ///
+    /// [snapshot]: https://sqlite.org/c3ref/snapshot_open.html
+    pub fn sqlite3_snapshot_open(
+        db: *mut sqlite3,
+        zSchema: *const c_char,
+        pSnapshot: *mut sqlite3_snapshot,
+    ) -> c_int;
+
+    /// Destroy a [snapshot][].
+    ///
+    /// [snapshot]: https://sqlite.org/c3ref/snapshot_free.html
+    pub fn sqlite3_snapshot_free(pSnapshot: *mut sqlite3_snapshot);
+
+    /// [Compare][cmp] the ages of two snapshots of the same database.
+    ///
+    /// [cmp]: https://sqlite.org/c3ref/snapshot_cmp.html
+    pub fn sqlite3_snapshot_cmp(p1: *mut sqlite3_snapshot, p2: *mut sqlite3_snapshot) -> c_int;
+
+    /// [Recover][recover] snapshots from a WAL file.
+    ///
+    /// [recover]: https://sqlite.org/c3ref/snapshot_recover.html
+    pub fn sqlite3_snapshot_recover(db: *mut sqlite3, zDb: *const c_char) -> c_int;
+}
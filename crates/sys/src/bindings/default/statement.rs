@@ -26,6 +26,10 @@ unsafe extern "C" {
 
     pub fn sqlite3_step(pStmt: *mut sqlite3_stmt) -> c_int;
 
+    pub fn sqlite3_sql(pStmt: *mut sqlite3_stmt) -> *const c_char;
+    pub fn sqlite3_expanded_sql(pStmt: *mut sqlite3_stmt) -> *mut c_char;
+    pub fn sqlite3_normalized_sql(pStmt: *mut sqlite3_stmt) -> *const c_char;
+
     pub fn sqlite3_bind_parameter_count(pStmt: *mut sqlite3_stmt) -> c_int;
     pub fn sqlite3_bind_parameter_name(pStmt: *mut sqlite3_stmt, arg2: c_int) -> *const c_char;
     pub fn sqlite3_bind_parameter_index(pStmt: *mut sqlite3_stmt, zName: *const c_char) -> c_int;
@@ -40,6 +44,10 @@ unsafe extern "C" {
 
     pub fn sqlite3_db_handle(pStmt: *mut sqlite3_stmt) -> *mut sqlite3;
 
+    pub fn sqlite3_stmt_readonly(pStmt: *mut sqlite3_stmt) -> c_int;
+    pub fn sqlite3_stmt_isexplain(pStmt: *mut sqlite3_stmt) -> c_int;
+    pub fn sqlite3_stmt_busy(pStmt: *mut sqlite3_stmt) -> c_int;
+
     pub fn sqlite3_changes(pStmt: *mut sqlite3) -> c_int;
     pub fn sqlite3_changes64(pStmt: *mut sqlite3) -> sqlite3_int64;
     pub fn sqlite3_last_insert_rowid(pStmt: *mut sqlite3) -> sqlite3_int64;
@@ -48,6 +56,7 @@ unsafe extern "C" {
     pub fn sqlite3_clear_bindings(pStmt: *mut sqlite3_stmt) -> c_int;
     pub fn sqlite3_reset(pStmt: *mut sqlite3_stmt) -> c_int;
     pub fn sqlite3_finalize(pStmt: *mut sqlite3_stmt) -> c_int;
+    pub fn sqlite3_complete(sql: *const c_char) -> c_int;
 }
 
 pub const SQLITE_PREPARE_PERSISTENT: i32 = 0x01;
@@ -0,0 +1,196 @@
+use core::ffi::{c_char, c_double, c_int, c_void};
+
+use super::types::sqlite3_int64;
+
+/// A [virtual filesystem][vfs], through which SQLite performs all I/O.
+///
+/// [vfs]: https://sqlite.org/c3ref/vfs.html
+#[repr(C)]
+pub struct sqlite3_vfs {
+    pub iVersion: c_int,
+    pub szOsFile: c_int,
+    pub mxPathname: c_int,
+    pub pNext: *mut sqlite3_vfs,
+    pub zName: *const c_char,
+    pub pAppData: *mut c_void,
+    pub xOpen: Option<
+        unsafe extern "C" fn(
+            pVfs: *mut sqlite3_vfs,
+            zName: *const c_char,
+            pFile: *mut sqlite3_file,
+            flags: c_int,
+            pOutFlags: *mut c_int,
+        ) -> c_int,
+    >,
+    pub xDelete: Option<
+        unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, zName: *const c_char, syncDir: c_int) -> c_int,
+    >,
+    pub xAccess: Option<
+        unsafe extern "C" fn(
+            pVfs: *mut sqlite3_vfs,
+            zName: *const c_char,
+            flags: c_int,
+            pResOut: *mut c_int,
+        ) -> c_int,
+    >,
+    pub xFullPathname: Option<
+        unsafe extern "C" fn(
+            pVfs: *mut sqlite3_vfs,
+            zName: *const c_char,
+            nOut: c_int,
+            zOut: *mut c_char,
+        ) -> c_int,
+    >,
+    pub xDlOpen: Option<
+        unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, zFilename: *const c_char) -> *mut c_void,
+    >,
+    pub xDlError:
+        Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, nByte: c_int, zErrMsg: *mut c_char)>,
+    pub xDlSym: Option<
+        unsafe extern "C" fn(
+            pVfs: *mut sqlite3_vfs,
+            pHandle: *mut c_void,
+            zSymbol: *const c_char,
+        ) -> sqlite3_syscall_ptr,
+    >,
+    pub xDlClose: Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, pHandle: *mut c_void)>,
+    pub xRandomness: Option<
+        unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, nByte: c_int, zOut: *mut c_char) -> c_int,
+    >,
+    pub xSleep: Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, microseconds: c_int) -> c_int>,
+    pub xCurrentTime:
+        Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, pTime: *mut c_double) -> c_int>,
+    pub xGetLastError: Option<
+        unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, nBuf: c_int, zBuf: *mut c_char) -> c_int,
+    >,
+    pub xCurrentTimeInt64:
+        Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, pTime: *mut sqlite3_int64) -> c_int>,
+    pub xSetSystemCall: Option<
+        unsafe extern "C" fn(
+            pVfs: *mut sqlite3_vfs,
+            zName: *const c_char,
+            pCall: sqlite3_syscall_ptr,
+        ) -> c_int,
+    >,
+    pub xGetSystemCall: Option<
+        unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, zName: *const c_char) -> sqlite3_syscall_ptr,
+    >,
+    pub xNextSystemCall:
+        Option<unsafe extern "C" fn(pVfs: *mut sqlite3_vfs, zName: *const c_char) -> *const c_char>,
+}
+
+/// A system call which a [VFS](sqlite3_vfs) allows to be overridden.
+pub type sqlite3_syscall_ptr = Option<unsafe extern "C" fn()>;
+
+/// An [open file][file] in a [VFS](sqlite3_vfs).
+///
+/// A VFS allocates [`szOsFile`](sqlite3_vfs::szOsFile) bytes for each file,
+/// beginning with this struct.
+///
+/// [file]: https://sqlite.org/c3ref/file.html
+#[repr(C)]
+pub struct sqlite3_file {
+    pub pMethods: *const sqlite3_io_methods,
+}
+
+/// The [methods][io] of an open [file](sqlite3_file).
+///
+/// [io]: https://sqlite.org/c3ref/io_methods.html
+#[repr(C)]
+pub struct sqlite3_io_methods {
+    pub iVersion: c_int,
+    pub xClose: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file) -> c_int>,
+    pub xRead: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            pBuf: *mut c_void,
+            iAmt: c_int,
+            iOfst: sqlite3_int64,
+        ) -> c_int,
+    >,
+    pub xWrite: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            pBuf: *const c_void,
+            iAmt: c_int,
+            iOfst: sqlite3_int64,
+        ) -> c_int,
+    >,
+    pub xTruncate:
+        Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, size: sqlite3_int64) -> c_int>,
+    pub xSync: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, flags: c_int) -> c_int>,
+    pub xFileSize:
+        Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, pSize: *mut sqlite3_int64) -> c_int>,
+    pub xLock: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, eLock: c_int) -> c_int>,
+    pub xUnlock: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, eLock: c_int) -> c_int>,
+    pub xCheckReservedLock:
+        Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, pResOut: *mut c_int) -> c_int>,
+    pub xFileControl: Option<
+        unsafe extern "C" fn(pFile: *mut sqlite3_file, op: c_int, pArg: *mut c_void) -> c_int,
+    >,
+    pub xSectorSize: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file) -> c_int>,
+    pub xDeviceCharacteristics: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file) -> c_int>,
+    pub xShmMap: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            iPg: c_int,
+            pgsz: c_int,
+            bExtend: c_int,
+            pp: *mut *mut c_void,
+        ) -> c_int,
+    >,
+    pub xShmLock: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            offset: c_int,
+            n: c_int,
+            flags: c_int,
+        ) -> c_int,
+    >,
+    pub xShmBarrier: Option<unsafe extern "C" fn(pFile: *mut sqlite3_file)>,
+    pub xShmUnmap:
+        Option<unsafe extern "C" fn(pFile: *mut sqlite3_file, deleteFlag: c_int) -> c_int>,
+    pub xFetch: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            iOfst: sqlite3_int64,
+            iAmt: c_int,
+            pp: *mut *mut c_void,
+        ) -> c_int,
+    >,
+    pub xUnfetch: Option<
+        unsafe extern "C" fn(
+            pFile: *mut sqlite3_file,
+            iOfst: sqlite3_int64,
+            p: *mut c_void,
+        ) -> c_int,
+    >,
+}
+
+unsafe extern "C" {
+    /// [Find][find] a registered VFS by name, or the default VFS if `zVfsName`
+    /// is null.
+    ///
+    /// [find]: https://sqlite.org/c3ref/vfs_find.html
+    pub fn sqlite3_vfs_find(zVfsName: *const c_char) -> *mut sqlite3_vfs;
+
+    /// [Register][register] a VFS, optionally making it the default.
+    ///
+    /// [register]: https://sqlite.org/c3ref/vfs_find.html
+    pub fn sqlite3_vfs_register(pVfs: *mut sqlite3_vfs, makeDflt: c_int) -> c_int;
+
+    /// [Unregister][unregister] a VFS.
+    ///
+    /// [unregister]: https://sqlite.org/c3ref/vfs_find.html
+    pub fn sqlite3_vfs_unregister(pVfs: *mut sqlite3_vfs) -> c_int;
+}
+
+pub const SQLITE_ACCESS_EXISTS: i32 = 0;
+pub const SQLITE_ACCESS_READWRITE: i32 = 1;
+pub const SQLITE_ACCESS_READ: i32 = 2;
+
+pub const SQLITE_LOCK_NONE: i32 = 0;
+pub const SQLITE_LOCK_SHARED: i32 = 1;
+pub const SQLITE_LOCK_RESERVED: i32 = 2;
+pub const SQLITE_LOCK_PENDING: i32 = 3;
+pub const SQLITE_LOCK_EXCLUSIVE: i32 = 4;
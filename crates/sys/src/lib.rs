@@ -10,6 +10,7 @@
 //! [SQLite]: https://sqlite.org/
 //! [C API]: https://sqlite.org/cintro.html
 
+#![no_std]
 #![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
 #![cfg_attr(docsrs, feature(doc_cfg), deny(rustdoc::broken_intra_doc_links))]
 
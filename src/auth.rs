@@ -0,0 +1,343 @@
+use core::{
+    ffi::{CStr, c_char, c_int, c_void},
+    ptr,
+};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use sqlite::{SQLITE_DENY, SQLITE_IGNORE, SQLITE_OK, sqlite3_set_authorizer};
+
+use crate::connection::Connection;
+
+pub(crate) type Authorizer = Box<dyn FnMut(AuthAction<'_>) -> AuthResult + Send>;
+
+impl Connection {
+    /// Register an [authorizer callback][authorizer], which is consulted as
+    /// each SQL statement is [prepared](Self::prepare), to allow or deny the
+    /// [actions](AuthAction) it would take.
+    ///
+    /// Use an authorizer to safely prepare SQL from untrusted sources. If the
+    /// callback panics, the action is [denied](AuthResult::Deny). Replaces any
+    /// previously registered authorizer.
+    ///
+    /// The callback must not use this connection.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use squire::{AuthAction, AuthResult, Connection};
+    ///
+    /// let connection = Connection::open(c":memory:")?;
+    /// connection.execute("CREATE TABLE users (name TEXT, password TEXT);", ())?;
+    ///
+    /// connection.set_authorizer(|action| match action {
+    ///     AuthAction::Read { column: Some("password"), .. } => AuthResult::Ignore,
+    ///     AuthAction::Read { .. } | AuthAction::Select => AuthResult::Allow,
+    ///     _ => AuthResult::Deny,
+    /// });
+    ///
+    /// assert!(connection.prepare("SELECT name, password FROM users;").is_ok());
+    /// assert!(connection.prepare("DELETE FROM users;").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [authorizer]: https://sqlite.org/c3ref/set_authorizer.html
+    #[cfg_attr(docsrs, doc(cfg(sqlite_has_authorization_callback)))]
+    #[doc(alias = "sqlite3_set_authorizer")]
+    pub fn set_authorizer<F>(&self, authorizer: F)
+    where
+        F: FnMut(AuthAction<'_>) -> AuthResult + Send + 'static,
+    {
+        let mut authorizer: Box<Authorizer> = Box::new(Box::new(authorizer));
+        let context = ptr::from_mut::<Authorizer>(&mut authorizer).cast::<c_void>();
+
+        let mut slot = self.authorizer();
+        unsafe {
+            sqlite3_set_authorizer(self.internal_ref().as_ptr(), Some(call_authorizer), context)
+        };
+        *slot = Some(authorizer);
+    }
+
+    /// Remove the [authorizer](Self::set_authorizer), if one is registered.
+    #[cfg_attr(docsrs, doc(cfg(sqlite_has_authorization_callback)))]
+    #[doc(alias = "sqlite3_set_authorizer")]
+    pub fn clear_authorizer(&self) {
+        let mut slot = self.authorizer();
+        unsafe { sqlite3_set_authorizer(self.internal_ref().as_ptr(), None, ptr::null_mut()) };
+        *slot = None;
+    }
+}
+
+unsafe extern "C" fn call_authorizer(
+    context: *mut c_void,
+    action: c_int,
+    first: *const c_char,
+    second: *const c_char,
+    _database: *const c_char,
+    _accessor: *const c_char,
+) -> c_int {
+    let authorizer = unsafe { &mut *context.cast::<Authorizer>() };
+    let action = unsafe { AuthAction::decode(action, text(first), text(second)) };
+
+    catch_unwind(AssertUnwindSafe(|| authorizer(action)))
+        .unwrap_or(AuthResult::Deny)
+        .raw()
+}
+
+/// Decode a string argument to the authorizer; `NULL` (or text which isn't
+/// valid UTF-8) becomes `None`.
+unsafe fn text<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(value) }.to_str().ok()
+    }
+}
+
+/// An action a SQL statement being [prepared](Connection::prepare) would
+/// take, passed to an [authorizer](Connection::set_authorizer).
+///
+/// Names which SQLite doesn't provide (or which aren't valid UTF-8) are
+/// `None`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum AuthAction<'a> {
+    #[doc(alias = "SQLITE_CREATE_INDEX")]
+    CreateIndex {
+        index: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_CREATE_TABLE")]
+    CreateTable { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_CREATE_TEMP_INDEX")]
+    CreateTempIndex {
+        index: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_CREATE_TEMP_TABLE")]
+    CreateTempTable { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_CREATE_TEMP_TRIGGER")]
+    CreateTempTrigger {
+        trigger: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_CREATE_TEMP_VIEW")]
+    CreateTempView { view: Option<&'a str> },
+    #[doc(alias = "SQLITE_CREATE_TRIGGER")]
+    CreateTrigger {
+        trigger: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_CREATE_VIEW")]
+    CreateView { view: Option<&'a str> },
+    #[doc(alias = "SQLITE_DELETE")]
+    Delete { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_DROP_INDEX")]
+    DropIndex {
+        index: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_DROP_TABLE")]
+    DropTable { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_DROP_TEMP_INDEX")]
+    DropTempIndex {
+        index: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_DROP_TEMP_TABLE")]
+    DropTempTable { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_DROP_TEMP_TRIGGER")]
+    DropTempTrigger {
+        trigger: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_DROP_TEMP_VIEW")]
+    DropTempView { view: Option<&'a str> },
+    #[doc(alias = "SQLITE_DROP_TRIGGER")]
+    DropTrigger {
+        trigger: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_DROP_VIEW")]
+    DropView { view: Option<&'a str> },
+    #[doc(alias = "SQLITE_INSERT")]
+    Insert { table: Option<&'a str> },
+    /// A `PRAGMA`, with its argument (if any).
+    #[doc(alias = "SQLITE_PRAGMA")]
+    Pragma {
+        name: Option<&'a str>,
+        value: Option<&'a str>,
+    },
+    /// Reading a table column. An authorizer which returns
+    /// [`Ignore`](AuthResult::Ignore) makes the column read as `NULL`.
+    #[doc(alias = "SQLITE_READ")]
+    Read {
+        table: Option<&'a str>,
+        column: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_SELECT")]
+    Select,
+    /// `BEGIN`, `COMMIT`, or `ROLLBACK`.
+    #[doc(alias = "SQLITE_TRANSACTION")]
+    Transaction { operation: Option<&'a str> },
+    /// Updating a table column. An authorizer which returns
+    /// [`Ignore`](AuthResult::Ignore) leaves the column unchanged.
+    #[doc(alias = "SQLITE_UPDATE")]
+    Update {
+        table: Option<&'a str>,
+        column: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_ATTACH")]
+    Attach { filename: Option<&'a str> },
+    #[doc(alias = "SQLITE_DETACH")]
+    Detach { database: Option<&'a str> },
+    #[doc(alias = "SQLITE_ALTER_TABLE")]
+    AlterTable {
+        database: Option<&'a str>,
+        table: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_REINDEX")]
+    Reindex { index: Option<&'a str> },
+    #[doc(alias = "SQLITE_ANALYZE")]
+    Analyze { table: Option<&'a str> },
+    #[doc(alias = "SQLITE_CREATE_VTABLE")]
+    CreateVirtualTable {
+        table: Option<&'a str>,
+        module: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_DROP_VTABLE")]
+    DropVirtualTable {
+        table: Option<&'a str>,
+        module: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_FUNCTION")]
+    Function { name: Option<&'a str> },
+    /// `SAVEPOINT`, `RELEASE`, or `ROLLBACK TO` a savepoint.
+    #[doc(alias = "SQLITE_SAVEPOINT")]
+    Savepoint {
+        operation: Option<&'a str>,
+        name: Option<&'a str>,
+    },
+    #[doc(alias = "SQLITE_RECURSIVE")]
+    Recursive,
+    /// An action code Squire doesn't know, with its raw arguments.
+    Other {
+        code: i32,
+        first: Option<&'a str>,
+        second: Option<&'a str>,
+    },
+}
+
+impl<'a> AuthAction<'a> {
+    fn decode(code: i32, first: Option<&'a str>, second: Option<&'a str>) -> Self {
+        match code {
+            sqlite::SQLITE_CREATE_INDEX => Self::CreateIndex {
+                index: first,
+                table: second,
+            },
+            sqlite::SQLITE_CREATE_TABLE => Self::CreateTable { table: first },
+            sqlite::SQLITE_CREATE_TEMP_INDEX => Self::CreateTempIndex {
+                index: first,
+                table: second,
+            },
+            sqlite::SQLITE_CREATE_TEMP_TABLE => Self::CreateTempTable { table: first },
+            sqlite::SQLITE_CREATE_TEMP_TRIGGER => Self::CreateTempTrigger {
+                trigger: first,
+                table: second,
+            },
+            sqlite::SQLITE_CREATE_TEMP_VIEW => Self::CreateTempView { view: first },
+            sqlite::SQLITE_CREATE_TRIGGER => Self::CreateTrigger {
+                trigger: first,
+                table: second,
+            },
+            sqlite::SQLITE_CREATE_VIEW => Self::CreateView { view: first },
+            sqlite::SQLITE_DELETE => Self::Delete { table: first },
+            sqlite::SQLITE_DROP_INDEX => Self::DropIndex {
+                index: first,
+                table: second,
+            },
+            sqlite::SQLITE_DROP_TABLE => Self::DropTable { table: first },
+            sqlite::SQLITE_DROP_TEMP_INDEX => Self::DropTempIndex {
+                index: first,
+                table: second,
+            },
+            sqlite::SQLITE_DROP_TEMP_TABLE => Self::DropTempTable { table: first },
+            sqlite::SQLITE_DROP_TEMP_TRIGGER => Self::DropTempTrigger {
+                trigger: first,
+                table: second,
+            },
+            sqlite::SQLITE_DROP_TEMP_VIEW => Self::DropTempView { view: first },
+            sqlite::SQLITE_DROP_TRIGGER => Self::DropTrigger {
+                trigger: first,
+                table: second,
+            },
+            sqlite::SQLITE_DROP_VIEW => Self::DropView { view: first },
+            sqlite::SQLITE_INSERT => Self::Insert { table: first },
+            sqlite::SQLITE_PRAGMA => Self::Pragma {
+                name: first,
+                value: second,
+            },
+            sqlite::SQLITE_READ => Self::Read {
+                table: first,
+                column: second,
+            },
+            sqlite::SQLITE_SELECT => Self::Select,
+            sqlite::SQLITE_TRANSACTION => Self::Transaction { operation: first },
+            sqlite::SQLITE_UPDATE => Self::Update {
+                table: first,
+                column: second,
+            },
+            sqlite::SQLITE_ATTACH => Self::Attach { filename: first },
+            sqlite::SQLITE_DETACH => Self::Detach { database: first },
+            sqlite::SQLITE_ALTER_TABLE => Self::AlterTable {
+                database: first,
+                table: second,
+            },
+            sqlite::SQLITE_REINDEX => Self::Reindex { index: first },
+            sqlite::SQLITE_ANALYZE => Self::Analyze { table: first },
+            sqlite::SQLITE_CREATE_VTABLE => Self::CreateVirtualTable {
+                table: first,
+                module: second,
+            },
+            sqlite::SQLITE_DROP_VTABLE => Self::DropVirtualTable {
+                table: first,
+                module: second,
+            },
+            sqlite::SQLITE_FUNCTION => Self::Function { name: second },
+            sqlite::SQLITE_SAVEPOINT => Self::Savepoint {
+                operation: first,
+                name: second,
+            },
+            sqlite::SQLITE_RECURSIVE => Self::Recursive,
+            code => Self::Other {
+                code,
+                first,
+                second,
+            },
+        }
+    }
+}
+
+/// An [authorizer](Connection::set_authorizer)’s decision on an
+/// [action](AuthAction).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum AuthResult {
+    /// Allow the action.
+    #[doc(alias = "SQLITE_OK")]
+    Allow = SQLITE_OK,
+    /// Reject the whole SQL statement with an
+    /// [authorization error](crate::ErrorCategory::Authorization).
+    #[doc(alias = "SQLITE_DENY")]
+    Deny = SQLITE_DENY,
+    /// Disallow the specific action, but continue preparing the statement
+    /// (e.g., by reading a column as `NULL`).
+    #[doc(alias = "SQLITE_IGNORE")]
+    Ignore = SQLITE_IGNORE,
+}
+
+impl AuthResult {
+    /// The SQLite result code for this decision.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+}
@@ -0,0 +1,195 @@
+use core::{marker::PhantomData, mem::ManuallyDrop, ops::ControlFlow, ptr, time::Duration};
+use std::{ffi::CString, thread};
+
+use sqlite::{
+    SQLITE_BUSY, SQLITE_DONE, SQLITE_LOCKED, SQLITE_OK, sqlite3_backup, sqlite3_backup_finish,
+    sqlite3_backup_init, sqlite3_backup_pagecount, sqlite3_backup_remaining, sqlite3_backup_step,
+};
+
+use crate::{
+    connection::Connection,
+    error::{Error, ErrorCategory, Result},
+};
+
+/// How long [`Backup::run_with_progress`] waits before retrying a step which
+/// found the source database busy or locked.
+const BUSY_DELAY: Duration = Duration::from_millis(5);
+
+impl Connection {
+    /// Start an [online backup][backup], copying the `schema` database (e.g.,
+    /// `"main"`) of this connection into the `destination_schema` database of
+    /// `destination`.
+    ///
+    /// No pages are copied until the [`Backup`] is [stepped](Backup::step).
+    /// `destination` is borrowed mutably, because SQLite requires that it
+    /// isn't used for anything else while the backup is in progress.
+    ///
+    /// [backup]: https://sqlite.org/backup.html
+    #[doc(alias = "sqlite3_backup_init")]
+    pub fn backup<'c>(
+        &'c self,
+        schema: &str,
+        destination: &'c mut Connection,
+        destination_schema: &str,
+    ) -> Result<Backup<'c>> {
+        let destination = &*destination;
+        let schema = CString::new(schema)?;
+        let destination_schema = CString::new(destination_schema)?;
+
+        let handle = unsafe {
+            sqlite3_backup_init(
+                destination.internal_ref().as_ptr(),
+                destination_schema.as_ptr(),
+                self.internal_ref().as_ptr(),
+                schema.as_ptr(),
+            )
+        };
+
+        match ptr::NonNull::new(handle) {
+            Some(handle) => Ok(Backup {
+                handle,
+                destination,
+                _source: PhantomData,
+            }),
+            None => {
+                let (code, _) = unsafe { destination.internal_ref().last_error() };
+                Err(
+                    Error::from_connection(destination.internal_ref(), code).unwrap_or_else(
+                        #[cold]
+                        || Error::from(ErrorCategory::OutOfMemory),
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// An [online backup][backup] in progress, started by
+/// [`Connection::backup`].
+///
+/// Dropping a `Backup` before it's done abandons it, rolling back any pages
+/// already written to the destination.
+///
+/// [backup]: https://sqlite.org/c3ref/backup.html
+pub struct Backup<'c> {
+    handle: ptr::NonNull<sqlite3_backup>,
+    destination: &'c Connection,
+    _source: PhantomData<&'c Connection>,
+}
+
+impl<'c> Backup<'c> {
+    /// Copy up to `pages` pages into the destination database, or every
+    /// remaining page if `pages` is negative.
+    ///
+    /// Returns `true` once the entire database has been copied.
+    #[doc(alias = "sqlite3_backup_step")]
+    pub fn step(&mut self, pages: i32) -> Result<bool> {
+        match unsafe { sqlite3_backup_step(self.as_ptr(), pages) } {
+            SQLITE_OK => Ok(false),
+            SQLITE_DONE => Ok(true),
+            code => Err(self.error(code)),
+        }
+    }
+
+    /// Copy the database `pages_per_step` pages at a time, calling `progress`
+    /// after each step.
+    ///
+    /// If the source database is busy or locked, `progress` is still called,
+    /// and the step is tried again after a short wait. If `progress` returns
+    /// [`Break`](ControlFlow::Break), the backup is abandoned, rolling back
+    /// the pages already copied, and `run_with_progress` returns `Ok(())`.
+    pub fn run_with_progress(
+        self,
+        pages_per_step: i32,
+        mut progress: impl FnMut(BackupProgress) -> ControlFlow<()>,
+    ) -> Result<()> {
+        loop {
+            let (done, busy) = match unsafe { sqlite3_backup_step(self.as_ptr(), pages_per_step) } {
+                SQLITE_OK => (false, false),
+                SQLITE_DONE => (true, false),
+                code if is_busy(code) => (false, true),
+                code => return Err(self.error(code)),
+            };
+
+            if progress(self.progress()).is_break() || done {
+                // Abandoning a backup while busy isn't a failure, though
+                // `sqlite3_backup_finish` repeats the busy step's error.
+                return match self.finish() {
+                    Err(err) if busy && is_busy(err.code().raw()) => Ok(()),
+                    result => result,
+                };
+            }
+            if busy {
+                thread::sleep(BUSY_DELAY);
+            }
+        }
+    }
+
+    /// The number of pages left to copy, and the total number of pages in the
+    /// source database, as of the last [step](Self::step).
+    #[doc(alias = "sqlite3_backup_remaining")]
+    #[doc(alias = "sqlite3_backup_pagecount")]
+    pub fn progress(&self) -> BackupProgress {
+        let (remaining, total) = unsafe {
+            (
+                sqlite3_backup_remaining(self.as_ptr()),
+                sqlite3_backup_pagecount(self.as_ptr()),
+            )
+        };
+
+        BackupProgress {
+            remaining: remaining.max(0) as u32,
+            total: total.max(0) as u32,
+        }
+    }
+
+    /// Release the backup, abandoning it if it isn't [done](Self::step).
+    #[doc(alias = "sqlite3_backup_finish")]
+    pub fn finish(self) -> Result<()> {
+        let backup = ManuallyDrop::new(self);
+
+        match unsafe { sqlite3_backup_finish(backup.as_ptr()) } {
+            SQLITE_OK => Ok(()),
+            code => Err(backup.error(code)),
+        }
+    }
+
+    /// Access the raw [`sqlite3_backup`] pointer.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut sqlite3_backup {
+        self.handle.as_ptr()
+    }
+
+    #[cold]
+    fn error(&self, code: i32) -> Error {
+        Error::from_connection(self.destination.internal_ref(), code)
+            .unwrap_or_else(|| Error::from(ErrorCategory::Unknown))
+    }
+}
+
+impl Drop for Backup<'_> {
+    #[doc(alias = "sqlite3_backup_finish")]
+    fn drop(&mut self) {
+        unsafe { sqlite3_backup_finish(self.as_ptr()) };
+    }
+}
+
+/// The state of a [`Backup`] after a step.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct BackupProgress {
+    /// The number of pages still to be copied.
+    pub remaining: u32,
+    /// The total number of pages in the source database.
+    pub total: u32,
+}
+
+impl BackupProgress {
+    /// The number of pages copied so far.
+    pub const fn copied(&self) -> u32 {
+        self.total.saturating_sub(self.remaining)
+    }
+}
+
+fn is_busy(code: i32) -> bool {
+    matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED)
+}
@@ -1,8 +1,11 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::num::NonZero;
+
 use crate::{
     blob::Reservation,
     error::{Error, ErrorCode, Result},
     ffi,
-    types::{Borrowed, RowId},
+    types::{Borrowed, Null, RowId, StrictBool},
 };
 
 /// A value which can be [bound as a parameter][bind] in SQLite [prepared
@@ -65,15 +68,7 @@ macro_rules! primitive {
 }
 
 primitive!(
-    char as i32,
-    f32 as f64,
-    f64 as f64,
-    i8 as i32,
-    u8 as i32,
-    i16 as i32,
-    u16 as i32,
-    i32 as i32,
-    u32 as i64,
+    f32 as f64, f64 as f64, i8 as i32, u8 as i32, i16 as i32, u16 as i32, i32 as i32, u32 as i64,
     i64 as i64,
 );
 
@@ -98,6 +93,44 @@ impl<'b> Bind<'b> for u64 {
     }
 }
 
+/// Binds an [`i128`] as an integer, if it fits in an `i64`; otherwise, returns
+/// a [range error](crate::ParameterError::Range). To store the full range of
+/// `i128` values, bind a [`Wide<i128>`](crate::Wide).
+impl<'b> Bind<'b> for i128 {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        i64::try_from(self).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RANGE,
+                    "i128 value cannot fit in i64 parameter",
+                )
+            },
+        )
+    }
+}
+
+/// Binds a [`u128`] as an integer, if it fits in an `i64`; otherwise, returns
+/// a [range error](crate::ParameterError::Range). To store the full range of
+/// `u128` values, bind a [`Wide<u128>`](crate::Wide).
+impl<'b> Bind<'b> for u128 {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        i64::try_from(self).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RANGE,
+                    "u128 value cannot fit in i64 parameter",
+                )
+            },
+        )
+    }
+}
+
 #[cfg(target_pointer_width = "64")]
 impl<'b> Bind<'b> for usize {
     type Value = i64;
@@ -115,6 +148,25 @@ impl<'b> Bind<'b> for usize {
     }
 }
 
+/// Defines [`Bind`] for a [`NonZero`] integer, binding it like the underlying
+/// integer type.
+macro_rules! nonzero {
+    ($($t:ty),+) => {
+        $(
+            impl<'b> Bind<'b> for NonZero<$t> {
+                type Value = <$t as Bind<'b>>::Value;
+
+                #[inline]
+                fn into_bind_value(self) -> Result<Self::Value> {
+                    self.get().into_bind_value()
+                }
+            }
+        )+
+    };
+}
+
+nonzero!(i32, i64, u32, u64);
+
 /// [`bool`] values are bound as `1` (for `true`) or `0` (for `false`).
 impl<'b> Bind<'b> for bool {
     type Value = i32;
@@ -124,7 +176,15 @@ impl<'b> Bind<'b> for bool {
     }
 }
 
-identity!(&str, String, &[u8], Vec<u8>, Reservation);
+impl<'b> Bind<'b> for StrictBool {
+    type Value = i32;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        self.0.into_bind_value()
+    }
+}
+
+identity!(char, &str, String, &[u8], Vec<u8>, Null, Reservation);
 
 impl<const N: usize> Bind<'_> for [u8; N] {
     type Value = Self;
@@ -134,6 +194,17 @@ impl<const N: usize> Bind<'_> for [u8; N] {
     }
 }
 
+impl<'a, 'b, const N: usize> Bind<'b> for &'a [u8; N]
+where
+    'a: 'b,
+{
+    type Value = &'a [u8];
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self.as_slice())
+    }
+}
+
 impl<'b> Bind<'b> for RowId {
     type Value = i64;
 
@@ -166,6 +237,30 @@ where
     }
 }
 
+impl<'a, 'b> Bind<'b> for Cow<'a, str>
+where
+    'a: 'b,
+{
+    type Value = Self;
+
+    #[inline]
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self)
+    }
+}
+
+impl<'a, 'b> Bind<'b> for Cow<'a, [u8]>
+where
+    'a: 'b,
+{
+    type Value = Self;
+
+    #[inline]
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self)
+    }
+}
+
 impl<'b, T> Bind<'b> for Option<T>
 where
     T: Bind<'b>,
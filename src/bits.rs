@@ -0,0 +1,106 @@
+//! Store types with an integer representation, like [`bitflags`][] structs,
+//! as SQLite `INTEGER`s.
+//!
+//! Any type which converts [into](Into) and [from](TryFrom) an [`i64`] can be
+//! bound and fetched as a [`Bits`]. With the `bind_with` and `fetch_with`
+//! attributes of [`Parameters`](crate::Parameters) and
+//! [`Columns`](crate::Columns), the field itself can keep its own type:
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! # use squire::{Columns, Connection, Parameters};
+//! #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+//! struct Permissions(u8);
+//!
+//! impl From<Permissions> for i64 {
+//!     fn from(permissions: Permissions) -> i64 {
+//!         permissions.0.into()
+//!     }
+//! }
+//!
+//! impl TryFrom<i64> for Permissions {
+//!     type Error = core::num::TryFromIntError;
+//!
+//!     fn try_from(bits: i64) -> Result<Self, Self::Error> {
+//!         u8::try_from(bits).map(Self)
+//!     }
+//! }
+//!
+//! #[derive(Columns, Parameters)]
+//! struct User {
+//!     name: String,
+//!     #[squire(bind_with = squire::bits::to_i64, fetch_with = squire::bits::from_i64)]
+//!     permissions: Permissions,
+//! }
+//! # fn main() -> squire::Result<()> {
+//! # let connection = Connection::open(c":memory:")?;
+//! # connection.execute("CREATE TABLE users (name TEXT, permissions INTEGER)", ())?;
+//! # let user = User { name: "ada".to_owned(), permissions: Permissions(0b101) };
+//! # connection.execute("INSERT INTO users VALUES (:name, :permissions)", user)?;
+//! # let user: User = connection.prepare("SELECT name, permissions FROM users")?.query(())?.one()?;
+//! # assert_eq!(Permissions(0b101), user.permissions);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`bitflags`]: https://docs.rs/bitflags
+
+use alloc::format;
+
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCode, Result},
+    fetch::Fetch,
+};
+
+/// A value [bound](Bind) and [fetched](Fetch) as its `i64` representation.
+///
+/// Fetching returns a [range error](crate::FetchError::Range) if `T` rejects
+/// the stored integer.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct Bits<T>(pub T);
+
+impl<T> Bits<T> {
+    /// Unwraps the `Bits` wrapper, returning the inner flags value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'b, T: Into<i64>> Bind<'b> for Bits<T> {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self.0.into())
+    }
+}
+
+impl<'r, T: TryFrom<i64>> Fetch<'r> for Bits<T> {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        T::try_from(value).map(Self).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_RANGE,
+                    format!(
+                        "{value} is out of range for {}",
+                        core::any::type_name::<T>()
+                    ),
+                )
+            },
+        )
+    }
+}
+
+/// Convert `value` to the `i64` it's bound as; for `bind_with`.
+pub fn to_i64<T: Into<i64>>(value: T) -> i64 {
+    value.into()
+}
+
+/// Unwrap a [`Bits`] fetched from an `INTEGER` column; for `fetch_with`.
+pub fn from_i64<T: TryFrom<i64>>(value: Bits<T>) -> T {
+    value.0
+}
@@ -3,6 +3,12 @@
 /// When a `Reservation` is [used](crate::Bind) as a prepared
 /// [statement](crate::Statement) parameter, SQLite will create a `BLOB` of the
 /// [requested length](Reservation::len()) and set every byte in the blob to `\0`.
+///
+/// Reserving a blob in an `INSERT` [statement](crate::Statement::insert)
+/// returns the [`RowId`](crate::RowId) of the new row, which identifies the
+/// blob to fill in later (for example, with [incremental blob I/O][blob]).
+///
+/// [blob]: https://sqlite.org/c3ref/blob_open.html
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 #[repr(transparent)]
 pub struct Reservation(isize);
@@ -14,6 +20,18 @@ impl Reservation {
         Self(bytes)
     }
 
+    /// Create a [`Reservation`] of `len` bytes.
+    ///
+    /// Lengths greater than [`isize::MAX`] are clamped to `isize::MAX`, which
+    /// SQLite will reject as [too big](crate::ErrorCategory::TooBig).
+    pub const fn with_len(len: usize) -> Self {
+        if len > isize::MAX as usize {
+            Self(isize::MAX)
+        } else {
+            Self(len as isize)
+        }
+    }
+
     /// The number of bytes to reserve, as a [`usize`].
     pub const fn len(&self) -> usize {
         self.0 as usize
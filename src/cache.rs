@@ -0,0 +1,138 @@
+use alloc::{boxed::Box, collections::VecDeque};
+use core::{
+    fmt,
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{connection::Connection, ffi, statement::Statement};
+
+/// A least-recently-used cache of prepared statements, keyed by SQL text.
+///
+/// The most recently used statement is at the front of `entries`.
+pub(crate) struct StatementCache {
+    capacity: usize,
+    entries: VecDeque<(Box<str>, ffi::Statement<'static>)>,
+}
+
+// SAFETY: Cached statements are owned by their `Connection`, and only move
+// between threads together with it.
+#[cfg(all(feature = "multi-thread", not(feature = "serialized")))]
+unsafe impl Send for StatementCache {}
+
+impl StatementCache {
+    pub(crate) const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    /// Remove the statement prepared from `sql` from the cache, if present.
+    pub(crate) fn take<'c>(&mut self, sql: &str) -> Option<ffi::Statement<'c>> {
+        let position = self.entries.iter().position(|(key, _)| &**key == sql)?;
+        let (_, statement) = self.entries.remove(position)?;
+
+        ffi::Statement::new(statement.as_ptr())
+    }
+
+    /// Return a statement to the cache, evicting (and finalizing) the least
+    /// recently used statement if the cache is full.
+    pub(crate) fn insert(&mut self, sql: Box<str>, statement: ffi::Statement<'_>) {
+        let Some(statement) = ffi::Statement::new(statement.as_ptr()) else {
+            return;
+        };
+
+        if self.capacity == 0 {
+            let _ = statement.close();
+            return;
+        }
+
+        if let Some(position) = self.entries.iter().position(|(key, _)| *key == sql)
+            && let Some((_, existing)) = self.entries.remove(position)
+        {
+            let _ = existing.close();
+        }
+
+        while self.entries.len() >= self.capacity {
+            if let Some((_, evicted)) = self.entries.pop_back() {
+                let _ = evicted.close();
+            }
+        }
+
+        self.entries.push_front((sql, statement));
+    }
+
+    /// Finalize every cached statement.
+    pub(crate) fn clear(&mut self) {
+        for (_, statement) in mem::take(&mut self.entries) {
+            let _ = statement.close();
+        }
+    }
+}
+
+impl fmt::Debug for StatementCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatementCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+/// A [`Statement`] borrowed from a [`Connection`]’s statement cache.
+///
+/// Returned by [`Connection::prepare_cached`]. A `CachedStatement`
+/// dereferences to [`Statement`]; when dropped, the statement is
+/// [reset](Statement::reset), its [bindings cleared](Statement::clear_bindings),
+/// and returned to the cache instead of being finalized.
+pub struct CachedStatement<'c> {
+    connection: &'c Connection,
+    sql: Box<str>,
+    statement: ManuallyDrop<Statement<'c>>,
+}
+
+impl<'c> CachedStatement<'c> {
+    pub(crate) fn new(connection: &'c Connection, sql: &str, statement: Statement<'c>) -> Self {
+        Self {
+            connection,
+            sql: sql.into(),
+            statement: ManuallyDrop::new(statement),
+        }
+    }
+}
+
+impl<'c> Deref for CachedStatement<'c> {
+    type Target = Statement<'c>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.statement
+    }
+}
+
+impl DerefMut for CachedStatement<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.statement
+    }
+}
+
+impl fmt::Debug for CachedStatement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CachedStatement")
+            .field(&*self.statement)
+            .finish()
+    }
+}
+
+impl Drop for CachedStatement<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `statement` is not accessed again after being taken.
+        let mut statement = unsafe { ManuallyDrop::take(&mut self.statement) };
+        let _ = statement.reset();
+        let _ = statement.clear_bindings();
+
+        self.connection
+            .statement_cache()
+            .insert(mem::take(&mut self.sql), statement.into_inner());
+    }
+}
@@ -1,15 +1,99 @@
+use alloc::vec::Vec;
 use core::fmt;
 
-use crate::{error::Result, fetch::Fetch, statement::Statement, types::ColumnIndex};
+use crate::{
+    error::Result,
+    fetch::Fetch,
+    statement::Statement,
+    types::{ColumnIndex, Value},
+};
 
 /// Specifies the [`ColumnIndex`] values needed by [`Columns`].
+///
+/// Hand-written implementations can find their columns with
+/// [`resolve_named`] or [`resolve_positional`].
 pub trait ColumnIndexes {
     type Indexes: Copy + fmt::Debug + Sized;
 
     fn resolve<'c>(statement: &Statement<'c>) -> Option<Self::Indexes>;
+
+    /// Resolve [`Indexes`](Self::Indexes), looking for named columns at or
+    /// after `start`.
+    ///
+    /// Also returns the position following the last column resolved, so that
+    /// column sets flattened with `#[squire(flatten)]` can find their columns
+    /// in order when column names repeat.
+    ///
+    /// The default implementation ignores `start` and calls
+    /// [`resolve`](Self::resolve).
+    fn resolve_from<'c>(
+        statement: &Statement<'c>,
+        start: ColumnIndex,
+    ) -> Option<(Self::Indexes, ColumnIndex)> {
+        Self::resolve(statement).map(|indexes| (indexes, start))
+    }
+}
+
+/// Resolve the [`ColumnIndex`] of each of the named columns, in order, for a
+/// hand-written [`ColumnIndexes::resolve`].
+///
+/// Returns `None` if the statement has no column with one of the `names`.
+///
+/// ```
+/// # use squire::{ColumnIndex, ColumnIndexes, Statement};
+/// struct Point;
+///
+/// impl ColumnIndexes for Point {
+///     type Indexes = [ColumnIndex; 2];
+///
+///     fn resolve<'c>(statement: &Statement<'c>) -> Option<Self::Indexes> {
+///         squire::resolve_named(statement, &["x", "y"])
+///     }
+/// }
+/// ```
+pub fn resolve_named<const N: usize>(
+    statement: &Statement<'_>,
+    names: &[&str; N],
+) -> Option<[ColumnIndex; N]> {
+    resolve_named_from(statement, names, ColumnIndex::INITIAL)
+}
+
+/// Like [`resolve_named`], but only find columns at or after `start`, for a
+/// hand-written [`ColumnIndexes::resolve_from`].
+pub fn resolve_named_from<const N: usize>(
+    statement: &Statement<'_>,
+    names: &[&str; N],
+    start: ColumnIndex,
+) -> Option<[ColumnIndex; N]> {
+    let columns = statement.columns();
+    let mut indexes = [ColumnIndex::INITIAL; N];
+
+    for (index, name) in indexes.iter_mut().zip(names) {
+        *index = columns.index_from(name, start)?;
+    }
+
+    Some(indexes)
+}
+
+/// The first `N` [column indexes](ColumnIndex), for [`Columns`] fetched in
+/// the statement's column order.
+pub const fn resolve_positional<const N: usize>() -> [ColumnIndex; N] {
+    let mut indexes = [ColumnIndex::INITIAL; N];
+
+    let mut i = 1;
+    while i < N {
+        indexes[i] = indexes[i - 1].next();
+        i += 1;
+    }
+
+    indexes
 }
 
 /// [Fetches](Fetch) the values of each column in a row.
+///
+/// `Columns` is implemented for tuples of up to 32 [`Fetch`] types, fetched
+/// from the row’s columns in order. Each element may independently borrow
+/// from the row (e.g., `(Borrowed<str>, i64, &[u8])`), without copying.
 pub trait Columns<'r>: ColumnIndexes + Sized {
     fn fetch<'c>(statement: &'r Statement<'c>, indexes: Self::Indexes) -> Result<Self>
     where
@@ -40,6 +124,30 @@ where
     }
 }
 
+impl ColumnIndexes for Vec<Value> {
+    type Indexes = ();
+
+    #[inline(always)]
+    fn resolve<'c>(_statement: &Statement<'c>) -> Option<Self::Indexes> {
+        Some(())
+    }
+}
+
+/// Fetch every column in the row, in order, as a dynamically-typed [`Value`].
+impl<'r> Columns<'r> for Vec<Value> {
+    fn fetch<'c>(statement: &'r Statement<'c>, _indexes: Self::Indexes) -> Result<Self>
+    where
+        'c: 'r,
+    {
+        let statement = statement.internal_ref();
+        let count = statement.column_count();
+
+        (0..count)
+            .map(|column| unsafe { Value::fetch_column(statement, ColumnIndex::new(column)) })
+            .collect()
+    }
+}
+
 /// Implement [`Columns`] for a tuple type.
 macro_rules! tuple {
     ($i:ident: $t:ident) => {
@@ -101,16 +209,23 @@ macro_rules! tuple {
     };
 }
 
-tuple!(a: A);
-tuple!(a: A, b: B);
-tuple!(a: A, b: B, c: C);
-tuple!(a: A, b: B, c: C, d: D);
-tuple!(a: A, b: B, c: C, d: D, e: E);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M);
+/// Implement [`Columns`] for each tuple type, up to the arity of the arguments.
+macro_rules! tuples {
+    ($($i:ident: $t:ident),+ $(,)?) => {
+        tuples!(@ [] $($i: $t),+);
+    };
+
+    (@ [$($ai:ident: $at:ident),*] $i:ident: $t:ident $(, $ri:ident: $rt:ident)*) => {
+        tuple!($($ai: $at,)* $i: $t);
+        tuples!(@ [$($ai: $at,)* $i: $t] $($ri: $rt),*);
+    };
+
+    (@ [$($ai:ident: $at:ident),*]) => {};
+}
+
+tuples!(
+    a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H,
+    i: I, j: J, k: K, l: L, m: M, n: N, o: O, p: P,
+    q: Q, r: R, s: S, t: T, u: U, v: V, w: W, x: X,
+    y: Y, z: Z, aa: AA, ab: AB, ac: AC, ad: AD, ae: AE, af: AF,
+);
@@ -0,0 +1,47 @@
+use alloc::vec::Vec;
+use core::ffi::CStr;
+
+use sqlite::sqlite3_complete;
+
+/// Check whether `sql` ends with a complete SQL statement: one terminated by
+/// a semicolon that isn’t inside a string literal, identifier, comment, or
+/// `CREATE TRIGGER` body.
+///
+/// A SQL shell can use `is_complete` to keep reading lines of input until the
+/// user has finished typing a statement. `is_complete` doesn’t otherwise
+/// check that the SQL is valid.
+///
+/// ```rust
+/// assert!(squire::is_complete("SELECT 1;"));
+/// assert!(!squire::is_complete("SELECT ';"));
+/// assert!(!squire::is_complete("SELECT 1 -- done;"));
+/// ```
+///
+/// SQLite reads `sql` only up to its first NUL character, so any text after
+/// an interior NUL is ignored: `"SELECT 1;\0 SELECT"` is complete. If `sql`
+/// contains a NUL, it is passed to SQLite as-is. Otherwise it is copied to add
+/// the terminator, into a stack buffer if it is short (under 256 bytes), or
+/// else into a heap allocation.
+#[doc(alias = "sqlite3_complete")]
+pub fn is_complete(sql: &str) -> bool {
+    const STACK: usize = 256;
+
+    let bytes = sql.as_bytes();
+
+    if let Ok(sql) = CStr::from_bytes_until_nul(bytes) {
+        complete(sql)
+    } else if bytes.len() < STACK {
+        let mut buffer = [0u8; STACK];
+        buffer[..bytes.len()].copy_from_slice(bytes);
+        complete(unsafe { CStr::from_bytes_with_nul_unchecked(&buffer[..=bytes.len()]) })
+    } else {
+        let mut buffer = Vec::with_capacity(bytes.len() + 1);
+        buffer.extend_from_slice(bytes);
+        buffer.push(0);
+        complete(unsafe { CStr::from_bytes_with_nul_unchecked(&buffer) })
+    }
+}
+
+fn complete(sql: &CStr) -> bool {
+    unsafe { sqlite3_complete(sql.as_ptr()) != 0 }
+}
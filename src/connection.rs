@@ -1,16 +1,60 @@
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(sqlite_has_column_metadata)]
+use alloc::{borrow::ToOwned, string::String};
+use alloc::{ffi::CString, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::cell::{RefCell, RefMut};
+#[cfg(feature = "std")]
+use core::time::Duration;
 use core::{fmt, mem};
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+#[cfg(feature = "std")]
+use std::{thread, time::Instant};
 
+#[cfg(feature = "std")]
 use sqlite::{
-    SQLITE_OPEN_CREATE, SQLITE_OPEN_FULLMUTEX, SQLITE_OPEN_NOFOLLOW, SQLITE_OPEN_NOMUTEX,
-    SQLITE_OPEN_READONLY, SQLITE_OPEN_READWRITE, SQLITE_OPEN_URI,
+    SQLITE_DBCONFIG_DEFENSIVE, SQLITE_DBCONFIG_ENABLE_FKEY, SQLITE_DBCONFIG_ENABLE_TRIGGER,
+    SQLITE_DBCONFIG_ENABLE_VIEW, SQLITE_DBCONFIG_TRUSTED_SCHEMA, SQLITE_OPEN_CREATE,
+    SQLITE_OPEN_FULLMUTEX, SQLITE_OPEN_NOFOLLOW, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READONLY,
+    SQLITE_OPEN_READWRITE, SQLITE_OPEN_URI,
 };
+#[cfg(all(feature = "std", sqlite_has_shared_cache))]
+use sqlite::{SQLITE_OPEN_PRIVATECACHE, SQLITE_OPEN_SHAREDCACHE};
 
+#[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+use crate::auth::Authorizer;
+#[cfg(any(
+    feature = "std",
+    all(feature = "functions", not(sqlite_has_window_function))
+))]
+use crate::error::{Error, ErrorCode};
+#[cfg(sqlite_has_json)]
+use crate::fetch::Fetch;
+#[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+use crate::hook::PreUpdateHook;
+#[cfg(sqlite_has_serialize)]
+use crate::types::DeserializeFlags;
+#[cfg(feature = "functions")]
+use crate::types::FunctionOptions;
 use crate::{
-    endpoint::{Endpoint, IntoEndpoint, Local, Vfs},
-    error::Result,
+    cache::{CachedStatement, StatementCache},
+    error::{ErrorCategory, Result},
     ffi,
     param::Parameters,
+    pragma::CheckpointMode,
     statement::{PrepareOptions, Statement},
+    status::DbStatus,
+    types::{Limit, TxnState},
+};
+#[cfg(feature = "std")]
+use crate::{
+    endpoint::{Endpoint, IntoEndpoint, Local, Vfs},
+    pragma::CacheSize,
+    vfs,
 };
 
 /// A _connection_ to one or more open SQLite database(s).
@@ -22,6 +66,18 @@ use crate::{
 /// (Even though SQLite is a local database, without a network or socket
 /// connection to a remote server, SQLite still uses the term “connection”.)
 ///
+/// # Thread safety
+///
+/// With the `multi-thread` feature, a `Connection` is [`Send`]: it can be
+/// moved to another thread, but only used by one thread at a time. With the
+/// `serialized` feature, connections are opened with `SQLITE_OPEN_FULLMUTEX`,
+/// so SQLite serializes every call on the connection, and `Connection` is
+/// also [`Sync`].
+///
+/// A [`Statement`] borrows its `Connection`; it is only `Send` with the
+/// `serialized` feature, since in multi-thread mode it must be used on the
+/// same thread as its connection.
+///
 /// # Examples
 ///
 /// ```rust
@@ -46,19 +102,89 @@ use crate::{
 /// ```
 pub struct Connection {
     inner: ffi::Connection,
+    #[cfg(feature = "std")]
+    cache: Mutex<StatementCache>,
+    #[cfg(not(feature = "std"))]
+    cache: RefCell<StatementCache>,
+    #[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+    authorizer: Mutex<Option<Box<Authorizer>>>,
+    #[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+    preupdate_hook: Mutex<Option<Box<PreUpdateHook>>>,
+    checkpoint_on_close: Option<CheckpointMode>,
+    owned: bool,
 }
 
 impl Connection {
     #[inline]
     #[must_use]
-    fn new(inner: ffi::Connection) -> Self {
-        Self { inner }
+    fn new(inner: ffi::Connection, statement_cache_capacity: usize) -> Self {
+        Self {
+            inner,
+            #[cfg(feature = "std")]
+            cache: Mutex::new(StatementCache::new(statement_cache_capacity)),
+            #[cfg(not(feature = "std"))]
+            cache: RefCell::new(StatementCache::new(statement_cache_capacity)),
+            #[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+            authorizer: Mutex::new(None),
+            #[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+            preupdate_hook: Mutex::new(None),
+            checkpoint_on_close: None,
+            owned: true,
+        }
+    }
+
+    /// Wrap a raw [`sqlite3`](sqlite::sqlite3) connection pointer that was
+    /// opened elsewhere (e.g., by C code sharing the connection with Rust).
+    ///
+    /// If `owned` is `true`, the `Connection` takes ownership of the pointer,
+    /// and closes it when [closed](Self::close) or dropped. Otherwise, closing
+    /// or dropping the `Connection` only finalizes the statements Squire
+    /// prepared on it, and leaves the connection open.
+    ///
+    /// SQLite allows only one authorizer and one pre-update hook per
+    /// connection. Setting either through the returned `Connection` replaces
+    /// any the other user of the connection installed, and if `owned` is
+    /// `false`, closing or dropping the `Connection` clears the hooks it set
+    /// (without restoring the previous ones). Hooks it didn't set are left
+    /// alone.
+    ///
+    /// As with [`open`](Self::open), the returned `Connection`'s statement
+    /// cache is disabled, so [`prepare_cached`](Self::prepare_cached)
+    /// prepares a new statement each time.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a valid, open SQLite connection. If `owned` is `false`,
+    /// the connection must stay open for as long as the returned `Connection`
+    /// (and any statements prepared with it) are in use; if `owned` is `true`,
+    /// nothing else may close it.
+    ///
+    /// With the `multi-thread` feature, the returned `Connection` is [`Send`]
+    /// (and with `serialized`, [`Sync`]), so it may be used from other
+    /// threads. Unless `ptr` was opened with `SQLITE_OPEN_FULLMUTEX` (or
+    /// SQLite is in [serialized] threading mode), the caller must ensure the
+    /// connection is never used by two threads at once, including by the code
+    /// that opened it. With `serialized`, `ptr` must have been opened with
+    /// `SQLITE_OPEN_FULLMUTEX`.
+    ///
+    /// [serialized]: https://sqlite.org/threadsafe.html
+    #[must_use = "an owned Connection will be closed if dropped"]
+    pub unsafe fn from_raw(ptr: *mut sqlite::sqlite3, owned: bool) -> Self {
+        debug_assert!(!ptr.is_null(), "Connection::from_raw given a null pointer");
+
+        let inner = unsafe { ffi::Connection::new_unchecked(ptr) };
+        let mut connection = Connection::new(inner, 0);
+        connection.owned = owned;
+        connection
     }
 
     /// Open a read/write [`Connection`] to a [database](Endpoint).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     #[must_use = "a Connection will be closed if dropped"]
     pub fn open<E: IntoEndpoint>(endpoint: E) -> Result<Self> {
         let endpoint = endpoint.into_endpoint();
+        vfs::check_vfs(endpoint.vfs())?;
 
         let connection = ffi::Connection::open(
             endpoint.location(),
@@ -66,7 +192,47 @@ impl Connection {
             endpoint.vfs(),
         )?;
 
-        Ok(Connection::new(connection))
+        Ok(Connection::new(connection, 0))
+    }
+
+    /// Open an in-memory [`Connection`] whose `main` database is
+    /// [deserialized](Self::deserialize) from `data`.
+    ///
+    /// This is handy for a read-only database bundled into a binary with
+    /// [`include_bytes!`], which can then be queried without touching the
+    /// filesystem:
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use squire::{Connection, DeserializeFlags, Memory};
+    ///
+    /// # let bundled = {
+    /// #     let source = Connection::open(Memory)?;
+    /// #     source.execute("CREATE TABLE example (a TEXT);", ())?;
+    /// #     source.serialize("main")?
+    /// # };
+    /// # let bundled: &[u8] = &bundled;
+    /// // let bundled = include_bytes!("bundled.db");
+    /// let connection = Connection::open_memory_from(
+    ///     bundled,
+    ///     DeserializeFlags::new().read_only(true),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Pass [`read_only`](DeserializeFlags::read_only) flags unless the
+    /// database needs to be written to. `data` is copied onto the SQLite
+    /// heap, which is what allows a [`resizable`](DeserializeFlags::resizable)
+    /// database to grow.
+    #[doc(alias = "sqlite3_deserialize")]
+    #[cfg(all(feature = "std", sqlite_has_serialize, sqlite_has_memory_database))]
+    #[must_use = "a Connection will be closed if dropped"]
+    pub fn open_memory_from(data: impl Into<Vec<u8>>, flags: DeserializeFlags) -> Result<Self> {
+        let connection = Self::open(crate::Memory)?;
+        connection.deserialize("main", data.into(), flags)?;
+
+        Ok(connection)
     }
 
     /// [Open](ConnectionBuilder::open()) a [`Connection`] configured with
@@ -86,6 +252,8 @@ impl Connection {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     #[must_use]
     pub fn builder<E: IntoEndpoint>(endpoint: E) -> ConnectionBuilder<E::Endpoint> {
         ConnectionBuilder::new(endpoint.into_endpoint())
@@ -101,6 +269,24 @@ impl Connection {
         Statement::prepare(self, query, PrepareOptions::transient())
     }
 
+    /// Prepare a [persistent](PrepareOptions::persistent()) SQL [`Statement`],
+    /// reusing a previously-prepared statement from the connection’s
+    /// statement cache if one exists for the same `query` text.
+    ///
+    /// When the returned [`CachedStatement`] is dropped, the statement is
+    /// returned to the cache. The cache is disabled by default; set its
+    /// capacity with [`ConnectionBuilder::statement_cache_capacity`]. When the
+    /// cache is full, the least recently used statement is finalized.
+    pub fn prepare_cached(&self, query: &str) -> Result<CachedStatement<'_>> {
+        let cached = self.statement_cache().take(query);
+        let statement = match cached {
+            Some(statement) => Statement::new(statement),
+            None => Statement::prepare(self, query, PrepareOptions::persistent())?,
+        };
+
+        Ok(CachedStatement::new(self, query, statement))
+    }
+
     /// Execute a SQL statement and return the number of affected rows.
     pub fn execute<P: for<'a> Parameters<'a>>(
         &self,
@@ -111,17 +297,461 @@ impl Connection {
         Ok(changes)
     }
 
+    /// Extract the value at `path` from a `json` document, using SQLite’s
+    /// [`json_extract()`][json_extract].
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let name: String = connection.json_extract(r#"{"user": {"name": "alice"}}"#, "$.user.name")?;
+    /// assert_eq!("alice", name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A `path` which doesn’t exist in the document extracts `NULL`; fetch an
+    /// [`Option`] to tell it apart. Malformed JSON or an invalid path is
+    /// returned as an error from SQLite.
+    ///
+    /// [json_extract]: https://sqlite.org/json1.html#jex
+    #[cfg(sqlite_has_json)]
+    pub fn json_extract<T>(&self, json: &str, path: &str) -> Result<T>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        self.prepare("SELECT json_extract(?, ?);")?
+            .query((json, path))?
+            .one()
+    }
+
+    /// Read a [connection status](DbStatus) counter, returning its current
+    /// and highwater values. If `reset` is `true`, the highwater value is
+    /// reset.
+    #[doc(alias = "sqlite3_db_status")]
+    pub fn status(&self, metric: DbStatus, reset: bool) -> Result<(i64, i64)> {
+        let (current, highwater) = self.inner.status(metric.raw(), reset)?;
+        Ok((current.into(), highwater.into()))
+    }
+
+    /// `true` if this connection is in [autocommit mode][autocommit]; `false`
+    /// if a transaction is open (for example, after `BEGIN`).
+    ///
+    /// [autocommit]: https://sqlite.org/c3ref/get_autocommit.html
+    #[doc(alias = "sqlite3_get_autocommit")]
+    pub fn in_autocommit(&self) -> bool {
+        self.inner.is_autocommit()
+    }
+
+    /// Check if the `schema` database (e.g., `"main"`) is read-only.
+    ///
+    /// Returns `None` if no database named `schema` is attached to this
+    /// connection.
+    #[doc(alias = "sqlite3_db_readonly")]
+    pub fn is_readonly(&self, schema: &str) -> Option<bool> {
+        let schema = CString::new(schema).ok()?;
+        self.inner.is_readonly(&schema)
+    }
+
+    /// The path of the file backing the `schema` database (e.g., `"main"`).
+    ///
+    /// Returns `None` if no database named `schema` is attached to this
+    /// connection, or if it is a temporary or in-memory database. Otherwise,
+    /// the path is absolute, even if the database was opened with a relative
+    /// path or a [URI](crate::Uri).
+    #[doc(alias = "sqlite3_db_filename")]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn filename(&self, schema: &str) -> Option<&Path> {
+        let schema = CString::new(schema).ok()?;
+        let filename = self.inner.filename(&schema)?.to_str().ok()?;
+
+        if filename.is_empty() {
+            None
+        } else {
+            Some(Path::new(filename))
+        }
+    }
+
+    /// The [transaction state](TxnState) of the `schema` database, or the
+    /// most advanced state of any attached database if `schema` is `None`.
+    ///
+    /// An unknown `schema` is reported as [`TxnState::None`].
+    #[doc(alias = "sqlite3_txn_state")]
+    pub fn txn_state(&self, schema: Option<&str>) -> TxnState {
+        let state = match schema {
+            Some(schema) => match CString::new(schema) {
+                Ok(schema) => self.inner.transaction_state(Some(&schema)),
+                Err(_) => return TxnState::None,
+            },
+            None => self.inner.transaction_state(None),
+        };
+
+        TxnState::from_raw(state).unwrap_or_default()
+    }
+
+    /// Look up the declared type, collation, and constraints of `column` in
+    /// `table`.
+    ///
+    /// If `schema` is `None`, each attached database is searched for `table`
+    /// in the order SQLite uses to resolve unqualified table names. Returns
+    /// an error with code `SQLITE_ERROR` if the table or column doesn't exist.
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// let connection = Connection::open(c":memory:")?;
+    /// connection.execute(
+    ///     "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL COLLATE NOCASE)",
+    ///     (),
+    /// )?;
+    ///
+    /// let name = connection.column_metadata(None, "users", "name")?;
+    /// assert_eq!(name.declared_type(), Some("TEXT"));
+    /// assert_eq!(name.collation(), "NOCASE");
+    /// assert!(name.not_null());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "sqlite3_table_column_metadata")]
+    #[cfg(sqlite_has_column_metadata)]
+    pub fn column_metadata(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+        column: &str,
+    ) -> Result<ColumnMeta> {
+        fn text(value: &core::ffi::CStr) -> String {
+            String::from_utf8_lossy(value.to_bytes()).into_owned()
+        }
+
+        let schema = schema.map(CString::new).transpose()?;
+        let table = CString::new(table)?;
+        let column = CString::new(column)?;
+
+        let metadata = unsafe {
+            self.inner
+                .table_column_metadata(schema.as_deref(), &table, &column)?
+        };
+
+        Ok(ColumnMeta {
+            declared_type: metadata.declared_type.map(text),
+            collation: metadata.collation.map_or_else(|| "BINARY".to_owned(), text),
+            not_null: metadata.not_null,
+            primary_key: metadata.primary_key,
+            auto_increment: metadata.auto_increment,
+        })
+    }
+
+    /// Register an [aggregate function][] named `name`, which takes `n_args`
+    /// arguments (or any number, if `-1`).
+    ///
+    /// ```
+    /// # use squire::{Connection, Fetch, FunctionOptions, ffi::ValueRef};
+    /// use squire::{Aggregate, Value};
+    ///
+    /// struct Product;
+    ///
+    /// impl Aggregate for Product {
+    ///     type State = Option<i64>;
+    ///
+    ///     fn step<'a>(&self, product: &mut Option<i64>, arguments: &'a [ValueRef<'a>]) -> squire::Result<()> {
+    ///         let value = i64::fetch_value(&arguments[0])?;
+    ///         *product = Some(product.unwrap_or(1) * value);
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn finish(&self, product: Option<i64>) -> squire::Result<Value> {
+    ///         Ok(product.map_or(Value::Null, Value::Integer))
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> squire::Result<()> {
+    /// let connection = Connection::open(c":memory:")?;
+    /// connection.create_aggregate_function("product", 1, FunctionOptions::default(), Product)?;
+    ///
+    /// let product: i64 = connection
+    ///     .prepare("SELECT product(column1) FROM (VALUES (2), (3), (7))")?
+    ///     .query(())?
+    ///     .one()?;
+    /// assert_eq!(product, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [aggregate function]: https://sqlite.org/appfunc.html#aggregate_functions
+    #[doc(alias = "sqlite3_create_function_v2")]
+    #[cfg(feature = "functions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
+    pub fn create_aggregate_function<A: ffi::Aggregate>(
+        &self,
+        name: &str,
+        n_args: i32,
+        options: FunctionOptions,
+        function: A,
+    ) -> Result<()> {
+        let name = CString::new(name)?;
+        self.inner
+            .define_aggregate_function(&name, function, n_args, options.raw())
+    }
+
+    /// Register an aggregate [window function][] named `name`, which takes
+    /// `n_args` arguments (or any number, if `-1`).
+    ///
+    /// Besides being usable as an ordinary aggregate, a
+    /// [`WindowFunction`](ffi::WindowFunction) can compute results over a
+    /// moving frame with `OVER (...)`. Window functions require SQLite 3.25
+    /// or later; on older versions, this returns an error.
+    ///
+    /// ```
+    /// # use squire::{Connection, Fetch, FunctionOptions, ffi::ValueRef};
+    /// use squire::{Aggregate, Value, WindowFunction};
+    ///
+    /// struct MovingSum;
+    ///
+    /// impl Aggregate for MovingSum {
+    ///     type State = i64;
+    ///
+    ///     fn step<'a>(&self, total: &mut i64, arguments: &'a [ValueRef<'a>]) -> squire::Result<()> {
+    ///         *total += i64::fetch_value(&arguments[0])?;
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn finish(&self, total: i64) -> squire::Result<Value> {
+    ///         Ok(Value::Integer(total))
+    ///     }
+    /// }
+    ///
+    /// impl WindowFunction for MovingSum {
+    ///     fn inverse<'a>(&self, total: &mut i64, arguments: &'a [ValueRef<'a>]) -> squire::Result<()> {
+    ///         *total -= i64::fetch_value(&arguments[0])?;
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn value(&self, total: &i64) -> squire::Result<Value> {
+    ///         Ok(Value::Integer(*total))
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> squire::Result<()> {
+    /// let connection = Connection::open(c":memory:")?;
+    /// connection.create_window_function("moving_sum", 1, FunctionOptions::default(), MovingSum)?;
+    ///
+    /// let totals: Vec<i64> = connection
+    ///     .prepare(
+    ///         "SELECT moving_sum(column1) OVER (ORDER BY column1 ROWS 1 PRECEDING)
+    ///          FROM (VALUES (1), (2), (3), (4))",
+    ///     )?
+    ///     .query(())?
+    ///     .all()?;
+    /// assert_eq!(totals, vec![1, 3, 5, 7]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [window function]: https://sqlite.org/windowfunctions.html#udfwinfunc
+    #[doc(alias = "sqlite3_create_window_function")]
+    #[cfg(feature = "functions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
+    pub fn create_window_function<W: ffi::WindowFunction>(
+        &self,
+        name: &str,
+        n_args: i32,
+        options: FunctionOptions,
+        function: W,
+    ) -> Result<()> {
+        #[cfg(sqlite_has_window_function)]
+        {
+            let name = CString::new(name)?;
+            self.inner
+                .define_window_function(&name, function, n_args, options.raw())
+        }
+
+        #[cfg(not(sqlite_has_window_function))]
+        {
+            let _ = (name, n_args, options, function);
+            Err(Error::with_detail(
+                ErrorCode::ERROR,
+                "window functions require SQLite 3.25 or later",
+            ))
+        }
+    }
+
+    /// Query the current value of a [run-time limit](Limit).
+    #[doc(alias = "sqlite3_limit")]
+    pub fn limit(&self, category: Limit) -> i32 {
+        self.inner.limit(category.raw(), -1)
+    }
+
+    /// Change a [run-time limit](Limit), returning its prior value.
+    ///
+    /// If `value` is negative, the limit is not changed. SQLite will silently
+    /// truncate `value` to the compile-time maximum for the limit (if lower).
+    #[doc(alias = "sqlite3_limit")]
+    pub fn set_limit(&self, category: Limit, value: i32) -> i32 {
+        self.inner.limit(category.raw(), value)
+    }
+
+    /// [Serialize][] the `schema` database (e.g., `"main"`) into bytes.
+    ///
+    /// The returned bytes are the same as what would be written to disk if
+    /// the database were backed up to a file.
+    ///
+    /// [Serialize]: https://sqlite.org/c3ref/serialize.html
+    #[doc(alias = "sqlite3_serialize")]
+    #[cfg(sqlite_has_serialize)]
+    pub fn serialize(&self, schema: &str) -> Result<Vec<u8>> {
+        let schema = CString::new(schema)?;
+        let data = self.inner.serialize(&schema)?;
+
+        Ok(data.to_vec())
+    }
+
+    /// [Deserialize][] `data` into the `schema` database (e.g., `"main"`),
+    /// replacing its contents with an in-memory database.
+    ///
+    /// `data` is copied onto the SQLite heap, so that SQLite can own (and
+    /// [resize](DeserializeFlags::resizable)) the database buffer.
+    ///
+    /// [Deserialize]: https://sqlite.org/c3ref/deserialize.html
+    #[doc(alias = "sqlite3_deserialize")]
+    #[cfg(sqlite_has_serialize)]
+    pub fn deserialize(&self, schema: &str, data: Vec<u8>, flags: DeserializeFlags) -> Result<()> {
+        let schema = CString::new(schema)?;
+        let data = ffi::Bytes::new(data)?;
+
+        self.inner.deserialize(&schema, data, flags.raw())
+    }
+
     /// Close this [`Connection`].
     ///
-    /// A `Connection` is also closed when it is dropped.
+    /// A `Connection` is also closed when it is dropped. If the connection
+    /// was opened with [`checkpoint_on_close`](ConnectionBuilder::checkpoint_on_close),
+    /// the checkpoint runs first; if it fails or is [busy](crate::Checkpoint::busy),
+    /// the connection is still closed, and `close` returns the error.
+    ///
+    /// A connection wrapped by [`from_raw`](Self::from_raw) without
+    /// ownership is left open.
     pub fn close(mut self) -> Result<()> {
-        let result = unsafe { self.dispose() };
+        let (checkpoint, closed) = unsafe { self.dispose() };
+        if closed.is_ok() {
+            #[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+            drop(self.authorizer().take());
+            #[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+            drop(self.preupdate_hook().take());
+        }
         mem::forget(self); // or Drop will close the connection agian
-        result
+        closed.and(checkpoint)
+    }
+
+    /// Close the connection, returning the results of the
+    /// [`checkpoint_on_close`](ConnectionBuilder::checkpoint_on_close)
+    /// checkpoint and of closing the connection, in that order.
+    unsafe fn dispose(&mut self) -> (Result<()>, Result<()>) {
+        if !self.owned {
+            self.release();
+            return (Ok(()), Ok(()));
+        }
+
+        self.statement_cache().clear();
+
+        let checkpoint = match self.checkpoint_on_close {
+            Some(mode) => self.wal_checkpoint(mode).and_then(|checkpoint| {
+                if checkpoint.busy {
+                    Err(ErrorCategory::Busy.into())
+                } else {
+                    Ok(())
+                }
+            }),
+            None => Ok(()),
+        };
+
+        (checkpoint, unsafe { self.inner.dispose() })
     }
 
-    unsafe fn dispose(&mut self) -> Result<()> {
-        unsafe { self.inner.dispose() }
+    /// Finalize cached statements and unregister any hooks this `Connection`
+    /// set, leaving the connection open for its other users.
+    fn release(&mut self) {
+        self.statement_cache().clear();
+
+        #[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+        if self.authorizer().is_some() {
+            self.clear_authorizer();
+        }
+
+        #[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+        if self.preupdate_hook().is_some() {
+            self.clear_preupdate_hook();
+        }
+    }
+
+    #[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+    pub(crate) fn authorizer(&self) -> MutexGuard<'_, Option<Box<Authorizer>>> {
+        self.authorizer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn statement_cache(&self) -> MutexGuard<'_, StatementCache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn statement_cache(&self) -> RefMut<'_, StatementCache> {
+        self.cache.borrow_mut()
+    }
+
+    #[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+    pub(crate) fn preupdate_hook(&self) -> MutexGuard<'_, Option<Box<PreUpdateHook>>> {
+        self.preupdate_hook
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Check if a thread panicked while using the statement cache.
+    #[cfg(feature = "r2d2")]
+    pub(crate) fn is_poisoned(&self) -> bool {
+        self.cache.is_poisoned()
+    }
+
+    /// Read the database’s schema version until it isn’t busy or locked, or
+    /// until `timeout` elapses; see [`ConnectionBuilder::open_timeout`].
+    #[cfg(feature = "std")]
+    fn wait_for_lock(&self, timeout: Duration) -> Result<()> {
+        const MAX_DELAY: Duration = Duration::from_millis(100);
+
+        // A timeout too large to represent (e.g., `Duration::MAX`) never ends.
+        let deadline = Instant::now().checked_add(timeout);
+        let mut delay = Duration::from_millis(1);
+
+        loop {
+            let probe = self
+                .prepare("PRAGMA schema_version;")
+                .and_then(|mut statement| statement.query(())?.one::<i64>());
+
+            match probe {
+                Err(err)
+                    if matches!(
+                        err.category(),
+                        Some(ErrorCategory::Busy | ErrorCategory::Locked)
+                    ) =>
+                {
+                    let now = Instant::now();
+                    if deadline.is_some_and(|deadline| now >= deadline) {
+                        return Err(Error::with_detail(
+                            ErrorCode::BUSY_TIMEOUT,
+                            format!("database was still locked after {timeout:?}"),
+                        ));
+                    }
+
+                    let remaining = deadline.map_or(delay, |deadline| deadline - now);
+                    thread::sleep(delay.min(remaining));
+                    delay = (delay * 2).min(MAX_DELAY);
+                }
+                result => return result.map(|_| ()),
+            }
+        }
     }
 
     /// Access the [`ffi::Connection`] underlying this [`Connection`].
@@ -129,6 +759,28 @@ impl Connection {
     pub fn internal_ref(&self) -> &ffi::Connection {
         &self.inner
     }
+
+    /// Access the raw [`sqlite3`](sqlite::sqlite3) connection pointer.
+    ///
+    /// The pointer remains owned by this `Connection`, and is only valid
+    /// until it's closed or dropped.
+    #[inline]
+    pub fn as_raw(&self) -> *mut sqlite::sqlite3 {
+        self.inner.as_ptr()
+    }
+
+    /// Give up this `Connection`, returning its raw [`sqlite3`](sqlite::sqlite3)
+    /// connection pointer without closing it.
+    ///
+    /// Squire's cached statements are finalized and its hooks unregistered
+    /// first. The caller becomes responsible for closing the connection;
+    /// e.g., with [`sqlite3_close`](https://sqlite.org/c3ref/close.html), or
+    /// by passing it back to [`from_raw`](Self::from_raw) as `owned`.
+    #[must_use = "the connection will be leaked if the pointer is dropped"]
+    pub fn into_raw(mut self) -> *mut sqlite::sqlite3 {
+        self.owned = false;
+        self.as_raw()
+    }
 }
 
 impl ffi::Connected for Connection {
@@ -149,6 +801,51 @@ impl Drop for Connection {
     }
 }
 
+/// The declared type, collation, and constraints of a table column; returned
+/// by [`Connection::column_metadata`].
+#[cfg(sqlite_has_column_metadata)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct ColumnMeta {
+    declared_type: Option<String>,
+    collation: String,
+    not_null: bool,
+    primary_key: bool,
+    auto_increment: bool,
+}
+
+#[cfg(sqlite_has_column_metadata)]
+impl ColumnMeta {
+    /// The type the column was declared with in `CREATE TABLE` (e.g.,
+    /// `"INTEGER"`), or `None` if it was declared without a type.
+    pub fn declared_type(&self) -> Option<&str> {
+        self.declared_type.as_deref()
+    }
+
+    /// The name of the column's default [collating sequence][collation], such
+    /// as `"BINARY"` or `"NOCASE"`.
+    ///
+    /// [collation]: https://sqlite.org/datatype3.html#collation
+    pub fn collation(&self) -> &str {
+        &self.collation
+    }
+
+    /// Whether the column has a `NOT NULL` constraint.
+    pub const fn not_null(&self) -> bool {
+        self.not_null
+    }
+
+    /// Whether the column is part of the table's `PRIMARY KEY`.
+    pub const fn primary_key(&self) -> bool {
+        self.primary_key
+    }
+
+    /// Whether the column is an [`AUTOINCREMENT`](https://sqlite.org/autoinc.html)
+    /// rowid alias.
+    pub const fn auto_increment(&self) -> bool {
+        self.auto_increment
+    }
+}
+
 /// Configure a [`Connection`] to be opened.
 ///
 /// Create a `ConnectionBuilder` with [`Connection::builder`].
@@ -167,10 +864,19 @@ impl Drop for Connection {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub struct ConnectionBuilder<E: Endpoint = Local> {
     endpoint: E,
     flags: i32,
+    statement_cache_capacity: usize,
+    cache_size: Option<CacheSize>,
+    mmap_size: Option<i64>,
+    db_config: DbConfig,
+    extended_result_codes: bool,
+    checkpoint_on_close: Option<CheckpointMode>,
+    open_timeout: Option<Duration>,
 }
 
 /// Default open mode flags for new connections.
@@ -178,7 +884,7 @@ pub struct ConnectionBuilder<E: Endpoint = Local> {
 /// When the `serialized` feature is enabled, connections are opened with
 /// `SQLITE_OPEN_FULLMUTEX` to ensure full mutex protection even if the
 /// underlying SQLite library was built with a less restrictive threading mode.
-#[cfg(feature = "serialized")]
+#[cfg(all(feature = "std", feature = "serialized"))]
 const DEFAULT_OPEN_MODE: i32 = SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE | SQLITE_OPEN_FULLMUTEX;
 
 /// Default open mode flags for new connections.
@@ -186,37 +892,65 @@ const DEFAULT_OPEN_MODE: i32 = SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE | SQLI
 /// When only `multi-thread` is enabled (not `serialized`), connections are
 /// opened with `SQLITE_OPEN_NOMUTEX` to disable the recursive mutexes on
 /// database connections, matching the expected threading model.
-#[cfg(all(feature = "multi-thread", not(feature = "serialized")))]
+#[cfg(all(feature = "std", feature = "multi-thread", not(feature = "serialized")))]
 const DEFAULT_OPEN_MODE: i32 = SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE | SQLITE_OPEN_NOMUTEX;
 
 /// Default open mode flags for new connections.
 ///
 /// When no threading features are enabled, connections use the default
 /// SQLite behavior without explicit mutex flags.
-#[cfg(not(feature = "multi-thread"))]
+#[cfg(all(feature = "std", not(feature = "multi-thread")))]
 const DEFAULT_OPEN_MODE: i32 = SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE;
 
+#[cfg(feature = "std")]
 const FILE_OPEN_MODES: i32 = SQLITE_OPEN_READONLY | SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE;
+#[cfg(feature = "std")]
 const CONCURRENCY_MODES: i32 = SQLITE_OPEN_FULLMUTEX | SQLITE_OPEN_NOMUTEX;
 
+#[cfg(feature = "std")]
 impl<E: Endpoint> ConnectionBuilder<E> {
     const fn new(endpoint: E) -> Self {
         Self {
             endpoint,
             flags: DEFAULT_OPEN_MODE,
+            statement_cache_capacity: 0,
+            cache_size: None,
+            mmap_size: None,
+            db_config: DbConfig::new(),
+            extended_result_codes: true,
+            checkpoint_on_close: None,
+            open_timeout: None,
         }
     }
 
     /// Open a [`Connection`] using the configuration set on this
     /// [builder](Self).
     pub fn open(&self) -> Result<Connection> {
+        vfs::check_vfs(self.endpoint.vfs())?;
+
         let connection = ffi::Connection::open(
             self.endpoint.location(),
             self.flags | self.endpoint.flags(),
             self.endpoint.vfs(),
         )?;
 
-        Ok(Connection::new(connection))
+        connection.set_extended_result_codes(self.extended_result_codes)?;
+        let mut connection = Connection::new(connection, self.statement_cache_capacity);
+        connection.checkpoint_on_close = self.checkpoint_on_close;
+
+        if let Some(timeout) = self.open_timeout {
+            connection.wait_for_lock(timeout)?;
+        }
+
+        self.db_config.apply(connection.internal_ref())?;
+        if let Some(size) = self.cache_size {
+            connection.set_cache_size(size)?;
+        }
+        if let Some(bytes) = self.mmap_size {
+            connection.set_mmap_size(bytes)?;
+        }
+
+        Ok(connection)
     }
 
     /// Open the connection in read-only mode.
@@ -283,8 +1017,185 @@ impl<E: Endpoint> ConnectionBuilder<E> {
         self.with_flags(flags)
     }
 
+    /// Enable or disable [shared-cache mode][shared] for the connection.
+    ///
+    /// Connections in the same process which open the same database with
+    /// shared cache enabled share a single page cache. This lets several
+    /// connections see the same in-memory database, opened by a URI like
+    /// `file:name?mode=memory&cache=shared`.
+    ///
+    /// Shared-cache connections lock individual tables rather than the whole
+    /// database, and a connection blocked by another fails with a
+    /// [`SharedCache`](crate::LockedError::SharedCache) locked error, which
+    /// (unlike [busy](crate::ErrorCategory::Busy) errors) isn’t retried by
+    /// the busy handler.
+    ///
+    /// [shared]: https://sqlite.org/sharedcache.html
+    #[cfg(sqlite_has_shared_cache)]
+    #[doc(alias = "SQLITE_OPEN_SHAREDCACHE")]
+    #[doc(alias = "SQLITE_OPEN_PRIVATECACHE")]
+    pub fn shared_cache(self, enable: bool) -> Self {
+        let flags = self.flags & !(SQLITE_OPEN_SHAREDCACHE | SQLITE_OPEN_PRIVATECACHE);
+        self.with_flags(if enable {
+            flags | SQLITE_OPEN_SHAREDCACHE
+        } else {
+            flags | SQLITE_OPEN_PRIVATECACHE
+        })
+    }
+
+    /// Enable or disable [extended result codes][extended].
+    ///
+    /// Extended result codes are enabled by default, and let an [`Error`]
+    /// report its specific [reason](crate::Error::reason) (e.g., a
+    /// [`Unique`](crate::ConstraintError::Unique) constraint violation, and
+    /// not just a [constraint](crate::ErrorCategory::Constraint) error).
+    /// Disabling them leaves only the [category](crate::Error::category) of
+    /// most errors.
+    ///
+    /// [`Error`]: crate::Error
+    /// [extended]: https://sqlite.org/rescode.html#extrc
+    #[doc(alias = "sqlite3_extended_result_codes")]
+    pub fn extended_result_codes(self, enable: bool) -> Self {
+        Self {
+            extended_result_codes: enable,
+            ..self
+        }
+    }
+
+    /// Set the number of statements to keep in the connection’s
+    /// [statement cache](Connection::prepare_cached).
+    ///
+    /// The default capacity is `0`, which disables caching.
+    pub fn statement_cache_capacity(self, capacity: usize) -> Self {
+        Self {
+            statement_cache_capacity: capacity,
+            ..self
+        }
+    }
+
+    /// Set the suggested size of the [page cache][] once the connection is
+    /// opened; see [`Connection::set_cache_size`].
+    ///
+    /// [page cache]: https://sqlite.org/pragma.html#pragma_cache_size
+    pub fn cache_size(self, size: CacheSize) -> Self {
+        Self {
+            cache_size: Some(size),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of bytes to read using [memory-mapped I/O][mmap]
+    /// once the connection is opened; see [`Connection::set_mmap_size`].
+    ///
+    /// [mmap]: https://sqlite.org/mmap.html
+    pub fn mmap_size(self, bytes: i64) -> Self {
+        Self {
+            mmap_size: Some(bytes),
+            ..self
+        }
+    }
+
+    /// Enable or disable [defensive mode][defensive], which stops SQL from
+    /// deliberately corrupting the database file (e.g., by writing to
+    /// `sqlite_schema` with `PRAGMA writable_schema`).
+    ///
+    /// Enable defensive mode when opening database files from an untrusted
+    /// source. Requires SQLite 3.26 or later.
+    ///
+    /// [defensive]: https://sqlite.org/c3ref/c_dbconfig_defensive.html#sqlitedbconfigdefensive
+    #[cfg(sqlite_has_db_config_defensive)]
+    #[doc(alias = "SQLITE_DBCONFIG_DEFENSIVE")]
+    pub fn defensive(self, enable: bool) -> Self {
+        self.with_db_config(SQLITE_DBCONFIG_DEFENSIVE, enable)
+    }
+
+    /// Choose whether SQL functions and virtual tables used by the schema
+    /// (e.g., in views, triggers, and `CHECK` constraints) are
+    /// [trusted][trusted].
+    ///
+    /// When disabled, only functions and virtual tables marked as innocuous
+    /// (`SQLITE_INNOCUOUS`) can be used by the schema. Requires SQLite 3.31 or later.
+    ///
+    /// [trusted]: https://sqlite.org/c3ref/c_dbconfig_defensive.html#sqlitedbconfigtrustedschema
+    #[cfg(sqlite_has_db_config_trusted_schema)]
+    #[doc(alias = "SQLITE_DBCONFIG_TRUSTED_SCHEMA")]
+    pub fn trusted_schema(self, enable: bool) -> Self {
+        self.with_db_config(SQLITE_DBCONFIG_TRUSTED_SCHEMA, enable)
+    }
+
+    /// Enable or disable enforcement of [foreign key][fkey] constraints; the
+    /// same as setting `PRAGMA foreign_keys`.
+    ///
+    /// [fkey]: https://sqlite.org/foreignkeys.html
+    #[doc(alias = "SQLITE_DBCONFIG_ENABLE_FKEY")]
+    pub fn enable_fkey(self, enable: bool) -> Self {
+        self.with_db_config(SQLITE_DBCONFIG_ENABLE_FKEY, enable)
+    }
+
+    /// Enable or disable [triggers][trigger].
+    ///
+    /// Disabling triggers stops them from running, but they can still be
+    /// created and dropped.
+    ///
+    /// [trigger]: https://sqlite.org/lang_createtrigger.html
+    #[doc(alias = "SQLITE_DBCONFIG_ENABLE_TRIGGER")]
+    pub fn enable_trigger(self, enable: bool) -> Self {
+        self.with_db_config(SQLITE_DBCONFIG_ENABLE_TRIGGER, enable)
+    }
+
+    /// Enable or disable [views][view].
+    ///
+    /// Disabling views makes queries which use them fail, but views can
+    /// still be created and dropped. Requires SQLite 3.30 or later.
+    ///
+    /// [view]: https://sqlite.org/lang_createview.html
+    #[cfg(sqlite_has_db_config_enable_view)]
+    #[doc(alias = "SQLITE_DBCONFIG_ENABLE_VIEW")]
+    pub fn enable_view(self, enable: bool) -> Self {
+        self.with_db_config(SQLITE_DBCONFIG_ENABLE_VIEW, enable)
+    }
+
+    /// Run a [WAL checkpoint](Connection::wal_checkpoint) when the connection
+    /// is closed or dropped.
+    ///
+    /// Use [`CheckpointMode::Truncate`] so the `-wal` file doesn’t linger
+    /// after the last connection closes. Checkpoint failures are returned by
+    /// [`Connection::close`], and ignored when a connection is dropped.
+    pub fn checkpoint_on_close(self, mode: CheckpointMode) -> Self {
+        Self {
+            checkpoint_on_close: Some(mode),
+            ..self
+        }
+    }
+
+    /// Wait up to `timeout` for the database to become readable when opening
+    /// the connection.
+    ///
+    /// `sqlite3_open_v2` doesn’t read the database file, so it never blocks
+    /// on a lock held by another connection; contention is only found by the
+    /// first statement that reads the database. With an `open_timeout`,
+    /// [`open`](Self::open) reads the schema version (with `PRAGMA
+    /// schema_version`) before returning, retrying with a backoff while the
+    /// database is [busy](ErrorCategory::Busy) or [locked](ErrorCategory::Locked).
+    ///
+    /// If the database is still unreadable after `timeout`, `open` fails with
+    /// [`BusyError::Timeout`](crate::BusyError::Timeout). The timeout only
+    /// covers the open itself: later statements that need a lock can still
+    /// fail immediately with `SQLITE_BUSY`. (Use [`Connection::with_retry`]
+    /// to retry those.)
+    pub fn open_timeout(self, timeout: Duration) -> Self {
+        Self {
+            open_timeout: Some(timeout),
+            ..self
+        }
+    }
+
     /// Select which [virtual filesystem][vfs] to use for the connection.
     ///
+    /// If no VFS named `vfs` is [registered](crate::vfs_names),
+    /// [`open`](Self::open) returns a [can't open](crate::ErrorCategory::CantOpen)
+    /// error.
+    ///
     /// [vfs]: https://sqlite.org/vfs.html
     pub fn vfs<L: ffi::Location>(
         self,
@@ -293,6 +1204,21 @@ impl<E: Endpoint> ConnectionBuilder<E> {
         ConnectionBuilder {
             endpoint: Vfs::new(self.endpoint, vfs),
             flags: self.flags,
+            statement_cache_capacity: self.statement_cache_capacity,
+            cache_size: self.cache_size,
+            mmap_size: self.mmap_size,
+            db_config: self.db_config,
+            extended_result_codes: self.extended_result_codes,
+            checkpoint_on_close: self.checkpoint_on_close,
+            open_timeout: self.open_timeout,
+        }
+    }
+
+    #[inline]
+    fn with_db_config(self, op: i32, enable: bool) -> Self {
+        Self {
+            db_config: self.db_config.with(op, enable),
+            ..self
         }
     }
 
@@ -310,9 +1236,44 @@ impl<E: Endpoint> ConnectionBuilder<E> {
 
     #[inline]
     fn with_flags(self, flags: i32) -> Self {
-        Self {
-            endpoint: self.endpoint,
-            flags,
+        Self { flags, ..self }
+    }
+}
+
+/// Boolean [`sqlite3_db_config`](sqlite::sqlite3_db_config) options to set
+/// once a [`ConnectionBuilder`] opens a connection.
+#[cfg(feature = "std")]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+struct DbConfig([Option<bool>; DbConfig::OPTIONS.len()]);
+
+#[cfg(feature = "std")]
+impl DbConfig {
+    const OPTIONS: [i32; 5] = [
+        SQLITE_DBCONFIG_DEFENSIVE,
+        SQLITE_DBCONFIG_TRUSTED_SCHEMA,
+        SQLITE_DBCONFIG_ENABLE_FKEY,
+        SQLITE_DBCONFIG_ENABLE_TRIGGER,
+        SQLITE_DBCONFIG_ENABLE_VIEW,
+    ];
+
+    const fn new() -> Self {
+        Self([None; Self::OPTIONS.len()])
+    }
+
+    fn with(mut self, op: i32, enable: bool) -> Self {
+        if let Some(i) = Self::OPTIONS.iter().position(|&option| option == op) {
+            self.0[i] = Some(enable);
         }
+        self
+    }
+
+    fn apply(&self, connection: &ffi::Connection) -> Result<()> {
+        for (op, enable) in Self::OPTIONS.into_iter().zip(self.0) {
+            if let Some(enable) = enable {
+                connection.set_config_flag(op, enable)?;
+            }
+        }
+
+        Ok(())
     }
 }
@@ -1,11 +1,14 @@
 use core::{ffi::CStr, fmt, ops::Deref};
-use std::ffi::CString;
+use std::{ffi::CString, path::Path};
 
 #[cfg(sqlite_has_memory_database)]
 use sqlite::SQLITE_OPEN_MEMORY;
 use sqlite::SQLITE_OPEN_URI;
 
-use crate::ffi;
+use crate::{
+    error::{Error, ErrorCode, Result},
+    ffi,
+};
 
 /// Specifies which SQLite database to [open](crate::Connection::open).
 ///
@@ -57,6 +60,10 @@ pub trait Endpoint: Clone + fmt::Debug {
 
 /// An in-memory, ephemeral SQLite database [`Endpoint`].
 ///
+/// Each connection opened to `Memory` gets its own empty database, which is
+/// discarded when the connection is closed. Use [`Temporary`] for a private
+/// database which can spill to disk.
+///
 /// ```rust
 /// # use std::path::PathBuf;
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -83,6 +90,36 @@ impl Endpoint for Memory {
     }
 }
 
+/// A private, [temporary][] on-disk SQLite database [`Endpoint`].
+///
+/// Like [`Memory`], each connection opened to `Temporary` gets its own empty
+/// database. SQLite keeps a temporary database in memory while it is small,
+/// but can write its pages to an anonymous temporary file as it grows. The
+/// file is deleted when the connection is closed.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use squire::{Connection, Temporary};
+///
+/// let connection = Connection::open(Temporary)?;
+///
+/// # let _: Connection = connection;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [temporary]: https://sqlite.org/inmemorydb.html#temp_db
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg(sqlite_has_temporary_database)]
+pub struct Temporary;
+
+#[cfg(sqlite_has_temporary_database)]
+impl Endpoint for Temporary {
+    fn location(&self) -> &CStr {
+        c""
+    }
+}
+
 /// A local filesystem SQLite database [`Endpoint`].
 ///
 /// To use a `Local` endpoint, pass any string or path to
@@ -158,6 +195,31 @@ impl<L: ffi::Location> Uri<L> {
     }
 }
 
+impl Uri {
+    /// Build a [`Uri`] for the database file at `path`, with
+    /// [query parameters][params] set by the returned [`UriBuilder`].
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use squire::{Connection, Uri, UriCache, UriMode};
+    ///
+    /// let uri = Uri::builder("./data.sqlite3")
+    ///     .mode(UriMode::ReadOnly)
+    ///     .cache(UriCache::Private)
+    ///     .build()?;
+    /// let connection = Connection::open(uri)?;
+    ///
+    /// # let _: Connection = connection;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [params]: https://sqlite.org/uri.html#uri_parameters_processed_by_sqlite
+    pub fn builder(path: impl AsRef<Path>) -> UriBuilder {
+        UriBuilder::new(path.as_ref())
+    }
+}
+
 impl Uri<&'static CStr> {
     pub const fn define(uri: &'static CStr) -> Self {
         Self { uri }
@@ -196,6 +258,180 @@ impl From<url::Url> for Uri {
     }
 }
 
+/// Assembles a SQLite [database URI][] from a path and query parameters.
+///
+/// Create a `UriBuilder` with [`Uri::builder`]. The path and each parameter
+/// are percent-encoded as needed.
+///
+/// [database URI]: https://sqlite.org/uri.html
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct UriBuilder {
+    uri: String,
+    params: Vec<(String, String)>,
+}
+
+impl UriBuilder {
+    fn new(path: &Path) -> Self {
+        let mut uri = String::from("file:");
+        let path = path.as_os_str().as_encoded_bytes();
+
+        if cfg!(windows) {
+            // `C:\dir\file` becomes `file:/C:/dir/file`.
+            if path.get(1) == Some(&b':') && path[0].is_ascii_alphabetic() {
+                uri.push('/');
+            }
+            for &byte in path {
+                match byte {
+                    b'\\' => uri.push('/'),
+                    b':' => uri.push(':'),
+                    byte => encode_byte(&mut uri, byte, b"/"),
+                }
+            }
+        } else {
+            for &byte in path {
+                encode_byte(&mut uri, byte, b"/");
+            }
+        }
+
+        Self {
+            uri,
+            params: Vec::new(),
+        }
+    }
+
+    /// Set the [access mode][mode] for the database.
+    ///
+    /// [mode]: https://sqlite.org/uri.html#urimode
+    pub fn mode(self, mode: UriMode) -> Self {
+        self.param("mode", mode.as_str())
+    }
+
+    /// Set whether the database uses a [shared cache][cache].
+    ///
+    /// [cache]: https://sqlite.org/uri.html#uricache
+    pub fn cache(self, cache: UriCache) -> Self {
+        self.param("cache", cache.as_str())
+    }
+
+    /// Mark the database as [immutable][]: SQLite will assume it can't change,
+    /// and won't lock it or check it for changes.
+    ///
+    /// [immutable]: https://sqlite.org/uri.html#uriimmutable
+    pub fn immutable(self, immutable: bool) -> Self {
+        self.param("immutable", if immutable { "1" } else { "0" })
+    }
+
+    /// Select which [virtual filesystem][vfs] to use for the database.
+    ///
+    /// [vfs]: https://sqlite.org/vfs.html
+    pub fn vfs(self, vfs: &str) -> Self {
+        self.param("vfs", vfs)
+    }
+
+    /// Set a query parameter, replacing any previous value for `key`.
+    ///
+    /// Query parameters that SQLite doesn't recognize are passed on to the
+    /// [VFS](Self::vfs). Neither `key` nor `value` may contain a `#`; SQLite
+    /// would treat it as the start of a URI fragment, and
+    /// [`build`](Self::build) fails if one is found.
+    pub fn param(mut self, key: &str, value: &str) -> Self {
+        match self.params.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => value.clone_into(v),
+            None => self.params.push((key.to_owned(), value.to_owned())),
+        }
+        self
+    }
+
+    /// Assemble the [`Uri`].
+    ///
+    /// Fails with a [misuse](crate::ErrorCategory::Misuse) error if any query
+    /// parameter contains a `#`.
+    pub fn build(self) -> Result<Uri> {
+        let mut uri = self.uri;
+
+        for (i, (key, value)) in self.params.iter().enumerate() {
+            if key.contains('#') || value.contains('#') {
+                return Err(Error::with_detail(
+                    ErrorCode::MISUSE,
+                    format!("URI query parameter {key:?} must not contain '#'"),
+                ));
+            }
+
+            uri.push(if i == 0 { '?' } else { '&' });
+            for &byte in key.as_bytes() {
+                encode_byte(&mut uri, byte, b"");
+            }
+            uri.push('=');
+            for &byte in value.as_bytes() {
+                encode_byte(&mut uri, byte, b"");
+            }
+        }
+
+        Ok(Uri::new(CString::new(uri)?))
+    }
+}
+
+/// Append `byte` to `uri`, percent-encoding it unless it is an unreserved
+/// URI character or in `keep`.
+fn encode_byte(uri: &mut String, byte: u8, keep: &[u8]) {
+    if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) || keep.contains(&byte) {
+        uri.push(byte as char);
+    } else {
+        uri.push_str(&format!("%{byte:02X}"));
+    }
+}
+
+/// The [access mode][mode] for a [`Uri`] database.
+///
+/// [mode]: https://sqlite.org/uri.html#urimode
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum UriMode {
+    /// Open the database read-only (`mode=ro`).
+    ReadOnly,
+    /// Open the database for reading and writing (`mode=rw`).
+    ReadWrite,
+    /// Open the database for reading and writing, creating it if it doesn't
+    /// exist (`mode=rwc`).
+    ReadWriteCreate,
+    /// Open an in-memory database (`mode=memory`), which is never read from
+    /// or written to disk.
+    Memory,
+}
+
+impl UriMode {
+    /// The value of the `mode` query parameter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ReadOnly => "ro",
+            Self::ReadWrite => "rw",
+            Self::ReadWriteCreate => "rwc",
+            Self::Memory => "memory",
+        }
+    }
+}
+
+/// The [cache mode][cache] for a [`Uri`] database.
+///
+/// [cache]: https://sqlite.org/uri.html#uricache
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum UriCache {
+    /// Share the page cache with other connections to the same database
+    /// (`cache=shared`).
+    Shared,
+    /// Use a private page cache (`cache=private`).
+    Private,
+}
+
+impl UriCache {
+    /// The value of the `cache` query parameter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Shared => "shared",
+            Self::Private => "private",
+        }
+    }
+}
+
 /// Selects a SQLite [virtual filesystem][vfs] for an [`Endpoint`].
 ///
 /// [vfs]: https://sqlite.org/vfs.html
@@ -264,6 +500,15 @@ impl IntoEndpoint for Memory {
     }
 }
 
+#[cfg(sqlite_has_temporary_database)]
+impl IntoEndpoint for Temporary {
+    type Endpoint = Self;
+
+    fn into_endpoint(self) -> Self::Endpoint {
+        self
+    }
+}
+
 impl<L: ffi::Location> IntoEndpoint for Local<L> {
     type Endpoint = Self;
 
@@ -1,3 +1,5 @@
+use core::fmt;
+
 use super::ErrorCode;
 
 /// The category of [`Error`](crate::Error) that occurred.
@@ -216,4 +218,40 @@ impl ErrorCategory {
     pub const fn code(self) -> ErrorCode {
         unsafe { ErrorCode::new_unchecked(self as i32) }
     }
+
+    /// The name of the category's result code constant (e.g., `SQLITE_BUSY`).
+    pub const fn name(self) -> &'static str {
+        match self.code().name() {
+            Some(name) => name,
+            None => "",
+        }
+    }
+
+    /// A human-readable description of the category (e.g., “database is
+    /// locked”), as given by [`ErrorCode::description`].
+    pub fn description(self) -> &'static str {
+        self.code().description()
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn category_text() {
+        assert_eq!("SQLITE_BUSY", ErrorCategory::Busy.name());
+        assert_eq!("database is locked", ErrorCategory::Busy.to_string());
+        assert_eq!("SQUIRE_ERROR_FETCH", ErrorCategory::Fetch.name());
+        assert_eq!(
+            "error fetching column value",
+            ErrorCategory::Fetch.description()
+        );
+    }
 }
@@ -29,9 +29,11 @@ macro_rules! code {
 pub(crate) const SQUIRE_ERROR: i32 = code!();
 pub(crate) const SQUIRE_ERROR_ROW: i32 = code!(1);
 pub(crate) const SQUIRE_ERROR_ROW_NOT_RETURNED: i32 = code!(1, 1);
+pub(crate) const SQUIRE_ERROR_ROW_TOO_MANY: i32 = code!(1, 2);
 pub(crate) const SQUIRE_ERROR_FETCH: i32 = code!(2);
 pub(crate) const SQUIRE_ERROR_FETCH_PARSE: i32 = code!(2, 1);
 pub(crate) const SQUIRE_ERROR_FETCH_RANGE: i32 = code!(2, 2);
+pub(crate) const SQUIRE_ERROR_FETCH_UNKNOWN_COLUMN: i32 = code!(2, 3);
 pub(crate) const SQUIRE_ERROR_PARAMETER: i32 = code!(3);
 pub(crate) const SQUIRE_ERROR_PARAMETER_BIND: i32 = code!(3, 1);
 pub(crate) const SQUIRE_ERROR_PARAMETER_RANGE: i32 = code!(3, 2);
@@ -210,9 +212,11 @@ impl ErrorCode {
             Self::SQUIRE => Some("SQUIRE_ERROR"),
             Self::SQUIRE_ROW => Some("SQUIRE_ERROR_ROW"),
             Self::SQUIRE_ROW_NOT_RETURNED => Some("SQUIRE_ERROR_ROW_NOT_RETURNED"),
+            Self::SQUIRE_ROW_TOO_MANY => Some("SQUIRE_ERROR_ROW_TOO_MANY"),
             Self::SQUIRE_FETCH => Some("SQUIRE_ERROR_FETCH"),
             Self::SQUIRE_FETCH_PARSE => Some("SQUIRE_ERROR_FETCH_PARSE"),
             Self::SQUIRE_FETCH_RANGE => Some("SQUIRE_ERROR_FETCH_RANGE"),
+            Self::SQUIRE_FETCH_UNKNOWN_COLUMN => Some("SQUIRE_ERROR_FETCH_UNKNOWN_COLUMN"),
             Self::SQUIRE_PARAMETER => Some("SQUIRE_ERROR_PARAMETER"),
             Self::SQUIRE_PARAMETER_BIND => Some("SQUIRE_ERROR_PARAMETER_BIND"),
             Self::SQUIRE_PARAMETER_RANGE => Some("SQUIRE_ERROR_PARAMETER_RANGE"),
@@ -231,9 +235,11 @@ impl ErrorCode {
         match *self {
             Self::SQUIRE_ROW => "error retrieving selected row",
             Self::SQUIRE_ROW_NOT_RETURNED => "query returned no rows",
+            Self::SQUIRE_ROW_TOO_MANY => "query returned more than one row",
             Self::SQUIRE_FETCH => "error fetching column value",
             Self::SQUIRE_FETCH_PARSE => "error parsing column value",
             Self::SQUIRE_FETCH_RANGE => "column value out of range",
+            Self::SQUIRE_FETCH_UNKNOWN_COLUMN => "no column with the given name",
             Self::SQUIRE_PARAMETER => "error binding parameter",
             Self::SQUIRE_PARAMETER_BIND => "error binding parameter value",
             Self::SQUIRE_PARAMETER_RANGE => "parameter value out of range",
@@ -360,9 +366,12 @@ impl ErrorCode {
     pub(crate) const SQUIRE: Self = Self::define(SQUIRE_ERROR);
     pub(crate) const SQUIRE_ROW: Self = Self::define(SQUIRE_ERROR_ROW);
     pub(crate) const SQUIRE_ROW_NOT_RETURNED: Self = Self::define(SQUIRE_ERROR_ROW_NOT_RETURNED);
+    pub(crate) const SQUIRE_ROW_TOO_MANY: Self = Self::define(SQUIRE_ERROR_ROW_TOO_MANY);
     pub(crate) const SQUIRE_FETCH: Self = Self::define(SQUIRE_ERROR_FETCH);
     pub(crate) const SQUIRE_FETCH_PARSE: Self = Self::define(SQUIRE_ERROR_FETCH_PARSE);
     pub(crate) const SQUIRE_FETCH_RANGE: Self = Self::define(SQUIRE_ERROR_FETCH_RANGE);
+    pub(crate) const SQUIRE_FETCH_UNKNOWN_COLUMN: Self =
+        Self::define(SQUIRE_ERROR_FETCH_UNKNOWN_COLUMN);
     pub(crate) const SQUIRE_PARAMETER: Self = Self::define(SQUIRE_ERROR_PARAMETER);
     pub(crate) const SQUIRE_PARAMETER_BIND: Self = Self::define(SQUIRE_ERROR_PARAMETER_BIND);
     pub(crate) const SQUIRE_PARAMETER_RANGE: Self = Self::define(SQUIRE_ERROR_PARAMETER_RANGE);
@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+
+/// The table and columns named by a failed `UNIQUE`, `PRIMARY KEY`, or
+/// `NOT NULL` [constraint](crate::ConstraintError), parsed from SQLite’s
+/// error message.
+///
+/// See [`Error::constraint_target`](crate::Error::constraint_target).
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ConstraintTarget<'a> {
+    /// The table the constraint is defined on.
+    pub table: &'a str,
+    /// The constrained columns, in the order SQLite listed them.
+    pub columns: Vec<&'a str>,
+}
+
+impl<'a> ConstraintTarget<'a> {
+    /// Parse a message like `UNIQUE constraint failed: users.email`.
+    pub(super) fn parse(message: &'a str) -> Option<Self> {
+        let (_, list) = message.split_once(" constraint failed: ")?;
+        // Drop any context Squire appended, e.g. “(after 3 successful rows)”.
+        let list = list.split_once(" (").map_or(list, |(list, _)| list);
+
+        let mut table = None;
+        let mut columns = Vec::new();
+        for target in list.split(", ") {
+            let (t, column) = target.split_once('.')?;
+            if *table.get_or_insert(t) != t || column.is_empty() {
+                return None;
+            }
+            columns.push(column);
+        }
+
+        Some(Self {
+            table: table?,
+            columns,
+        })
+    }
+}
@@ -1,4 +1,8 @@
+use alloc::string::String;
+
 use super::code::ErrorCode;
+#[cfg(feature = "std")]
+use super::integration::ErrorContainer;
 use super::integration::IntegrationError;
 use super::location::ErrorLocation;
 use crate::ffi;
@@ -29,6 +33,8 @@ pub(crate) enum ErrorDetail {
     Message(String),
     SourceMessage(String, ErrorLocation),
     Integration(IntegrationError),
+    #[cfg(feature = "std")]
+    Io(String, ErrorContainer<std::io::Error>),
 }
 
 impl ErrorDetail {
@@ -83,6 +89,13 @@ impl ErrorDetail {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ErrorDetail {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string(), ErrorContainer::new(error))
+    }
+}
+
 impl From<String> for ErrorDetail {
     fn from(message: String) -> Self {
         Self::Message(message)
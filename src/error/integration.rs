@@ -1,9 +1,11 @@
 use core::{fmt, ops::Deref};
 
+#[cfg(any(feature = "decimal", feature = "uuid"))]
+use alloc::boxed::Box;
 #[cfg(not(feature = "multi-thread"))]
-use std::rc::Rc;
+use alloc::rc::Rc;
 #[cfg(feature = "multi-thread")]
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 /// An [error](core::error::Error) from a crate that Squire integrates with.
 #[derive(Clone, Debug)]
@@ -15,6 +17,13 @@ pub enum IntegrationError {
     #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
     Chrono(chrono::ParseError),
 
+    /// An error from the [`rust_decimal`][] crate.
+    ///
+    /// [`rust_decimal`]: https://lib.rs/rust_decimal
+    #[cfg(feature = "decimal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+    Decimal(Box<rust_decimal::Error>),
+
     /// An error from the [`jiff`][] crate.
     ///
     /// [`jiff`]: https://crates.io/crates/jiff
@@ -78,6 +87,33 @@ impl From<chrono::ParseError> for IntegrationError {
     }
 }
 
+#[cfg(feature = "decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl IntegrationError {
+    /// `true` if this is a [`rust_decimal::Error`]; `false` if otherwise.
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal(_))
+    }
+
+    /// Access the [`rust_decimal::Error`] contained in this [`IntegrationError`].
+    ///
+    /// Returns `None` if this is not a `Decimal` error.
+    pub fn as_decimal(&self) -> Option<&rust_decimal::Error> {
+        match self {
+            Self::Decimal(bx) => Some(bx.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl From<rust_decimal::Error> for IntegrationError {
+    fn from(error: rust_decimal::Error) -> Self {
+        Self::Decimal(Box::new(error))
+    }
+}
+
 #[cfg(feature = "jiff")]
 #[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
 impl IntegrationError {
@@ -214,10 +250,12 @@ impl From<uuid::Error> for IntegrationError {
 }
 
 impl fmt::Display for IntegrationError {
-    fn fmt(&self, #[allow(unused_variables)] f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, #[allow(unused_variables)] f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             #[cfg(feature = "chrono")]
             IntegrationError::Chrono(ref error) => error.fmt(f),
+            #[cfg(feature = "decimal")]
+            IntegrationError::Decimal(ref bx) => bx.fmt(f),
             #[cfg(feature = "jiff")]
             IntegrationError::Jiff(ref error) => error.fmt(f),
             #[cfg(all(feature = "serde", feature = "json"))]
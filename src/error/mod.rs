@@ -1,9 +1,16 @@
+use alloc::{
+    boxed::Box,
+    ffi::NulError,
+    format,
+    string::{String, ToString},
+};
 use core::fmt;
 
 use crate::ffi;
 
 mod category;
 mod code;
+mod constraint;
 mod detail;
 mod integration;
 mod location;
@@ -13,6 +20,7 @@ use detail::{ErrorDetail, ErrorInner};
 
 pub use category::ErrorCategory;
 pub use code::ErrorCode;
+pub use constraint::ConstraintTarget;
 pub use integration::{ErrorContainer, IntegrationError};
 pub use location::ErrorLocation;
 pub use reason::{
@@ -102,6 +110,42 @@ impl Error {
         Self::new(ErrorCode::SQUIRE_ROW_NOT_RETURNED)
     }
 
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn row_too_many() -> Self {
+        Self::new(ErrorCode::SQUIRE_ROW_TOO_MANY)
+    }
+
+    /// Note in the error's message that it occurred after `rows` sets of
+    /// parameters were executed successfully.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn after_rows(mut self, rows: usize) -> Self {
+        let context = format!("after {rows} successful rows");
+
+        self.inner.detail = match self.inner.detail.take() {
+            None if self.is_squire() => Some(ErrorDetail::Message(context)),
+            None => Some(ErrorDetail::Message(format!(
+                "{} ({context})",
+                self.code().description()
+            ))),
+            Some(ErrorDetail::Message(message)) => {
+                Some(ErrorDetail::Message(format!("{message} ({context})")))
+            }
+            Some(ErrorDetail::SourceMessage(message, location)) => Some(
+                ErrorDetail::SourceMessage(format!("{message} ({context})"), location),
+            ),
+            #[cfg(feature = "std")]
+            Some(ErrorDetail::Io(message, error)) => {
+                Some(ErrorDetail::Io(format!("{message} ({context})"), error))
+            }
+            // Keep the integration error, so it remains the error's source.
+            detail @ Some(ErrorDetail::Integration(_)) => detail,
+        };
+
+        self
+    }
+
     /// The [`ErrorCode`] identifying what error occurred.
     pub const fn code(&self) -> ErrorCode {
         self.inner.code
@@ -152,6 +196,82 @@ impl Error {
         }
     }
 
+    /// For a failed `UNIQUE`, `PRIMARY KEY`, or `NOT NULL`
+    /// [constraint](ErrorCategory::Constraint), the table and columns it
+    /// names, parsed from SQLite’s error message.
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// connection.execute("CREATE TABLE users (email TEXT UNIQUE);", ())?;
+    /// connection.execute("INSERT INTO users VALUES ('a@example.com');", ())?;
+    ///
+    /// let err = connection
+    ///     .execute("INSERT INTO users VALUES ('a@example.com');", ())
+    ///     .unwrap_err();
+    /// let target = err.constraint_target().expect("a unique constraint");
+    /// assert_eq!("users", target.table);
+    /// assert_eq!(vec!["email"], target.columns);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Parsing is best-effort: returns `None` for other errors, and for
+    /// constraints whose message doesn’t name columns (like `CHECK` and
+    /// `FOREIGN KEY` constraints, or a `UNIQUE` index on an expression).
+    /// The full message is still available from the error’s
+    /// [`Display`](fmt::Display) output.
+    pub fn constraint_target(&self) -> Option<ConstraintTarget<'_>> {
+        if self.category() != Some(ErrorCategory::Constraint) {
+            return None;
+        }
+
+        ConstraintTarget::parse(self.message()?)
+    }
+
+    /// Format this error with the line of `sql` where it occurred, and a caret
+    /// pointing at its [source location](Self::source_location).
+    ///
+    /// `sql` should be the text that was being prepared when the error
+    /// occurred. If the error has no source location, this is the same as
+    /// the error’s [`Display`](fmt::Display) output.
+    ///
+    /// ```text
+    /// near "FORM": syntax error [SQLITE_ERROR]
+    ///  --> line 1, column 10
+    ///   |
+    /// 1 | SELECT * FORM users;
+    ///   |          ^
+    /// ```
+    pub fn annotate(&self, sql: &str) -> String {
+        let Some(location) = self.source_location() else {
+            return self.to_string();
+        };
+
+        let mut offset = location.offset().min(sql.len());
+        while !sql.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let start = sql[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = sql[offset..].find('\n').map_or(sql.len(), |i| offset + i);
+        let line = sql[start..end].trim_end_matches('\r');
+        let line_number = sql[..start].matches('\n').count() + 1;
+
+        // Keep tabs in the padding so the caret lines up with the source line.
+        let padding: String = sql[start..offset]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let column = padding.chars().count() + 1;
+        let gutter = " ".repeat(line_number.to_string().len());
+
+        format!(
+            "{self}\n{gutter}--> line {line_number}, column {column}\n{gutter} |\n{line_number} | {line}\n{gutter} | {padding}^"
+        )
+    }
+
     const fn detail(&self) -> Option<&ErrorDetail> {
         self.inner.detail.as_ref()
     }
@@ -160,6 +280,8 @@ impl Error {
         match self.detail() {
             Some(ErrorDetail::Message(message)) => Some(message.as_str()),
             Some(ErrorDetail::SourceMessage(message, _)) => Some(message.as_str()),
+            #[cfg(feature = "std")]
+            Some(ErrorDetail::Io(message, _)) => Some(message.as_str()),
             _ => None,
         }
     }
@@ -216,10 +338,17 @@ impl core::error::Error for Error {
     }
 
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        #[cfg(feature = "std")]
+        if let Some(ErrorDetail::Io(_, error)) = self.detail() {
+            return Some(error.as_ref());
+        }
+
         if let Some(integration) = self.as_integration() {
             match *integration {
                 #[cfg(feature = "chrono")]
                 IntegrationError::Chrono(ref error) => Some(error),
+                #[cfg(feature = "decimal")]
+                IntegrationError::Decimal(ref bx) => Some(bx.as_ref()),
                 #[cfg(feature = "jiff")]
                 IntegrationError::Jiff(ref error) => Some(error),
                 #[cfg(all(feature = "serde", feature = "json"))]
@@ -249,6 +378,12 @@ impl From<core::ffi::FromBytesWithNulError> for Error {
     }
 }
 
+impl From<NulError> for Error {
+    fn from(_: NulError) -> Self {
+        Self::from(ErrorCategory::TextEncoding)
+    }
+}
+
 impl From<i32> for Error {
     #[cold]
     fn from(code: i32) -> Self {
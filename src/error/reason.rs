@@ -223,8 +223,12 @@ impl ErrorReason {
 
             // Squire errors
             super::code::SQUIRE_ERROR_ROW_NOT_RETURNED => Some(Self::Row(RowError::NotReturned)),
+            super::code::SQUIRE_ERROR_ROW_TOO_MANY => Some(Self::Row(RowError::TooMany)),
             super::code::SQUIRE_ERROR_FETCH_PARSE => Some(Self::Fetch(FetchError::Parse)),
             super::code::SQUIRE_ERROR_FETCH_RANGE => Some(Self::Fetch(FetchError::Range)),
+            super::code::SQUIRE_ERROR_FETCH_UNKNOWN_COLUMN => {
+                Some(Self::Fetch(FetchError::UnknownColumn))
+            }
             super::code::SQUIRE_ERROR_PARAMETER_BIND => Some(Self::Parameter(ParameterError::Bind)),
             super::code::SQUIRE_ERROR_PARAMETER_RANGE => {
                 Some(Self::Parameter(ParameterError::Range))
@@ -630,6 +634,10 @@ pub enum ReadOnlyError {
 pub enum RowError {
     /// The query didn't return a row.
     NotReturned = super::code::SQUIRE_ERROR_ROW_NOT_RETURNED,
+
+    /// The query returned more than one row, but at most one was
+    /// [expected](crate::Execution::optional).
+    TooMany = super::code::SQUIRE_ERROR_ROW_TOO_MANY,
 }
 
 /// An error reading a SQLite column value into its Rust type.
@@ -649,6 +657,10 @@ pub enum FetchError {
     /// SQLite is out of the range the destination Rust type can represent
     /// (e.g., fetching into a `u8` a value > 255).
     Range = super::code::SQUIRE_ERROR_FETCH_RANGE,
+
+    /// [Fetching](crate::Row::get_by_name) a column by name failed; the query
+    /// has no column with that name.
+    UnknownColumn = super::code::SQUIRE_ERROR_FETCH_UNKNOWN_COLUMN,
 }
 
 /// An error passing prepared statement parameter(s) to SQLite.
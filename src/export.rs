@@ -0,0 +1,379 @@
+//! Write query results out in common data formats.
+
+use std::io;
+
+#[cfg(all(feature = "json", feature = "serde"))]
+use squire_serde::{Serialize, Serializer, json};
+
+use crate::{
+    error::{Error, ErrorCode, Result},
+    statement::{Execute, Execution},
+    types::Value,
+};
+
+impl<'c, 's, S> Execution<'c, 's, S>
+where
+    S: Execute<'c, 's>,
+    'c: 's,
+{
+    /// Write every row returned by the query to `writer` as CSV, returning
+    /// the number of rows written.
+    ///
+    /// Unless [disabled](CsvOptions::header), the first line is a header with
+    /// the name of each column. Rows are fetched as [`Value`]s and written one
+    /// at a time, so the result set is never held in memory. Wrap `writer` in
+    /// a [`BufWriter`](io::BufWriter) if it is unbuffered.
+    ///
+    /// A `REAL` value is always written with a decimal point or exponent
+    /// (e.g., `1.0` or `1e300`), so it can be told apart from an `INTEGER`.
+    /// Infinite reals are written as `Inf` and `-Inf`, as SQLite itself
+    /// converts them to text. (SQLite stores NaN as `NULL`, so a `REAL` is
+    /// never NaN.)
+    ///
+    /// ```rust
+    /// # use squire::{Connection, export::CsvOptions};
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare("SELECT 1 AS id, 'a, b' AS name;")?;
+    ///
+    /// let mut csv = Vec::new();
+    /// query.query(())?.write_csv(&mut csv, CsvOptions::default())?;
+    /// assert_eq!(b"id,name\n1,\"a, b\"\n", csv.as_slice());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Fails with an [I/O error](crate::ErrorCategory::Io) if writing fails,
+    /// or a [parse error](crate::FetchError::Parse) if a `TEXT` value isn't
+    /// valid UTF-8.
+    pub fn write_csv(mut self, mut writer: impl io::Write, options: CsvOptions) -> Result<usize> {
+        let mut line = String::new();
+
+        if options.header {
+            let columns = self.cursor().columns();
+
+            for (i, column) in columns.iter().enumerate() {
+                if i > 0 {
+                    line.push(options.delimiter);
+                }
+                options.push_text(&mut line, columns.name(column).unwrap_or_default());
+            }
+
+            write_line(&mut writer, &mut line)?;
+        }
+
+        let mut rows = 0;
+        while let Some(row) = self.row()? {
+            for (i, value) in row.to_values()?.iter().enumerate() {
+                if i > 0 {
+                    line.push(options.delimiter);
+                }
+                options.push_value(&mut line, value);
+            }
+
+            write_line(&mut writer, &mut line)?;
+            rows += 1;
+        }
+
+        writer.flush().map_err(io_error)?;
+
+        Ok(rows)
+    }
+
+    /// Write every row returned by the query to `writer` as [newline-delimited
+    /// JSON][ndjson], returning the number of rows written.
+    ///
+    /// Each row is written as a JSON object on its own line, keyed by column
+    /// name. Values are mapped to JSON as:
+    ///
+    /// - `NULL` as `null`;
+    /// - `INTEGER` and `REAL` as numbers (or `null`, for infinite reals);
+    /// - `TEXT` as a string; and
+    /// - `BLOB` as a string, in standard [Base64](BlobEncoding::Base64) with
+    ///   padding.
+    ///
+    /// As with [`write_csv`](Self::write_csv), rows are written one at a
+    /// time, and never held in memory. If the query has more than one column
+    /// with the same name, each is written, and the object has duplicate keys.
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare("SELECT 1 AS id, 'a' AS name, NULL AS note;")?;
+    ///
+    /// let mut json = Vec::new();
+    /// query.query(())?.write_ndjson(&mut json)?;
+    /// assert_eq!(b"{\"id\":1,\"name\":\"a\",\"note\":null}\n", json.as_slice());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Fails with an [I/O error](crate::ErrorCategory::Io) if writing fails,
+    /// or a [parse error](crate::FetchError::Parse) if a `TEXT` value isn't
+    /// valid UTF-8.
+    ///
+    /// [ndjson]: https://github.com/ndjson/ndjson-spec
+    #[cfg(all(feature = "json", feature = "serde"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+    pub fn write_ndjson(mut self, mut writer: impl io::Write) -> Result<usize> {
+        let columns = self.cursor().columns();
+        let keys: Vec<String> = columns
+            .iter()
+            .map(|column| columns.name(column).unwrap_or_default().to_owned())
+            .collect();
+
+        let mut rows = 0;
+        while let Some(row) = self.row()? {
+            let values = row.to_values()?;
+            json::to_writer(&mut writer, &JsonRow(&keys, &values))
+                .map_err(|err| io_error(err.into()))?;
+            writer.write_all(b"\n").map_err(io_error)?;
+            rows += 1;
+        }
+
+        writer.flush().map_err(io_error)?;
+
+        Ok(rows)
+    }
+}
+
+/// A row written by [`write_ndjson`](Execution::write_ndjson), as a JSON
+/// object keyed by column name.
+#[cfg(all(feature = "json", feature = "serde"))]
+struct JsonRow<'a>(&'a [String], &'a [Value]);
+
+#[cfg(all(feature = "json", feature = "serde"))]
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        use squire_serde::ser::SerializeMap;
+
+        // Serialized as a map (rather than collected into one), so that
+        // duplicate column names are each written.
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in self.0.iter().zip(self.1) {
+            map.serialize_entry(key, &JsonValue(value))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(all(feature = "json", feature = "serde"))]
+struct JsonValue<'a>(&'a Value);
+
+#[cfg(all(feature = "json", feature = "serde"))]
+impl Serialize for JsonValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Null => serializer.serialize_unit(),
+            Value::Integer(value) => serializer.serialize_i64(*value),
+            Value::Real(value) if value.is_finite() => serializer.serialize_f64(*value),
+            Value::Real(_) => serializer.serialize_unit(),
+            Value::Text(value) => serializer.serialize_str(value),
+            Value::Blob(value) => {
+                let mut text = String::new();
+                BlobEncoding::Base64.encode(&mut text, value);
+                serializer.serialize_str(&text)
+            }
+        }
+    }
+}
+
+/// Format `value` so that it reads back as a `REAL`, never an `INTEGER`.
+fn real_to_string(value: f64) -> String {
+    if value.is_finite() {
+        // Unlike `Display`, `Debug` always writes a decimal point or exponent
+        format!("{value:?}")
+    } else if value.is_nan() {
+        "NaN".to_owned()
+    } else if value.is_sign_positive() {
+        "Inf".to_owned()
+    } else {
+        "-Inf".to_owned()
+    }
+}
+
+fn write_line(writer: &mut impl io::Write, line: &mut String) -> Result<()> {
+    line.push('\n');
+    writer.write_all(line.as_bytes()).map_err(io_error)?;
+    line.clear();
+
+    Ok(())
+}
+
+#[cold]
+fn io_error(err: io::Error) -> Error {
+    Error::with_detail(ErrorCode::IOERR_WRITE, err)
+}
+
+/// Configures how [`write_csv`](Execution::write_csv) formats rows.
+///
+/// The default writes a header line, separates fields with `,`, quotes only
+/// fields which need it, writes `NULL` as an empty field, and writes BLOBs in
+/// [hex](BlobEncoding::Hex).
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CsvOptions {
+    delimiter: char,
+    quoting: Quoting,
+    null: String,
+    blob: BlobEncoding,
+    header: bool,
+}
+
+impl CsvOptions {
+    /// Separate fields with `delimiter` instead of `,`.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Choose which fields are enclosed in double quotes.
+    pub fn quoting(mut self, quoting: Quoting) -> Self {
+        self.quoting = quoting;
+        self
+    }
+
+    /// Write `NULL` values as `null` (e.g., `"NULL"` or `"\N"`), instead of as
+    /// an empty field.
+    ///
+    /// `null` is written as-is, and is never quoted, so that a `NULL` can be
+    /// told apart from a quoted string with the same text.
+    pub fn null(mut self, null: impl Into<String>) -> Self {
+        self.null = null.into();
+        self
+    }
+
+    /// Choose how BLOB values are written as text.
+    pub fn blob(mut self, blob: BlobEncoding) -> Self {
+        self.blob = blob;
+        self
+    }
+
+    /// Write (or skip) the header line of column names.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    fn push_value(&self, line: &mut String, value: &Value) {
+        match value {
+            Value::Null => line.push_str(&self.null),
+            Value::Integer(value) => self.push_number(line, &value.to_string()),
+            Value::Real(value) => self.push_number(line, &real_to_string(*value)),
+            Value::Text(value) => self.push_text(line, value),
+            Value::Blob(value) => {
+                let mut text = String::with_capacity(value.len() * 2);
+                self.blob.encode(&mut text, value);
+                self.push_text(line, &text);
+            }
+        }
+    }
+
+    fn push_number(&self, line: &mut String, number: &str) {
+        match self.quoting {
+            Quoting::Always => self.push_quoted(line, number),
+            _ => line.push_str(number),
+        }
+    }
+
+    fn push_text(&self, line: &mut String, text: &str) {
+        let quote = match self.quoting {
+            Quoting::Necessary => {
+                text == self.null
+                    || text.contains([self.delimiter, '"', '\n', '\r'])
+                    || text.starts_with(' ')
+            }
+            Quoting::NonNumeric | Quoting::Always => true,
+        };
+
+        if quote {
+            self.push_quoted(line, text);
+        } else {
+            line.push_str(text);
+        }
+    }
+
+    fn push_quoted(&self, line: &mut String, text: &str) {
+        line.push('"');
+        for c in text.chars() {
+            if c == '"' {
+                line.push('"');
+            }
+            line.push(c);
+        }
+        line.push('"');
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quoting: Quoting::Necessary,
+            null: String::new(),
+            blob: BlobEncoding::Hex,
+            header: true,
+        }
+    }
+}
+
+/// Which CSV fields [`write_csv`](Execution::write_csv) encloses in double
+/// quotes.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Quoting {
+    /// Quote fields containing the delimiter, a quote, or a line break, and
+    /// text which would read back as [`NULL`](CsvOptions::null), such as an
+    /// empty string.
+    Necessary,
+    /// Quote every TEXT and BLOB field, but not numbers.
+    NonNumeric,
+    /// Quote every field except `NULL`.
+    Always,
+}
+
+/// How [`write_csv`](Execution::write_csv) writes BLOB values as text.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum BlobEncoding {
+    /// Upper-case hexadecimal, as returned by SQLite’s [`hex()`][hex].
+    ///
+    /// [hex]: https://sqlite.org/lang_corefunc.html#hex
+    Hex,
+    /// Standard [Base64][], with padding.
+    ///
+    /// [Base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+    Base64,
+}
+
+impl BlobEncoding {
+    fn encode(self, text: &mut String, bytes: &[u8]) {
+        match self {
+            Self::Hex => {
+                const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+                for &byte in bytes {
+                    text.push(DIGITS[usize::from(byte >> 4)] as char);
+                    text.push(DIGITS[usize::from(byte & 0xF)] as char);
+                }
+            }
+            Self::Base64 => {
+                const ALPHABET: &[u8; 64] =
+                    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+                for chunk in bytes.chunks(3) {
+                    let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+                        group | u32::from(byte) << (16 - 8 * i)
+                    });
+
+                    for i in 0..4 {
+                        if i <= chunk.len() {
+                            let index = (group >> (18 - 6 * i)) & 0x3F;
+                            text.push(ALPHABET[index as usize] as char);
+                        } else {
+                            text.push('=');
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
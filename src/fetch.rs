@@ -1,10 +1,18 @@
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{ffi::CStr, num::NonZero};
+
 #[cfg(feature = "value")]
 use crate::ffi::ValueRef;
 use crate::{
     error::{Error, ErrorCode, Result},
     ffi::{self, Fetch as _},
     statement::Statement,
-    types::{Borrowed, ColumnIndex, RowId, Type},
+    types::{Borrowed, ColumnIndex, RowId, StrictBool, Type},
 };
 
 #[cfg_attr(
@@ -30,20 +38,89 @@ pub trait Fetch<'r>: Sized {
     type Value: ffi::Fetch<'r>;
 
     fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Result<Self> {
-        let value = unsafe { Self::Value::fetch_column(statement.internal_ref(), column) };
+        let value = unsafe { Self::Value::try_fetch_column(statement.internal_ref(), column)? };
         Self::from_value(value)
     }
 
     #[cfg(feature = "value")]
     #[cfg_attr(docsrs, doc(cfg(any(feature = "functions", feature = "value"))))]
     fn fetch_value<'c>(value: &'r ValueRef<'c>) -> Result<Self> {
-        let value = unsafe { Self::Value::fetch_value(value) };
+        let value = unsafe { Self::Value::try_fetch_value(value)? };
         Self::from_value(value)
     }
 
     fn from_value(value: Self::Value) -> Result<Self>;
 }
 
+/// Check that a `TEXT` or `BLOB` value fetched with `len` bytes wasn't
+/// silently lost.
+///
+/// SQLite returns a `NULL` pointer, which reads as empty, when it can't
+/// produce a column value (e.g., when it exceeds [`SQLITE_LIMIT_LENGTH`][limit]
+/// or memory runs out). An empty value from a column that isn't empty is
+/// reported as an error.
+///
+/// [limit]: https://sqlite.org/c3ref/c_limit_attached.html#sqlitelimitlength
+#[inline]
+pub(crate) fn check_fetched(
+    statement: &ffi::Statement<'_>,
+    column: ColumnIndex,
+    len: usize,
+) -> Result<()> {
+    if len > 0 {
+        return Ok(());
+    }
+
+    match statement.column_bytes(column) {
+        0 => Ok(()),
+        size => Err(fetch_failed(statement, column, size)),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn fetch_failed(statement: &ffi::Statement<'_>, column: ColumnIndex, size: usize) -> Error {
+    use ffi::Connected as _;
+
+    let connection = statement.as_connection();
+    let (code, _) = unsafe { connection.last_error() };
+
+    if code & 0xff == sqlite::SQLITE_TOOBIG {
+        let limit = connection.limit(sqlite::SQLITE_LIMIT_LENGTH, -1);
+        Error::with_detail(
+            ErrorCode::SQUIRE_FETCH_RANGE,
+            format!(
+                "column {} is {size} bytes, over the length limit of {limit} bytes",
+                column.value()
+            ),
+        )
+    } else {
+        Error::from_connection(statement, code).unwrap_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::NOMEM,
+                    format!("could not fetch column {} of {size} bytes", column.value()),
+                )
+            },
+        )
+    }
+}
+
+/// Defines `fetch_column` for a [`Fetch`] type whose [`Value`](Fetch::Value)
+/// is `TEXT` or `BLOB`, [checking](check_fetched) that it was read in full.
+macro_rules! fetch_sized {
+    () => {
+        fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Result<Self> {
+            let value = unsafe {
+                <Self::Value as ffi::Fetch<'r>>::try_fetch_column(statement.internal_ref(), column)?
+            };
+            check_fetched(statement.internal_ref(), column, value.len())?;
+            Self::from_value(value)
+        }
+    };
+}
+
 /// Defines [`Fetch`] for a type that implements [`ffi::Fetch`].
 macro_rules! identity {
     ($($t:ty),+) => {
@@ -71,7 +148,7 @@ macro_rules! primitive {
 
             #[inline]
             fn from_value(value: Self::Value) -> Result<Self> {
-                Ok($t::from(value))
+                Ok(<$t>::from(value))
             }
         }
     };
@@ -105,14 +182,47 @@ primitive!(i64 :> isize);
 primitive!(i64 :> usize);
 identity!(i64);
 primitive!(i64 :> u64);
+primitive!(i64 => i128);
+primitive!(i64 :> u128);
 identity!(Type);
 
+/// Defines [`Fetch`] for a [`NonZero`] integer, returning a
+/// [range error](crate::FetchError::Range) if the stored value is `0`.
+macro_rules! nonzero {
+    ($($t:ty),+) => {
+        $(
+            impl<'r> Fetch<'r> for NonZero<$t> {
+                type Value = <$t as Fetch<'r>>::Value;
+
+                #[inline]
+                fn from_value(value: Self::Value) -> Result<Self> {
+                    NonZero::new(<$t>::from_value(value)?).ok_or_else(
+                        #[cold]
+                        || {
+                            Error::with_detail(
+                                ErrorCode::SQUIRE_FETCH_RANGE,
+                                concat!("NonZero<", stringify!($t), "> value cannot be 0"),
+                            )
+                        },
+                    )
+                }
+            }
+        )+
+    };
+}
+
+nonzero!(i32, i64, u32, u64);
+
 /// Read the column as an [`f64`] with [`sqlite3_column_double`][], and cast to
 /// [`f32`] with `value as f32`.
 ///
 /// If the value overflows an `f32` (a previously [finite](f64::is_finite())
 /// `f64` became [infinite](f32::is_infinite())), returns a [range error][].
 ///
+/// Values too small in magnitude to be a normal `f32` aren't an error: they
+/// round to the nearest [subnormal](f32::is_subnormal()) value, or to a zero
+/// of the same sign.
+///
 /// [`sqlite3_column_double`]: sqlite::sqlite3_column_double
 /// [range error]: crate::FetchError::Range
 impl<'r> Fetch<'r> for f32 {
@@ -149,6 +259,25 @@ impl<'r> Fetch<'r> for bool {
     }
 }
 
+/// Read the column as an [`i64`] with
+/// [`sqlite3_column_int64`](sqlite::sqlite3_column_int64); `1` is `true`, `0`
+/// is `false`, and any other value returns a
+/// [range error](crate::FetchError::Range).
+impl<'r> Fetch<'r> for StrictBool {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        match value {
+            0 => Ok(Self(false)),
+            1 => Ok(Self(true)),
+            _ => Err(Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_RANGE,
+                format!("expected a boolean 0 or 1, got {value}"),
+            )),
+        }
+    }
+}
+
 impl<'r> Fetch<'r> for RowId {
     type Value = i64;
 
@@ -160,12 +289,77 @@ impl<'r> Fetch<'r> for RowId {
     }
 }
 
+/// Text which isn't valid UTF-8 returns a [parse error](crate::FetchError::Parse).
+/// To replace invalid sequences instead, fetch a [`Cow<str>`](Cow).
+impl<'r> Fetch<'r> for Borrowed<'r, str> {
+    type Value = Self;
+
+    fetch_sized!();
+
+    #[inline]
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl<'r> Fetch<'r> for Borrowed<'r, [u8]> {
+    type Value = Self;
+
+    fetch_sized!();
+
+    #[inline]
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+/// Reads text as a C string, borrowed from the row without copying.
+///
+/// SQLite text can contain NUL characters; fetching text with an interior NUL
+/// returns a [parse error](crate::FetchError::Parse). `NULL` is fetched as an
+/// empty string.
+impl<'r> Fetch<'r> for Borrowed<'r, CStr> {
+    type Value = Borrowed<'r, [u8]>;
+
+    fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Result<Self> {
+        let value = unsafe { ffi::fetch_column_text_with_nul(statement.internal_ref(), column) };
+        check_fetched(
+            statement.internal_ref(),
+            column,
+            value.len().saturating_sub(1),
+        )?;
+        Self::from_value(value)
+    }
+
+    #[cfg(feature = "value")]
+    fn fetch_value<'c>(value: &'r ValueRef<'c>) -> Result<Self> {
+        let value = unsafe { ffi::fetch_value_text_with_nul(value) };
+        Self::from_value(value)
+    }
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        CStr::from_bytes_with_nul(value.into_inner())
+            .map(Borrowed::new)
+            .map_err(
+                #[cold]
+                |err| {
+                    Error::with_detail(
+                        ErrorCode::SQUIRE_FETCH_PARSE,
+                        format!("invalid C string: {err}"),
+                    )
+                },
+            )
+    }
+}
+
 impl<'r, 'a> Fetch<'r> for &'a str
 where
     'r: 'a,
 {
     type Value = Borrowed<'r, str>;
 
+    fetch_sized!();
+
     fn from_value(value: Self::Value) -> Result<Self> {
         // SAFETY: We have 'r: 'a, so shortening the lifetime from 'r to 'a is sound.
         // The caller ensures 'r outlives 'a, so the reference remains valid.
@@ -179,6 +373,8 @@ where
 {
     type Value = Borrowed<'r, [u8]>;
 
+    fetch_sized!();
+
     fn from_value(value: Self::Value) -> Result<Self> {
         // SAFETY: We have 'r: 'a, so shortening the lifetime from 'r to 'a is sound.
         // The caller ensures 'r outlives 'a, so the reference remains valid.
@@ -190,27 +386,91 @@ where
     }
 }
 
+/// Reads a one-character text column as a [`char`].
+///
+/// Text which isn't exactly one Unicode scalar value (including empty text)
+/// returns a [range error](crate::FetchError::Range).
+impl<'r> Fetch<'r> for char {
+    type Value = Borrowed<'r, str>;
+
+    fetch_sized!();
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_RANGE,
+                format!("expected a single character, got {} bytes", value.len()),
+            )),
+        }
+    }
+}
+
 impl<'r> Fetch<'r> for String {
     type Value = Borrowed<'r, str>;
 
+    fetch_sized!();
+
     fn from_value(value: Self::Value) -> Result<Self> {
         Ok(value.to_owned())
     }
 }
 
+/// Read the column as text, replacing any invalid UTF-8 with
+/// [`U+FFFD`](char::REPLACEMENT_CHARACTER).
+///
+/// Valid text is borrowed from the row without copying.
+impl<'r> Fetch<'r> for Cow<'r, str> {
+    type Value = Borrowed<'r, [u8]>;
+
+    fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Result<Self> {
+        let value = unsafe { ffi::fetch_column_text(statement.internal_ref(), column) };
+        check_fetched(statement.internal_ref(), column, value.len())?;
+        Self::from_value(value)
+    }
+
+    #[cfg(feature = "value")]
+    fn fetch_value<'c>(value: &'r ValueRef<'c>) -> Result<Self> {
+        let value = unsafe { ffi::fetch_value_text(value) };
+        Self::from_value(value)
+    }
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Ok(String::from_utf8_lossy(value.into_inner()))
+    }
+}
+
 impl<'r> Fetch<'r> for Vec<u8> {
     type Value = Borrowed<'r, [u8]>;
 
+    fetch_sized!();
+
     fn from_value(value: Self::Value) -> Result<Self> {
         Ok(value.to_owned())
     }
 }
 
+/// Fetching a `[u8; N]` from a BLOB whose length is not exactly `N` returns a
+/// [range error](crate::FetchError::Range).
 impl<'r, const N: usize> Fetch<'r> for [u8; N] {
     type Value = Borrowed<'r, [u8]>;
 
+    fetch_sized!();
+
     fn from_value(value: Self::Value) -> Result<Self> {
-        Self::try_from(value.into_inner()).map_err(|_| Error::new(ErrorCode::SQUIRE_FETCH_RANGE))
+        let bytes = value.into_inner();
+
+        Self::try_from(bytes).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_RANGE,
+                    format!("expected a {N}-byte blob, got {} bytes", bytes.len()),
+                )
+            },
+        )
     }
 }
 
@@ -220,6 +480,16 @@ where
 {
     type Value = Option<T::Value>;
 
+    fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Result<Self> {
+        let column_type = unsafe { Type::fetch_column(statement.internal_ref(), column) };
+
+        if column_type.has_value() {
+            T::fetch_column(statement, column).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     fn from_value(value: Self::Value) -> Result<Self> {
         Ok(match value {
             Some(value) => Some(T::from_value(value)?),
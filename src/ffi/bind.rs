@@ -1,3 +1,4 @@
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use core::{
     ffi::{c_char, c_uchar, c_void},
     ptr,
@@ -9,7 +10,7 @@ use super::{pointer::Pointee, statement::Statement};
 use crate::{
     blob::Reservation,
     error::{Error, Result},
-    types::{BindIndex, Borrowed},
+    types::{BindIndex, Borrowed, Null},
 };
 
 use sqlite::{
@@ -241,6 +242,33 @@ impl<'b> Bind<'b> for &str {
     }
 }
 
+/// [Binds](Bind) a [`char`] as one-character text, like a [`&str`](str).
+///
+/// Before Squire 0.0.1-alpha.13, a `char` was bound as its integer code point.
+/// To store code points, bind `u32::from(c)` instead.
+impl<'b> Bind<'b> for char {
+    unsafe fn bind_parameter<'c>(self, statement: &Statement<'c>, index: BindIndex) -> Result<()>
+    where
+        'c: 'b,
+    {
+        let mut buffer = [0; 4];
+        let text: &str = self.encode_utf8(&mut buffer);
+
+        unsafe { Bind::<'b>::bind_parameter(text, statement, index) }
+    }
+
+    #[cfg(feature = "functions")]
+    unsafe fn bind_return<'c>(self, context: &ContextRef<'c>)
+    where
+        'b: 'c,
+    {
+        let mut buffer = [0; 4];
+        let text: &str = self.encode_utf8(&mut buffer);
+
+        unsafe { Bind::<'b>::bind_return(text, context) }
+    }
+}
+
 #[cfg_attr(
     target_pointer_width = "32",
     doc = "[Binds](Bind) a [`&[u8]`](primitive@slice) via [`sqlite3_bind_blob`]."
@@ -523,6 +551,66 @@ impl<'b, 'a: 'b> Bind<'b> for Borrowed<'a, [u8]> {
     }
 }
 
+/// [Binds](Bind) a [`Cow<str>`](Cow) as text.
+///
+/// A [borrowed](Cow::Borrowed) string is bound like [`Borrowed<str>`], with the
+/// [`SQLITE_STATIC`] flag; an [owned](Cow::Owned) string is bound like a
+/// [`String`], with [`SQLITE_TRANSIENT`].
+impl<'b, 'a: 'b> Bind<'b> for Cow<'a, str> {
+    unsafe fn bind_parameter<'c>(self, statement: &Statement<'c>, index: BindIndex) -> Result<()>
+    where
+        'c: 'b,
+    {
+        match self {
+            Cow::Borrowed(value) => unsafe {
+                Borrowed::new(value).bind_parameter(statement, index)
+            },
+            Cow::Owned(value) => unsafe { value.bind_parameter(statement, index) },
+        }
+    }
+
+    #[cfg(feature = "functions")]
+    unsafe fn bind_return<'c>(self, context: &ContextRef<'c>)
+    where
+        'b: 'c,
+    {
+        match self {
+            Cow::Borrowed(value) => unsafe { Borrowed::new(value).bind_return(context) },
+            Cow::Owned(value) => unsafe { value.bind_return(context) },
+        }
+    }
+}
+
+/// [Binds](Bind) a [`Cow<[u8]>`](Cow) as a blob.
+///
+/// A [borrowed](Cow::Borrowed) slice is bound like [`Borrowed<[u8]>`], with
+/// the [`SQLITE_STATIC`] flag; an [owned](Cow::Owned) vector is bound like a
+/// [`Vec<u8>`], with [`SQLITE_TRANSIENT`].
+impl<'b, 'a: 'b> Bind<'b> for Cow<'a, [u8]> {
+    unsafe fn bind_parameter<'c>(self, statement: &Statement<'c>, index: BindIndex) -> Result<()>
+    where
+        'c: 'b,
+    {
+        match self {
+            Cow::Borrowed(value) => unsafe {
+                Borrowed::new(value).bind_parameter(statement, index)
+            },
+            Cow::Owned(value) => unsafe { value.bind_parameter(statement, index) },
+        }
+    }
+
+    #[cfg(feature = "functions")]
+    unsafe fn bind_return<'c>(self, context: &ContextRef<'c>)
+    where
+        'b: 'c,
+    {
+        match self {
+            Cow::Borrowed(value) => unsafe { Borrowed::new(value).bind_return(context) },
+            Cow::Owned(value) => unsafe { value.bind_return(context) },
+        }
+    }
+}
+
 /// [Binds](Bind) a reference using the [pointer passing interface].
 ///
 /// [pointer passing interface]: https://sqlite.org/bindptr.html
@@ -591,8 +679,7 @@ impl<'b, T: Pointee> Bind<'b> for Box<T> {
     }
 }
 
-pub(crate) struct Null;
-
+/// [Binds](Bind) `NULL` via [`sqlite3_bind_null`].
 impl<'b> Bind<'b> for Null {
     unsafe fn bind_parameter<'c>(self, statement: &Statement<'c>, index: BindIndex) -> Result<()>
     where
@@ -76,19 +76,40 @@ impl Bytes {
             return Ok(Self::empty());
         }
 
-        #[cfg(target_pointer_width = "32")]
-        let ptr = unsafe { sqlite3_malloc(len) as *mut c_uchar };
-        #[cfg(target_pointer_width = "64")]
-        let ptr = unsafe { sqlite3_malloc64(len as u64) as *mut c_uchar };
+        let ptr = malloc(len)?;
+        // Take ownership first, so the allocation is freed if `populate` fails.
+        let bytes = unsafe { Self::from_raw_parts(ptr, len) };
+
+        let data = unsafe { slice::from_raw_parts_mut(ptr, len) };
+        populate(data)?;
+
+        Ok(bytes)
+    }
 
-        if ptr.is_null() {
-            return Err(ErrorCategory::OutOfMemory.into());
+    /// Allocate `len` bytes on the [SQLite heap][free], and call `populate` to
+    /// fill in the bytes, returning an [`AllocError`] if SQLite is out of
+    /// memory.
+    ///
+    /// Like [`allocate`](Self::allocate), but with an infallible `populate`,
+    /// so that the only possible failure is the allocation itself.
+    ///
+    /// [free]: https://sqlite.org/c3ref/free.html
+    pub fn try_allocate<F: FnOnce(&mut [u8])>(
+        len: usize,
+        populate: F,
+    ) -> core::result::Result<Self, AllocError> {
+        if len == 0 {
+            return Ok(Self::empty());
         }
 
+        let ptr = malloc(len)?;
+        // Take ownership first, so the allocation is freed if `populate` panics.
+        let bytes = unsafe { Self::from_raw_parts(ptr, len) };
+
         let data = unsafe { slice::from_raw_parts_mut(ptr, len) };
-        populate(data)?;
+        populate(data);
 
-        Ok(unsafe { Self::from_raw_parts(ptr, len) })
+        Ok(bytes)
     }
 
     /// Allocate `len` `'\0'` bytes on the [SQLite heap][free].
@@ -253,6 +274,58 @@ impl<'b> Bind<'b> for Bytes {
     }
 }
 
+/// The error returned when [`sqlite3_malloc64`] fails to allocate a
+/// [`Bytes`].
+///
+/// An `AllocError` converts into an [`OutOfMemory`](ErrorCategory::OutOfMemory)
+/// [`Error`].
+///
+/// [`sqlite3_malloc64`]: https://sqlite.org/c3ref/free.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct AllocError {
+    len: usize,
+}
+
+impl AllocError {
+    /// The number of bytes which couldn't be allocated.
+    #[inline]
+    pub const fn requested(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to allocate {} bytes on the SQLite heap",
+            self.len
+        )
+    }
+}
+
+impl core::error::Error for AllocError {}
+
+impl From<AllocError> for Error {
+    #[cold]
+    fn from(_: AllocError) -> Self {
+        ErrorCategory::OutOfMemory.into()
+    }
+}
+
+fn malloc(len: usize) -> core::result::Result<*mut c_uchar, AllocError> {
+    #[cfg(target_pointer_width = "32")]
+    let ptr = unsafe { sqlite3_malloc(len as i32) as *mut c_uchar };
+    #[cfg(target_pointer_width = "64")]
+    let ptr = unsafe { sqlite3_malloc64(len as u64) as *mut c_uchar };
+
+    if ptr.is_null() {
+        Err(AllocError { len })
+    } else {
+        Ok(ptr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use sqlite::{SQLITE_OPEN_CREATE, SQLITE_OPEN_READWRITE};
@@ -301,6 +374,19 @@ mod tests {
         assert_eq!(&*bytes, &[1, 2, 3, 4, 5]);
     }
 
+    #[test]
+    fn test_try_allocate() {
+        let bytes =
+            Bytes::try_allocate(3, |dest| dest.copy_from_slice(&[7, 8, 9])).expect("try_allocate");
+        assert_eq!(&*bytes, &[7, 8, 9]);
+
+        let err = Bytes::try_allocate(usize::MAX / 2, |_| {}).expect_err("too large");
+        assert_eq!(err.requested(), usize::MAX / 2);
+
+        let err: Error = err.into();
+        assert_eq!(err.category(), Some(ErrorCategory::OutOfMemory));
+    }
+
     #[test]
     fn test_from_array() {
         let bytes: Bytes = [0xDE, 0xAD, 0xBE, 0xEF].into();
@@ -1,27 +1,60 @@
 #[cfg(feature = "functions")]
+use alloc::boxed::Box;
+use alloc::format;
+#[cfg(feature = "functions")]
 use core::ffi::c_void;
+#[cfg(sqlite_has_column_metadata)]
+use core::ffi::{c_char, c_int};
 use core::{ffi::CStr, fmt, ptr};
 
 #[cfg(feature = "functions")]
 use sqlite::sqlite3_create_function_v2;
+#[cfg(all(feature = "functions", sqlite_has_window_function))]
+use sqlite::sqlite3_create_window_function;
 #[cfg(sqlite_has_error_offset)]
 use sqlite::sqlite3_error_offset;
 #[cfg(sqlite_has_set_error_message)]
 use sqlite::sqlite3_set_errmsg;
+#[cfg(sqlite_has_column_metadata)]
+use sqlite::sqlite3_table_column_metadata;
+#[cfg(sqlite_has_serialize)]
+use sqlite::{
+    SQLITE_DESERIALIZE_FREEONCLOSE, sqlite3_deserialize, sqlite3_int64, sqlite3_serialize,
+};
 use sqlite::{
-    SQLITE_OK, SQLITE_OPEN_EXRESCODE, sqlite3, sqlite3_close, sqlite3_errcode, sqlite3_errmsg,
-    sqlite3_errstr, sqlite3_open_v2,
+    SQLITE_OK, SQLITE_OPEN_EXRESCODE, sqlite3, sqlite3_close, sqlite3_db_config,
+    sqlite3_db_filename, sqlite3_db_readonly, sqlite3_db_status, sqlite3_errcode, sqlite3_errmsg,
+    sqlite3_errstr, sqlite3_extended_result_codes, sqlite3_get_autocommit, sqlite3_limit,
+    sqlite3_open_v2, sqlite3_txn_state,
 };
 
+#[cfg(sqlite_has_serialize)]
+use super::bytes::Bytes;
 use super::call::call;
+#[cfg(all(feature = "functions", sqlite_has_window_function))]
+use super::func::{WindowFunction, inverse, value};
 #[cfg(feature = "mutex")]
 use super::mutex::MutexRef;
 #[cfg(feature = "functions")]
 use super::{
     bind::destroy_box,
-    func::{Function, call},
+    func::{Aggregate, Function, call, finish, step},
 };
 use crate::error::{Error, Result};
+#[cfg(sqlite_has_serialize)]
+use crate::error::{ErrorCategory, ErrorCode};
+
+/// The metadata of a table column, returned by
+/// [`Connection::table_column_metadata`].
+#[cfg(sqlite_has_column_metadata)]
+#[derive(Clone, Copy, Debug)]
+pub struct TableColumn<'a> {
+    pub declared_type: Option<&'a CStr>,
+    pub collation: Option<&'a CStr>,
+    pub not_null: bool,
+    pub primary_key: bool,
+    pub auto_increment: bool,
+}
 
 /// A thin wrapper around a [`sqlite3`] connection pointer.
 #[repr(transparent)]
@@ -191,6 +224,298 @@ impl Connection {
         }
     }
 
+    /// Define an [aggregate][] SQL function.
+    ///
+    /// [aggregate]: https://sqlite.org/c3ref/create_function.html
+    #[cfg(feature = "functions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
+    pub fn define_aggregate_function<A: Aggregate>(
+        &self,
+        name: &CStr,
+        func: A,
+        arity: i32,
+        flags: i32,
+    ) -> Result<()> {
+        let func = Box::into_raw(Box::new(func));
+
+        let result = unsafe {
+            sqlite3_create_function_v2(
+                self.as_ptr(),
+                name.as_ptr(),
+                arity,
+                flags,
+                func.cast::<c_void>(),
+                None,
+                Some(step::<A>),
+                Some(finish::<A>),
+                Some(destroy_box::<A>),
+            )
+        };
+
+        match Error::from_connection(self, result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Define an aggregate [window function][].
+    ///
+    /// [window function]: https://sqlite.org/c3ref/create_function.html
+    #[doc(alias = "sqlite3_create_window_function")]
+    #[cfg(all(feature = "functions", sqlite_has_window_function))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
+    pub fn define_window_function<W: WindowFunction>(
+        &self,
+        name: &CStr,
+        func: W,
+        arity: i32,
+        flags: i32,
+    ) -> Result<()> {
+        let func = Box::into_raw(Box::new(func));
+
+        let result = unsafe {
+            sqlite3_create_window_function(
+                self.as_ptr(),
+                name.as_ptr(),
+                arity,
+                flags,
+                func.cast::<c_void>(),
+                Some(step::<W>),
+                Some(finish::<W>),
+                Some(value::<W>),
+                Some(inverse::<W>),
+                Some(destroy_box::<W>),
+            )
+        };
+
+        match Error::from_connection(self, result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Query or change a [run-time limit][limit], returning its prior value.
+    ///
+    /// `id` is one of the [`SQLITE_LIMIT_*`][categories] constants. If `value`
+    /// is negative, the limit is left unchanged.
+    ///
+    /// [limit]: https://sqlite.org/c3ref/limit.html
+    /// [categories]: https://sqlite.org/c3ref/c_limit_attached.html
+    #[doc(alias = "sqlite3_limit")]
+    pub fn limit(&self, id: i32, value: i32) -> i32 {
+        unsafe { sqlite3_limit(self.as_ptr(), id, value) }
+    }
+
+    /// Enable or disable [extended result codes][extended] for the connection.
+    ///
+    /// Connections are opened with extended result codes enabled.
+    ///
+    /// [extended]: https://sqlite.org/rescode.html#extrc
+    #[doc(alias = "sqlite3_extended_result_codes")]
+    pub fn set_extended_result_codes(&self, enable: bool) -> Result<()> {
+        call! { sqlite3_extended_result_codes(self.as_ptr(), enable as i32) }
+    }
+
+    /// Enable or disable a boolean [configuration option][config], returning
+    /// whether it's enabled afterward.
+    ///
+    /// `op` is one of the `SQLITE_DBCONFIG_*` constants [taking][ops] an
+    /// `int` and an `int*`.
+    ///
+    /// [config]: https://sqlite.org/c3ref/db_config.html
+    /// [ops]: https://sqlite.org/c3ref/c_dbconfig_defensive.html
+    #[doc(alias = "sqlite3_db_config")]
+    pub fn set_config_flag(&self, op: i32, enable: bool) -> Result<bool> {
+        let mut enabled = 0;
+
+        call! { sqlite3_db_config(self.as_ptr(), op, enable as i32, &mut enabled as *mut i32) }?;
+
+        Ok(enabled != 0)
+    }
+
+    /// Read a [connection status][status] counter, returning its current and
+    /// highwater values. If `reset` is `true`, the highwater value is reset.
+    ///
+    /// `op` is one of the [`SQLITE_DBSTATUS_*`][ops] constants.
+    ///
+    /// [status]: https://sqlite.org/c3ref/db_status.html
+    /// [ops]: https://sqlite.org/c3ref/c_dbstatus_options.html
+    #[doc(alias = "sqlite3_db_status")]
+    pub fn status(&self, op: i32, reset: bool) -> Result<(i32, i32)> {
+        let mut current = 0;
+        let mut highwater = 0;
+
+        call! { sqlite3_db_status(self.as_ptr(), op, &mut current, &mut highwater, reset as i32) }?;
+
+        Ok((current, highwater))
+    }
+
+    /// Test whether this [`Connection`] is in [autocommit mode][autocommit]
+    /// (that is, not inside an explicit transaction).
+    ///
+    /// [autocommit]: https://sqlite.org/c3ref/get_autocommit.html
+    #[doc(alias = "sqlite3_get_autocommit")]
+    pub fn is_autocommit(&self) -> bool {
+        unsafe { sqlite3_get_autocommit(self.as_ptr()) != 0 }
+    }
+
+    /// Test whether the `schema` database (e.g., `c"main"`) is
+    /// [read-only][readonly], returning `None` if `schema` is not the name of
+    /// a database on this connection.
+    ///
+    /// [readonly]: https://sqlite.org/c3ref/db_readonly.html
+    #[doc(alias = "sqlite3_db_readonly")]
+    pub fn is_readonly(&self, schema: &CStr) -> Option<bool> {
+        match unsafe { sqlite3_db_readonly(self.as_ptr(), schema.as_ptr()) } {
+            -1 => None,
+            result => Some(result != 0),
+        }
+    }
+
+    /// The [filename][] of the `schema` database (e.g., `c"main"`).
+    ///
+    /// Returns `None` if `schema` is not the name of a database on this
+    /// connection. Returns an empty string for temporary and in-memory
+    /// databases.
+    ///
+    /// [filename]: https://sqlite.org/c3ref/db_filename.html
+    #[doc(alias = "sqlite3_db_filename")]
+    pub fn filename(&self, schema: &CStr) -> Option<&CStr> {
+        let filename = unsafe { sqlite3_db_filename(self.as_ptr(), schema.as_ptr()) };
+
+        if filename.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(filename) })
+        }
+    }
+
+    /// The [transaction state][state] of the `schema` database, or of the
+    /// most active database on the connection if `schema` is `None`.
+    ///
+    /// Returns one of the `SQLITE_TXN_*` constants, or `-1` if `schema` is not
+    /// the name of a database on this connection.
+    ///
+    /// [state]: https://sqlite.org/c3ref/txn_state.html
+    #[doc(alias = "sqlite3_txn_state")]
+    pub fn transaction_state(&self, schema: Option<&CStr>) -> i32 {
+        let schema = schema.map_or(ptr::null(), CStr::as_ptr);
+        unsafe { sqlite3_txn_state(self.as_ptr(), schema) }
+    }
+
+    /// Look up the [declared type, collation, and constraints][metadata] of
+    /// `column` in `table`, in the `schema` database or (if `None`) the first
+    /// attached database containing `table`.
+    ///
+    /// # Safety
+    ///
+    /// Callers must not retain the returned [`CStr`]s; per the SQLite docs,
+    /// they are only valid until the next call to any SQLite API function.
+    ///
+    /// [metadata]: https://sqlite.org/c3ref/table_column_metadata.html
+    #[doc(alias = "sqlite3_table_column_metadata")]
+    #[cfg(sqlite_has_column_metadata)]
+    pub unsafe fn table_column_metadata<'c, 'a>(
+        &'c self,
+        schema: Option<&CStr>,
+        table: &CStr,
+        column: &CStr,
+    ) -> Result<TableColumn<'a>>
+    where
+        'c: 'a,
+    {
+        let mut declared_type: *const c_char = ptr::null();
+        let mut collation: *const c_char = ptr::null();
+        let mut not_null: c_int = 0;
+        let mut primary_key: c_int = 0;
+        let mut auto_increment: c_int = 0;
+
+        let result = unsafe {
+            sqlite3_table_column_metadata(
+                self.as_ptr(),
+                schema.map_or(ptr::null(), CStr::as_ptr),
+                table.as_ptr(),
+                column.as_ptr(),
+                &mut declared_type,
+                &mut collation,
+                &mut not_null,
+                &mut primary_key,
+                &mut auto_increment,
+            )
+        };
+
+        if let Some(err) = Error::from_connection(self, result) {
+            return Err(err);
+        }
+
+        let text =
+            |value: *const c_char| (!value.is_null()).then(|| unsafe { CStr::from_ptr(value) });
+
+        Ok(TableColumn {
+            declared_type: text(declared_type),
+            collation: text(collation),
+            not_null: not_null != 0,
+            primary_key: primary_key != 0,
+            auto_increment: auto_increment != 0,
+        })
+    }
+
+    /// [Serialize][] the `schema` database (e.g., `c"main"`) into a [`Bytes`]
+    /// buffer on the SQLite heap.
+    ///
+    /// [Serialize]: https://sqlite.org/c3ref/serialize.html
+    #[doc(alias = "sqlite3_serialize")]
+    #[cfg(sqlite_has_serialize)]
+    pub fn serialize(&self, schema: &CStr) -> Result<Bytes> {
+        let mut size: sqlite3_int64 = -1;
+        let data = unsafe { sqlite3_serialize(self.as_ptr(), schema.as_ptr(), &mut size, 0) };
+
+        if !data.is_null() {
+            Ok(unsafe { Bytes::from_raw_parts(data, size as usize) })
+        } else if size == 0 {
+            Ok(Bytes::empty())
+        } else if size < 0 {
+            let message = format!("unknown database {}", schema.to_string_lossy());
+            Err(Error::with_detail(ErrorCode::ERROR, message))
+        } else {
+            Err(ErrorCategory::OutOfMemory.into())
+        }
+    }
+
+    /// [Deserialize][] `data` into the `schema` database (e.g., `c"main"`),
+    /// replacing its current contents.
+    ///
+    /// SQLite takes ownership of `data`, and will [free][] it when the
+    /// database is closed (or if `deserialize` fails). `flags` may include
+    /// `SQLITE_DESERIALIZE_READONLY` and `SQLITE_DESERIALIZE_RESIZEABLE`;
+    /// `SQLITE_DESERIALIZE_FREEONCLOSE` is always set.
+    ///
+    /// [Deserialize]: https://sqlite.org/c3ref/deserialize.html
+    /// [free]: https://sqlite.org/c3ref/free.html
+    #[doc(alias = "sqlite3_deserialize")]
+    #[cfg(sqlite_has_serialize)]
+    pub fn deserialize(&self, schema: &CStr, data: Bytes, flags: i32) -> Result<()> {
+        let (data, len) = data.into_raw_parts();
+        let len = len as sqlite3_int64;
+        let flags = (flags | SQLITE_DESERIALIZE_FREEONCLOSE) as core::ffi::c_uint;
+
+        let result = unsafe {
+            sqlite3_deserialize(
+                self.as_ptr(),
+                schema.as_ptr(),
+                data.cast_mut(),
+                len,
+                len,
+                flags,
+            )
+        };
+
+        match Error::from_connection(self, result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
     #[inline]
     pub(crate) unsafe fn dispose(&mut self) -> Result<()> {
         call! { sqlite3_close(self.as_ptr()) }
@@ -1,3 +1,5 @@
+use alloc::format;
+
 use sqlite::{
     sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_double, sqlite3_column_int,
     sqlite3_column_int64, sqlite3_column_text,
@@ -13,7 +15,10 @@ use super::pointer::{Pointee, Pointer, PointerMut};
 use super::statement::Statement;
 #[cfg(feature = "value")]
 use super::value::{OpaqueValueRef, ValueRef};
-use crate::types::{Borrowed, ColumnIndex, Type};
+use crate::{
+    error::{Error, ErrorCode, Result},
+    types::{Borrowed, ColumnIndex, Type},
+};
 
 #[cfg_attr(
     not(feature = "value"),
@@ -69,6 +74,41 @@ pub trait Fetch<'r> {
     unsafe fn fetch_value<'c>(value: &'r ValueRef<'c>) -> Self
     where
         'c: 'r;
+
+    /// [Fetch](Self::fetch_column) a column value, checking that it's valid
+    /// for this type (e.g., that text is valid UTF-8).
+    ///
+    /// # Safety
+    ///
+    /// As for [`fetch_column`](Self::fetch_column).
+    #[inline]
+    unsafe fn try_fetch_column<'c>(
+        statement: &'r Statement<'c>,
+        column: ColumnIndex,
+    ) -> Result<Self>
+    where
+        'c: 'r,
+        Self: Sized,
+    {
+        Ok(unsafe { Self::fetch_column(statement, column) })
+    }
+
+    /// [Unpack](Self::fetch_value) a dynamic value, checking that it's valid
+    /// for this type (e.g., that text is valid UTF-8).
+    ///
+    /// # Safety
+    ///
+    /// As for [`fetch_value`](Self::fetch_value).
+    #[cfg(feature = "value")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "functions", feature = "value"))))]
+    #[inline]
+    unsafe fn try_fetch_value<'c>(value: &'r ValueRef<'c>) -> Result<Self>
+    where
+        'c: 'r,
+        Self: Sized,
+    {
+        Ok(unsafe { Self::fetch_value(value) })
+    }
 }
 
 impl<'r> Fetch<'r> for i32 {
@@ -171,6 +211,10 @@ where
     }
 }
 
+/// [`fetch_column`](Fetch::fetch_column) and [`fetch_value`](Fetch::fetch_value)
+/// assume the text is valid UTF-8; callers must ensure it is, or use
+/// [`try_fetch_column`](Fetch::try_fetch_column) and
+/// [`try_fetch_value`](Fetch::try_fetch_value), which check.
 impl<'r> Fetch<'r> for Borrowed<'r, str> {
     unsafe fn fetch_column<'c>(statement: &'r Statement<'c>, column: ColumnIndex) -> Self
     where
@@ -192,6 +236,118 @@ impl<'r> Fetch<'r> for Borrowed<'r, str> {
 
         unsafe { Self::from_raw_str(data, len) }
     }
+
+    unsafe fn try_fetch_column<'c>(
+        statement: &'r Statement<'c>,
+        column: ColumnIndex,
+    ) -> Result<Self>
+    where
+        'c: 'r,
+    {
+        let bytes = unsafe { fetch_column_text(statement, column) };
+
+        core::str::from_utf8(bytes.into_inner())
+            .map(Borrowed::new)
+            .map_err(
+                #[cold]
+                |err| {
+                    Error::with_detail(
+                        ErrorCode::SQUIRE_FETCH_PARSE,
+                        format!("invalid UTF-8 in column {}: {err}", column.value()),
+                    )
+                },
+            )
+    }
+
+    #[cfg(feature = "value")]
+    unsafe fn try_fetch_value<'c>(value: &'r ValueRef<'c>) -> Result<Self>
+    where
+        'c: 'r,
+    {
+        let bytes = unsafe { fetch_value_text(value) };
+
+        core::str::from_utf8(bytes.into_inner())
+            .map(Borrowed::new)
+            .map_err(
+                #[cold]
+                |err| {
+                    Error::with_detail(
+                        ErrorCode::SQUIRE_FETCH_PARSE,
+                        format!("invalid UTF-8 text: {err}"),
+                    )
+                },
+            )
+    }
+}
+
+/// Read the UTF-8 text of a column as bytes, without assuming they're valid.
+///
+/// # Safety
+///
+/// As for [`Fetch::fetch_column`].
+pub(crate) unsafe fn fetch_column_text<'r, 'c>(
+    statement: &'r Statement<'c>,
+    column: ColumnIndex,
+) -> Borrowed<'r, [u8]>
+where
+    'c: 'r,
+{
+    let data = unsafe { sqlite3_column_text(statement.as_ptr(), column.value()) };
+    let len = unsafe { sqlite3_column_bytes(statement.as_ptr(), column.value()) };
+
+    unsafe { Borrowed::from_raw_bytes(data.cast(), len) }
+}
+
+/// Read the UTF-8 text of a column as bytes, including SQLite's NUL terminator.
+///
+/// # Safety
+///
+/// As for [`Fetch::fetch_column`].
+pub(crate) unsafe fn fetch_column_text_with_nul<'r, 'c>(
+    statement: &'r Statement<'c>,
+    column: ColumnIndex,
+) -> Borrowed<'r, [u8]>
+where
+    'c: 'r,
+{
+    let data = unsafe { sqlite3_column_text(statement.as_ptr(), column.value()) };
+    let len = unsafe { sqlite3_column_bytes(statement.as_ptr(), column.value()) };
+
+    unsafe { Borrowed::from_raw_bytes_with_nul(data, len) }
+}
+
+/// Read the UTF-8 text of a value as bytes, including SQLite's NUL terminator.
+///
+/// # Safety
+///
+/// As for [`Fetch::fetch_value`].
+#[cfg(feature = "value")]
+pub(crate) unsafe fn fetch_value_text_with_nul<'r, 'c>(
+    value: &'r ValueRef<'c>,
+) -> Borrowed<'r, [u8]>
+where
+    'c: 'r,
+{
+    let data = unsafe { sqlite3_value_text(value.as_ptr()) };
+    let len = unsafe { sqlite3_value_bytes(value.as_ptr()) };
+
+    unsafe { Borrowed::from_raw_bytes_with_nul(data, len) }
+}
+
+/// Read the UTF-8 text of a value as bytes, without assuming they're valid.
+///
+/// # Safety
+///
+/// As for [`Fetch::fetch_value`].
+#[cfg(feature = "value")]
+pub(crate) unsafe fn fetch_value_text<'r, 'c>(value: &'r ValueRef<'c>) -> Borrowed<'r, [u8]>
+where
+    'c: 'r,
+{
+    let data = unsafe { sqlite3_value_text(value.as_ptr()) };
+    let len = unsafe { sqlite3_value_bytes(value.as_ptr()) };
+
+    unsafe { Borrowed::from_raw_bytes(data.cast(), len) }
 }
 
 impl<'r> Fetch<'r> for Borrowed<'r, [u8]> {
@@ -1,3 +1,4 @@
+use alloc::{boxed::Box, string::ToString};
 use core::{
     ffi::{c_char, c_int},
     fmt,
@@ -6,12 +7,17 @@ use core::{
 };
 
 use sqlite::{
-    sqlite3, sqlite3_context, sqlite3_context_db_handle, sqlite3_result_error,
-    sqlite3_result_error_code, sqlite3_user_data, sqlite3_value,
+    SQLITE_ERROR, sqlite3, sqlite3_aggregate_context, sqlite3_context, sqlite3_context_db_handle,
+    sqlite3_result_error, sqlite3_result_error_code, sqlite3_result_error_nomem, sqlite3_user_data,
+    sqlite3_value,
 };
 
 use super::{bind::Bind, connection::Connected};
-use crate::ffi::ValueRef;
+use crate::{
+    error::{Error, Result},
+    ffi::ValueRef,
+    types::{Null, Value},
+};
 
 #[cfg(not(feature = "multi-thread"))]
 pub trait Function: 'static {
@@ -42,6 +48,156 @@ pub(super) unsafe extern "C" fn call<F: Function>(
     }
 }
 
+/// An aggregate [SQL function][], which [steps](Self::step) through each row
+/// in a group, accumulating a [`State`](Self::State), then
+/// [finishes](Self::finish) it into the function's result.
+///
+/// [SQL function]: https://sqlite.org/appfunc.html#the_aggregate_function_callbacks
+#[cfg(not(feature = "multi-thread"))]
+pub trait Aggregate: 'static {
+    /// The state accumulated for each group; starting from the default.
+    type State: Default;
+
+    /// Add a row's `arguments` to the `state`.
+    fn step<'a>(&self, state: &mut Self::State, arguments: &'a [ValueRef<'a>]) -> Result<()>;
+
+    /// Compute the function's result from the final `state` of a group.
+    fn finish(&self, state: Self::State) -> Result<Value>;
+}
+
+/// An aggregate [SQL function][], which [steps](Self::step) through each row
+/// in a group, accumulating a [`State`](Self::State), then
+/// [finishes](Self::finish) it into the function's result.
+///
+/// [SQL function]: https://sqlite.org/appfunc.html#the_aggregate_function_callbacks
+#[cfg(feature = "multi-thread")]
+pub trait Aggregate: Send + 'static {
+    /// The state accumulated for each group; starting from the default.
+    type State: Default;
+
+    /// Add a row's `arguments` to the `state`.
+    fn step<'a>(&self, state: &mut Self::State, arguments: &'a [ValueRef<'a>]) -> Result<()>;
+
+    /// Compute the function's result from the final `state` of a group.
+    fn finish(&self, state: Self::State) -> Result<Value>;
+}
+
+/// An aggregate [window function][], which can also remove rows that leave
+/// the window frame, and report its current result as the frame moves.
+///
+/// [window function]: https://sqlite.org/windowfunctions.html#udfwinfunc
+pub trait WindowFunction: Aggregate {
+    /// Remove a row's `arguments`, which were previously
+    /// [added](Aggregate::step), from the `state`.
+    fn inverse<'a>(&self, state: &mut Self::State, arguments: &'a [ValueRef<'a>]) -> Result<()>;
+
+    /// Compute the function's result from the current `state`, without
+    /// consuming it.
+    fn value(&self, state: &Self::State) -> Result<Value>;
+}
+
+/// Find the state slot SQLite allocates for each aggregate group, which holds
+/// a pointer to the boxed [`Aggregate::State`].
+///
+/// If `allocate` is `false` and the group has no slot yet, returns null.
+unsafe fn state_slot<A: Aggregate>(
+    context: *mut sqlite3_context,
+    allocate: bool,
+) -> *mut *mut A::State {
+    let size = if allocate {
+        mem::size_of::<*mut A::State>() as c_int
+    } else {
+        0
+    };
+
+    unsafe { sqlite3_aggregate_context(context, size).cast() }
+}
+
+/// Take the [`Aggregate::State`] of a group out of its slot, if it has one.
+unsafe fn take_state<A: Aggregate>(context: *mut sqlite3_context) -> Option<Box<A::State>> {
+    let slot = unsafe { state_slot::<A>(context, false) };
+    if slot.is_null() {
+        return None;
+    }
+
+    let state = unsafe { mem::replace(&mut *slot, ptr::null_mut()) };
+    if state.is_null() {
+        None
+    } else {
+        Some(unsafe { Box::from_raw(state) })
+    }
+}
+
+/// Access the user data and arguments passed to an aggregate callback, and
+/// the group's [`Aggregate::State`], creating it if needed.
+unsafe fn update<A: Aggregate>(
+    context: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+    f: impl for<'a> FnOnce(&A, &mut A::State, &'a [ValueRef<'a>]) -> Result<()>,
+) {
+    let function = unsafe { &*sqlite3_user_data(context).cast::<A>() };
+
+    let slot = unsafe { state_slot::<A>(context, true) };
+    if slot.is_null() {
+        unsafe { sqlite3_result_error_nomem(context) };
+        return;
+    }
+
+    if unsafe { (*slot).is_null() } {
+        unsafe { *slot = Box::into_raw(Box::default()) };
+    }
+    let state = unsafe { &mut **slot };
+
+    let arguments = unsafe { slice::from_raw_parts(argv, argc as usize) };
+    let arguments: &[ValueRef<'_>] = unsafe { mem::transmute(arguments) };
+
+    if let Err(err) = f(function, state, arguments) {
+        let mut context = ContextRef::new(context).expect("context");
+        context.set_failure(&err);
+    }
+}
+
+pub(super) unsafe extern "C" fn step<A: Aggregate>(
+    context: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    unsafe { update::<A>(context, argc, argv, A::step) }
+}
+
+pub(super) unsafe extern "C" fn finish<A: Aggregate>(context: *mut sqlite3_context) {
+    let function = unsafe { &*sqlite3_user_data(context).cast::<A>() };
+    let state = unsafe { take_state::<A>(context) }.map_or_else(A::State::default, |state| *state);
+
+    let mut context = ContextRef::new(context).expect("context");
+    context.set_outcome(function.finish(state));
+}
+
+#[cfg(sqlite_has_window_function)]
+pub(super) unsafe extern "C" fn value<W: WindowFunction>(context: *mut sqlite3_context) {
+    let function = unsafe { &*sqlite3_user_data(context).cast::<W>() };
+    let slot = unsafe { state_slot::<W>(context, false) };
+
+    let result = if slot.is_null() || unsafe { (*slot).is_null() } {
+        function.value(&W::State::default())
+    } else {
+        function.value(unsafe { &**slot })
+    };
+
+    let mut context = ContextRef::new(context).expect("context");
+    context.set_outcome(result);
+}
+
+#[cfg(sqlite_has_window_function)]
+pub(super) unsafe extern "C" fn inverse<W: WindowFunction>(
+    context: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    unsafe { update::<W>(context, argc, argv, W::inverse) }
+}
+
 /// A thin wrapper around a [`sqlite3_context`] function context.
 #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
 #[repr(transparent)]
@@ -102,6 +258,32 @@ impl<'a> ContextRef<'a> {
         unsafe { sqlite3_result_error_code(self.as_ptr(), code) };
     }
 
+    /// Report a Squire [`Error`] as the function's error; errors which don't
+    /// come from SQLite are reported as `SQLITE_ERROR`.
+    pub fn set_failure(&mut self, error: &Error) {
+        let code = if error.is_sqlite() {
+            error.code().raw()
+        } else {
+            SQLITE_ERROR
+        };
+
+        self.set_error(&error.to_string());
+        self.set_error_code(code);
+    }
+
+    /// Set the function's result to a dynamically-typed [`Value`], or report
+    /// its [error](Self::set_failure).
+    fn set_outcome(&mut self, result: Result<Value>) {
+        match result {
+            Ok(Value::Null) => unsafe { self.set_result(Null) },
+            Ok(Value::Integer(value)) => unsafe { self.set_result(value) },
+            Ok(Value::Real(value)) => unsafe { self.set_result(value) },
+            Ok(Value::Text(value)) => unsafe { self.set_result(value) },
+            Ok(Value::Blob(value)) => unsafe { self.set_result(value) },
+            Err(err) => self.set_failure(&err),
+        }
+    }
+
     /// Access the raw [`sqlite3_context`] pointer.
     #[inline]
     pub const fn as_ptr(&self) -> *mut sqlite3_context {
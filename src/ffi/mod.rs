@@ -16,6 +16,7 @@ mod connection;
 mod fetch;
 #[cfg(feature = "functions")]
 mod func;
+#[cfg(feature = "std")]
 mod location;
 #[cfg(feature = "mutex")]
 mod mutex;
@@ -27,12 +28,19 @@ mod value;
 
 pub use crate::types::ColumnIndex;
 pub use bind::{Bind, destructor};
-pub use bytes::Bytes;
+pub use bytes::{AllocError, Bytes};
+#[cfg(sqlite_has_column_metadata)]
+pub use connection::TableColumn;
 pub use connection::{Connected, Connection};
 pub use fetch::Fetch;
+pub(crate) use fetch::{fetch_column_text, fetch_column_text_with_nul};
+#[cfg(feature = "value")]
+pub(crate) use fetch::{fetch_value_text, fetch_value_text_with_nul};
 #[cfg(feature = "functions")]
 #[cfg_attr(docsrs, doc(cfg(feature = "functions")))]
-pub use func::{ContextRef, Function};
+pub use func::{Aggregate, ContextRef, Function, WindowFunction};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use location::{IntoLocation, Location};
 #[cfg(feature = "mutex")]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "mutex", feature = "serialized"))))]
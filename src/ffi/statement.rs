@@ -1,5 +1,5 @@
 use core::{
-    ffi::{CStr, c_char, c_int},
+    ffi::{CStr, c_char, c_int, c_void},
     fmt,
     marker::PhantomData,
     ptr,
@@ -9,18 +9,27 @@ use core::{
 use sqlite::sqlite3_changes;
 #[cfg(target_pointer_width = "64")]
 use sqlite::sqlite3_changes64;
+#[cfg(sqlite_has_column_declared_type)]
+use sqlite::sqlite3_column_decltype;
+#[cfg(sqlite_has_normalize_sql)]
+use sqlite::sqlite3_normalized_sql;
 use sqlite::{
     SQLITE_DONE, SQLITE_OK, SQLITE_ROW, sqlite3, sqlite3_bind_parameter_count,
-    sqlite3_bind_parameter_name, sqlite3_clear_bindings, sqlite3_column_count, sqlite3_column_name,
-    sqlite3_data_count, sqlite3_db_handle, sqlite3_finalize, sqlite3_prepare_v3, sqlite3_reset,
-    sqlite3_step, sqlite3_stmt,
+    sqlite3_bind_parameter_name, sqlite3_clear_bindings, sqlite3_column_bytes,
+    sqlite3_column_count, sqlite3_column_name, sqlite3_data_count, sqlite3_db_handle,
+    sqlite3_expanded_sql, sqlite3_finalize, sqlite3_free, sqlite3_prepare_v3, sqlite3_reset,
+    sqlite3_sql, sqlite3_step, sqlite3_stmt, sqlite3_stmt_busy, sqlite3_stmt_isexplain,
+    sqlite3_stmt_readonly, sqlite3_stmt_status,
 };
+#[cfg(sqlite_has_column_metadata)]
+use sqlite::{sqlite3_column_database_name, sqlite3_column_origin_name, sqlite3_column_table_name};
 
 use super::{
     bind::Bind,
     call::call,
     connection::{Connected, Connection},
     fetch::Fetch,
+    string::String,
 };
 use crate::{
     error::{Error, ErrorCategory, Result},
@@ -34,11 +43,11 @@ pub struct Statement<'c> {
     _connection: PhantomData<fn() -> &'c Connection>,
 }
 
-#[cfg(any(feature = "multi-thread", feature = "serialized"))]
-#[cfg_attr(
-    docsrs,
-    doc(cfg(any(feature = "multi-thread", feature = "serialized")))
-)]
+// In multi-thread mode, a statement must not be used on one thread while its
+// connection is used on another, so a `Statement` (which borrows its
+// connection) is only `Send` when SQLite serializes access to the connection.
+#[cfg(feature = "serialized")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialized")))]
 unsafe impl<'c> Send for Statement<'c> {}
 
 #[cfg(feature = "serialized")]
@@ -115,11 +124,106 @@ impl<'c> Statement<'c> {
         }
     }
 
+    /// The declared type of the table column that `index` returns, if it is a
+    /// table column (and not an expression or subquery).
+    #[cfg(sqlite_has_column_declared_type)]
+    #[doc(alias = "sqlite3_column_decltype")]
+    pub fn column_declared_type(&self, index: ColumnIndex) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_column_decltype(self.as_ptr(), index.value()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    /// The name of the database containing the table column that `index`
+    /// returns.
+    #[cfg(sqlite_has_column_metadata)]
+    #[doc(alias = "sqlite3_column_database_name")]
+    pub fn column_database_name(&self, index: ColumnIndex) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_column_database_name(self.as_ptr(), index.value()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    /// The name of the table containing the table column that `index` returns.
+    #[cfg(sqlite_has_column_metadata)]
+    #[doc(alias = "sqlite3_column_table_name")]
+    pub fn column_table_name(&self, index: ColumnIndex) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_column_table_name(self.as_ptr(), index.value()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    /// The name of the table column that `index` returns, which may differ
+    /// from its [name](Self::column_name) in the result set.
+    #[cfg(sqlite_has_column_metadata)]
+    #[doc(alias = "sqlite3_column_origin_name")]
+    pub fn column_origin_name(&self, index: ColumnIndex) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_column_origin_name(self.as_ptr(), index.value()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    /// The SQL text used to prepare the statement.
+    #[doc(alias = "sqlite3_sql")]
+    pub fn sql(&self) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_sql(self.as_ptr()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    /// The SQL text of the statement, with bound parameter values substituted
+    /// for their placeholders.
+    ///
+    /// Returns `None` if SQLite could not allocate the string, if it would
+    /// exceed the [length limit](crate::Limit::Length), or if it is not valid
+    /// UTF-8.
+    #[doc(alias = "sqlite3_expanded_sql")]
+    pub fn expanded_sql(&self) -> Option<String> {
+        let ptr = unsafe { sqlite3_expanded_sql(self.as_ptr()) };
+        if ptr.is_null() {
+            return None;
+        }
+
+        let text = unsafe { CStr::from_ptr(ptr) };
+        if text.to_str().is_ok() {
+            // SAFETY: `sqlite3_expanded_sql` returns memory obtained from
+            // `sqlite3_malloc64`, which `String` frees when dropped.
+            Some(unsafe { String::from_raw_parts(ptr, text.count_bytes()) })
+        } else {
+            unsafe { sqlite3_free(ptr as *mut c_void) };
+            None
+        }
+    }
+
+    /// The [normalized][] SQL text of the statement.
+    ///
+    /// [normalized]: https://sqlite.org/c3ref/expanded_sql.html
+    #[cfg(sqlite_has_normalize_sql)]
+    #[doc(alias = "sqlite3_normalized_sql")]
+    pub fn normalized_sql(&self) -> Option<&CStr> {
+        let ptr = unsafe { sqlite3_normalized_sql(self.as_ptr()) };
+        unsafe { Self::c_str(ptr) }
+    }
+
+    unsafe fn c_str<'a>(ptr: *const c_char) -> Option<&'a CStr> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr) })
+        }
+    }
+
     #[doc(alias = "sqlite3_column_count")]
     pub fn column_count(&self) -> c_int {
         unsafe { sqlite3_column_count(self.as_ptr()) }
     }
 
+    /// Read the size in bytes of a column's `TEXT` or `BLOB` value in the
+    /// current row, excluding any NUL terminator.
+    ///
+    /// `INTEGER` and `REAL` values are converted to text to be measured;
+    /// `NULL` (or an out-of-range column) has a size of `0`.
+    #[doc(alias = "sqlite3_column_bytes")]
+    pub fn column_bytes(&self, index: ColumnIndex) -> usize {
+        let len = unsafe { sqlite3_column_bytes(self.as_ptr(), index.value()) };
+        len.max(0) as usize
+    }
+
     /// Return the highest (1-based) parameter index used by this [`Statement`].
     #[doc(alias = "sqlite3_bind_parameter_count")]
     pub fn parameter_count(&self) -> c_int {
@@ -239,6 +343,38 @@ impl<'c> Statement<'c> {
         unsafe { T::fetch_column(self, column) }
     }
 
+    /// Check if the statement makes no direct changes to the database file.
+    #[doc(alias = "sqlite3_stmt_readonly")]
+    pub fn is_readonly(&self) -> bool {
+        unsafe { sqlite3_stmt_readonly(self.as_ptr()) != 0 }
+    }
+
+    /// Check if the statement is an `EXPLAIN` (`1`) or `EXPLAIN QUERY PLAN`
+    /// (`2`) statement, or an ordinary statement (`0`).
+    #[doc(alias = "sqlite3_stmt_isexplain")]
+    pub fn explain_mode(&self) -> c_int {
+        unsafe { sqlite3_stmt_isexplain(self.as_ptr()) }
+    }
+
+    /// Check if the statement has been stepped, but not yet run to completion
+    /// or reset.
+    #[doc(alias = "sqlite3_stmt_busy")]
+    pub fn is_busy(&self) -> bool {
+        unsafe { sqlite3_stmt_busy(self.as_ptr()) != 0 }
+    }
+
+    /// Read a [statement status][status] counter. If `reset` is `true`, the
+    /// counter is reset to zero.
+    ///
+    /// `op` is one of the [`SQLITE_STMTSTATUS_*`][ops] constants.
+    ///
+    /// [status]: https://sqlite.org/c3ref/stmt_status.html
+    /// [ops]: https://sqlite.org/c3ref/c_stmtstatus_counter.html
+    #[doc(alias = "sqlite3_stmt_status")]
+    pub fn status(&self, op: c_int, reset: bool) -> c_int {
+        unsafe { sqlite3_stmt_status(self.as_ptr(), op, reset as c_int) }
+    }
+
     #[doc(alias = "sqlite3_data_count")]
     pub fn data_count(&mut self) -> c_int {
         unsafe { sqlite3_data_count(self.as_ptr()) }
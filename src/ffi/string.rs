@@ -6,6 +6,7 @@ use core::{
     ops::Deref,
     ptr, slice,
 };
+#[cfg(feature = "std")]
 use std::io;
 
 #[cfg(target_pointer_width = "32")]
@@ -26,7 +27,7 @@ use sqlite::{
 use super::{bind::result, func::ContextRef};
 use super::{
     bind::{Bind, bind},
-    bytes::Bytes,
+    bytes::{AllocError, Bytes},
     connection::Connected,
     statement::Statement,
 };
@@ -129,7 +130,16 @@ impl String {
     ///
     /// [`sqlite3_malloc64`]: https://sqlite.org/c3ref/free.html
     pub fn empty() -> Result<Self> {
-        let bytes = Bytes::zeroed(1)?;
+        Ok(Self::try_empty()?)
+    }
+
+    /// Return an empty [`String`], consisting of just the null terminator,
+    /// or an [`AllocError`] if [`sqlite3_malloc64`] cannot allocate a single
+    /// byte.
+    ///
+    /// [`sqlite3_malloc64`]: https://sqlite.org/c3ref/free.html
+    pub fn try_empty() -> core::result::Result<Self, AllocError> {
+        let bytes = Bytes::try_allocate(1, |data| data.fill(0))?;
         Ok(unsafe { Self::from_bytes_unchecked(bytes) })
     }
 
@@ -454,6 +464,8 @@ impl fmt::Write for StringBuilder {
     }
 }
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl io::Write for StringBuilder {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         // SAFETY: `sqlite3_str_append` is safe to call with any valid
@@ -1,3 +1,4 @@
+use alloc::borrow::ToOwned;
 use core::{borrow::Borrow, fmt, marker::PhantomData, mem, ptr};
 
 use sqlite::{sqlite3_value, sqlite3_value_dup, sqlite3_value_free};
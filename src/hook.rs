@@ -0,0 +1,259 @@
+use core::{
+    ffi::{CStr, c_char, c_int, c_void},
+    fmt,
+    marker::PhantomData,
+    ptr,
+};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use sqlite::{
+    SQLITE_DELETE, SQLITE_INSERT, SQLITE_UPDATE, sqlite3, sqlite3_preupdate_count,
+    sqlite3_preupdate_depth, sqlite3_preupdate_hook, sqlite3_preupdate_new, sqlite3_preupdate_old,
+    sqlite3_value,
+};
+
+use crate::{
+    connection::Connection,
+    error::{Error, ErrorCategory, Result},
+    fetch::Fetch,
+    ffi::ValueRef,
+    types::ColumnIndex,
+};
+
+pub(crate) type PreUpdateHook = Box<dyn FnMut(PreUpdate<'_>) + Send>;
+
+impl Connection {
+    /// Register a [pre-update hook][hook], which is called before each row
+    /// of a rowid table is inserted, updated, or deleted.
+    ///
+    /// The hook receives a [`PreUpdate`], which describes the change and can
+    /// read the row’s values before and after it. Replaces any previously
+    /// registered pre-update hook. If the hook panics, the panic is caught,
+    /// and the change is still made.
+    ///
+    /// The hook must not use this connection.
+    ///
+    /// [hook]: https://sqlite.org/c3ref/preupdate_blobwrite.html
+    #[cfg_attr(docsrs, doc(cfg(feature = "preupdate-hook")))]
+    #[doc(alias = "sqlite3_preupdate_hook")]
+    pub fn set_preupdate_hook<F>(&self, hook: F)
+    where
+        F: FnMut(PreUpdate<'_>) + Send + 'static,
+    {
+        let mut hook: Box<PreUpdateHook> = Box::new(Box::new(hook));
+        let context = ptr::from_mut::<PreUpdateHook>(&mut hook).cast::<c_void>();
+
+        let mut slot = self.preupdate_hook();
+        unsafe {
+            sqlite3_preupdate_hook(
+                self.internal_ref().as_ptr(),
+                Some(call_preupdate_hook),
+                context,
+            )
+        };
+        *slot = Some(hook);
+    }
+
+    /// Remove the [pre-update hook](Self::set_preupdate_hook), if one is
+    /// registered.
+    #[cfg_attr(docsrs, doc(cfg(feature = "preupdate-hook")))]
+    pub fn clear_preupdate_hook(&self) {
+        let mut slot = self.preupdate_hook();
+        unsafe { sqlite3_preupdate_hook(self.internal_ref().as_ptr(), None, ptr::null_mut()) };
+        *slot = None;
+    }
+}
+
+unsafe extern "C" fn call_preupdate_hook(
+    context: *mut c_void,
+    db: *mut sqlite3,
+    op: c_int,
+    database: *const c_char,
+    table: *const c_char,
+    old_rowid: i64,
+    new_rowid: i64,
+) {
+    let hook = unsafe { &mut *context.cast::<PreUpdateHook>() };
+    let Some(op) = PreUpdateOp::from_raw(op) else {
+        return;
+    };
+
+    let update = PreUpdate {
+        db,
+        op,
+        database: unsafe { CStr::from_ptr(database) },
+        table: unsafe { CStr::from_ptr(table) },
+        old_rowid,
+        new_rowid,
+        _hook: PhantomData,
+    };
+
+    let _ = catch_unwind(AssertUnwindSafe(|| hook(update)));
+}
+
+/// The kind of change reported to a [pre-update hook](Connection::set_preupdate_hook).
+#[cfg_attr(docsrs, doc(cfg(feature = "preupdate-hook")))]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum PreUpdateOp {
+    /// A row is being inserted.
+    #[doc(alias = "SQLITE_INSERT")]
+    Insert = SQLITE_INSERT,
+    /// A row is being updated.
+    #[doc(alias = "SQLITE_UPDATE")]
+    Update = SQLITE_UPDATE,
+    /// A row is being deleted.
+    #[doc(alias = "SQLITE_DELETE")]
+    Delete = SQLITE_DELETE,
+}
+
+impl PreUpdateOp {
+    /// The `SQLITE_INSERT`, `SQLITE_UPDATE`, or `SQLITE_DELETE` constant for
+    /// this operation.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`PreUpdateOp`] for a `SQLITE_INSERT`, `SQLITE_UPDATE`, or
+    /// `SQLITE_DELETE` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_INSERT => Some(Self::Insert),
+            SQLITE_UPDATE => Some(Self::Update),
+            SQLITE_DELETE => Some(Self::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// A change about to be made to a row, passed to a
+/// [pre-update hook](Connection::set_preupdate_hook).
+///
+/// A `PreUpdate` (and any [values](ValueRef) read from it) is only valid
+/// within the hook call which received it.
+#[cfg_attr(docsrs, doc(cfg(feature = "preupdate-hook")))]
+pub struct PreUpdate<'a> {
+    db: *mut sqlite3,
+    op: PreUpdateOp,
+    database: &'a CStr,
+    table: &'a CStr,
+    old_rowid: i64,
+    new_rowid: i64,
+    _hook: PhantomData<&'a mut PreUpdateHook>,
+}
+
+impl<'a> PreUpdate<'a> {
+    /// Whether the row is being inserted, updated, or deleted.
+    pub const fn op(&self) -> PreUpdateOp {
+        self.op
+    }
+
+    /// The name of the database being changed (e.g., `"main"`), or `None` if
+    /// the name is not valid UTF-8.
+    pub fn database(&self) -> Option<&'a str> {
+        str::from_utf8(self.database.to_bytes()).ok()
+    }
+
+    /// The name of the table being changed, or `None` if the name is not
+    /// valid UTF-8.
+    pub fn table(&self) -> Option<&'a str> {
+        str::from_utf8(self.table.to_bytes()).ok()
+    }
+
+    /// The rowid of the row before the change, or `None` for an
+    /// [insert](PreUpdateOp::Insert).
+    pub const fn old_rowid(&self) -> Option<i64> {
+        match self.op {
+            PreUpdateOp::Insert => None,
+            _ => Some(self.old_rowid),
+        }
+    }
+
+    /// The rowid of the row after the change, or `None` for a
+    /// [delete](PreUpdateOp::Delete).
+    pub const fn new_rowid(&self) -> Option<i64> {
+        match self.op {
+            PreUpdateOp::Delete => None,
+            _ => Some(self.new_rowid),
+        }
+    }
+
+    /// The number of columns in the row being changed.
+    #[doc(alias = "sqlite3_preupdate_count")]
+    pub fn count(&self) -> usize {
+        unsafe { sqlite3_preupdate_count(self.db) as usize }
+    }
+
+    /// The trigger depth of the change: `0` for a change made directly by a
+    /// statement, `1` for a change made by a trigger, and so on.
+    #[doc(alias = "sqlite3_preupdate_depth")]
+    pub fn depth(&self) -> usize {
+        unsafe { sqlite3_preupdate_depth(self.db) as usize }
+    }
+
+    /// Read a column of the row before it is updated or deleted.
+    ///
+    /// Fails with a [misuse](ErrorCategory::Misuse) error for an
+    /// [insert](PreUpdateOp::Insert), or a [range](ErrorCategory::Range)
+    /// error if `column` is out of bounds.
+    #[doc(alias = "sqlite3_preupdate_old")]
+    pub fn old_value(&self, column: ColumnIndex) -> Result<ValueRef<'a>> {
+        self.value(column, sqlite3_preupdate_old)
+    }
+
+    /// Read a column of the row after it is inserted or updated.
+    ///
+    /// Fails with a [misuse](ErrorCategory::Misuse) error for a
+    /// [delete](PreUpdateOp::Delete), or a [range](ErrorCategory::Range)
+    /// error if `column` is out of bounds.
+    #[doc(alias = "sqlite3_preupdate_new")]
+    pub fn new_value(&self, column: ColumnIndex) -> Result<ValueRef<'a>> {
+        self.value(column, sqlite3_preupdate_new)
+    }
+
+    /// [Fetch](Fetch) a column of the row before it is updated or deleted.
+    pub fn fetch_old<T>(&self, column: ColumnIndex) -> Result<T>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        T::fetch_value(&self.old_value(column)?)
+    }
+
+    /// [Fetch](Fetch) a column of the row after it is inserted or updated.
+    pub fn fetch_new<T>(&self, column: ColumnIndex) -> Result<T>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        T::fetch_value(&self.new_value(column)?)
+    }
+
+    fn value(
+        &self,
+        column: ColumnIndex,
+        read: unsafe extern "C" fn(*mut sqlite3, c_int, *mut *mut sqlite3_value) -> c_int,
+    ) -> Result<ValueRef<'a>> {
+        let mut value = ptr::null_mut();
+        let result = unsafe { read(self.db, column.value(), &mut value) };
+
+        if let Some(err) = Error::from_code(result) {
+            return Err(err);
+        }
+
+        ValueRef::new(value).ok_or_else(
+            #[cold]
+            || Error::from(ErrorCategory::Misuse),
+        )
+    }
+}
+
+impl fmt::Debug for PreUpdate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreUpdate")
+            .field("op", &self.op)
+            .field("database", &self.database())
+            .field("table", &self.table())
+            .field("old_rowid", &self.old_rowid())
+            .field("new_rowid", &self.new_rowid())
+            .finish()
+    }
+}
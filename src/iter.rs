@@ -3,6 +3,11 @@
 //! These concrete types are exposed in Squire’s API, but they don’t normally
 //! need to be referenced by name.
 
+use core::{
+    ffi::c_int,
+    ops::{Bound, Range, RangeBounds},
+};
+
 use crate::{
     column::{ColumnIndexes, Columns},
     error::Result,
@@ -111,3 +116,19 @@ impl Iterator for BindIndexes {
         (usize::MAX, None)
     }
 }
+
+/// Clamp `range` to a half-open range of index values no less than `min`.
+pub(crate) fn index_range(range: impl RangeBounds<c_int>, min: c_int) -> Range<c_int> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => min,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => c_int::MAX,
+    };
+
+    start.max(min)..end.max(min)
+}
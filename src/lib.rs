@@ -9,7 +9,7 @@
 //! #     all(nightly, feature = "lang-array-assume-init"),
 //! #     feature(maybe_uninit_array_assume_init)
 //! # )]
-//! use squire::{Columns, Connection, Memory};
+//! use squire::{Columns, Connection, Memory, Null};
 //!
 //! #[derive(Columns, PartialEq, Eq, Clone, Debug)]
 //! struct User {
@@ -33,7 +33,7 @@
 //! let mut add_user = connection.prepare("INSERT INTO users (username, email) VALUES (?, ?)")?;
 //!
 //! add_user.execute(("alice", "alice@example.com"))?;
-//! add_user.execute(("bob", None::<&str>))?;
+//! add_user.execute(("bob", Null))?;
 //!
 //! let mut select_users = connection.prepare("SELECT * FROM users")?;
 //! let users: Vec<User> = select_users.query(())?.all()?;
@@ -49,6 +49,13 @@
 //! # }
 //! ```
 //!
+//! Without the default `std` feature, Squire builds as `no_std` (using
+//! [`alloc`]). Opening databases by path or [`Endpoint`] needs `std`, so a
+//! `no_std` [`Connection`] wraps a `sqlite3` pointer opened elsewhere, with
+//! [`Connection::from_raw`]. Other APIs which rely on the standard library,
+//! like VFS registration, backups, sessions, authorizers, and [`std::net`]
+//! and [`std::time`] conversions, are also only available with `std`.
+//!
 //! [SQLite]: https://sqlite.org/
 
 #![cfg_attr(
@@ -65,54 +72,147 @@
 )]
 #![cfg_attr(all(nightly, feature = "lang-step-trait"), feature(step_trait))]
 #![cfg_attr(docsrs, feature(doc_cfg), deny(rustdoc::broken_intra_doc_links))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
+#[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+mod auth;
+#[cfg(feature = "std")]
+mod backup;
 mod bind;
+pub mod bits;
 mod blob;
+mod cache;
 mod column;
+#[cfg(sqlite_has_complete)]
+mod complete;
 mod connection;
+#[cfg(feature = "std")]
 mod endpoint;
 mod error;
+#[cfg(feature = "std")]
+pub mod export;
 mod fetch;
 pub mod ffi;
+#[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+mod hook;
 pub mod iter;
+#[cfg(feature = "std")]
+mod library;
+mod memory;
+#[cfg(feature = "tokio")]
+mod nonblocking;
 mod param;
+#[cfg(feature = "r2d2")]
+mod pool;
+mod pragma;
 mod query;
+#[cfg(feature = "std")]
+mod retry;
 mod row;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(all(feature = "std", sqlite_has_session))]
+mod session;
+#[cfg(sqlite_has_snapshot)]
+mod snapshot;
 mod statement;
+mod status;
+mod transaction;
 mod types;
+#[cfg(feature = "std")]
+mod vfs;
 
+#[cfg(feature = "std")]
+pub use backup::{Backup, BackupProgress};
 pub use bind::Bind;
 pub use blob::Reservation;
-pub use column::{ColumnIndexes, Columns};
-pub use connection::{Connection, ConnectionBuilder};
-pub use endpoint::{Endpoint, IntoEndpoint, Local, Uri};
+pub use cache::CachedStatement;
+pub use column::{ColumnIndexes, Columns, resolve_named, resolve_named_from, resolve_positional};
+#[cfg(sqlite_has_complete)]
+pub use complete::is_complete;
+pub use connection::Connection;
+#[cfg(feature = "std")]
+pub use connection::ConnectionBuilder;
+#[cfg(feature = "std")]
+pub use endpoint::{Endpoint, IntoEndpoint, Local, Uri, UriBuilder, UriCache, UriMode};
 pub use error::{
-    AbortError, AuthorizationError, BusyError, CantOpenError, ConstraintError, CorruptError, Error,
-    ErrorCategory, ErrorCode, ErrorContainer, ErrorLocation, ErrorReason, FetchError, GeneralError,
-    IntegrationError, IoError, LockedError, ParameterError, ReadOnlyError, Result, RowError,
-    TextEncodingError,
+    AbortError, AuthorizationError, BusyError, CantOpenError, ConstraintError, ConstraintTarget,
+    CorruptError, Error, ErrorCategory, ErrorCode, ErrorContainer, ErrorLocation, ErrorReason,
+    FetchError, GeneralError, IntegrationError, IoError, LockedError, ParameterError,
+    ReadOnlyError, Result, RowError, TextEncodingError,
 };
+pub use features::{FeatureKey, Version, directive::Threading};
 pub use fetch::Fetch;
-pub use param::Parameters;
-pub use query::Query;
-pub use row::{Row, Rows};
+#[cfg(feature = "std")]
+pub use library::{LibraryInfo, library};
+pub use memory::{set_hard_heap_limit, set_soft_heap_limit};
+#[cfg(feature = "tokio")]
+pub use nonblocking::AsyncConnection;
+pub use param::{NamedParameters, Parameters, Positional};
+#[cfg(feature = "r2d2")]
+pub use pool::SquireConnectionManager;
+pub use pragma::{CacheSize, Checkpoint, CheckpointMode, JournalMode, Synchronous};
+pub use query::{CheckedQuery, Query};
+#[cfg(feature = "std")]
+pub use retry::RetryPolicy;
+pub use row::{Row, RowRef, Rows};
 pub use statement::{
-    Binding, Execution, PrepareOptions, Statement, StatementColumns, StatementParameters,
+    Binding, ColumnOrigin, Completion, Execution, ExplainMode, PrepareOptions, Statement,
+    StatementColumns, StatementParameters,
+};
+pub use status::{DbStatus, Status, StmtCounter, status};
+pub use transaction::{DropBehavior, Transaction};
+pub use types::{
+    Affinity, BindIndex, Borrowed, ColumnIndex, Encoding, Limit, Null, RowId, StrictBool, TxnState,
+    Type, Value, Wide,
 };
-pub use types::{BindIndex, Borrowed, ColumnIndex, Encoding, RowId, Type};
+#[cfg(feature = "std")]
+pub use vfs::{LockLevel, Vfs, VfsFile, default_vfs, register_vfs, vfs_names};
 
-#[cfg(sqlite_has_memory_database)]
+#[cfg(all(feature = "std", sqlite_has_authorization_callback))]
+pub use auth::{AuthAction, AuthResult};
+#[cfg(sqlite_has_column_metadata)]
+pub use connection::ColumnMeta;
+#[cfg(all(feature = "std", sqlite_has_memory_database))]
 pub use endpoint::Memory;
+#[cfg(all(feature = "std", sqlite_has_temporary_database))]
+pub use endpoint::Temporary;
+#[cfg(sqlite_has_memory_management)]
+pub use memory::release_memory;
+#[cfg(feature = "std")]
+pub use types::PackedIp;
+#[cfg(sqlite_has_carray)]
+pub use types::{Carray, CarrayElement};
 
+#[cfg(all(feature = "std", sqlite_has_pre_update_hook, feature = "value"))]
+pub use hook::{PreUpdate, PreUpdateOp};
+
+#[cfg(all(feature = "std", sqlite_has_session))]
+pub use session::{ConflictResolution, ConflictType, Session};
+#[cfg(sqlite_has_snapshot)]
+pub use snapshot::Snapshot;
+
+#[cfg(feature = "functions")]
+pub use ffi::{Aggregate, WindowFunction};
 #[cfg(feature = "utf-16")]
 pub use types::ByteOrder;
+#[cfg(sqlite_has_serialize)]
+pub use types::DeserializeFlags;
 #[cfg(feature = "functions")]
 pub use types::FunctionOptions;
 #[cfg(all(feature = "json", feature = "serde"))]
 pub use types::Json;
 #[cfg(all(feature = "jsonb", feature = "serde"))]
 pub use types::Jsonb;
+#[cfg(feature = "decimal")]
+pub use types::ScaledDecimal;
+#[cfg(feature = "jiff")]
+pub use types::SqlText;
+#[cfg(feature = "std-time")]
+pub use types::{Micros, Millis, Secs, UnixMillis, UnixSeconds};
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use squire_derive::{Columns, Parameters};
+pub use squire_derive::{Columns, Parameters, query};
@@ -0,0 +1,76 @@
+use std::sync::OnceLock;
+
+use features::{Configuration, FeatureKey, Version, directive::Threading};
+
+/// The SQLite [version](Self::version), [threading mode](Self::threading),
+/// and [features](Self::has_feature) detected when Squire was built.
+///
+/// ```rust
+/// use squire::FeatureKey;
+///
+/// let library = squire::library();
+/// if library.has_feature(FeatureKey::Json) {
+///     // ...
+/// }
+/// ```
+///
+/// These are the same checks Squire uses to enable its own APIs, exposed so
+/// that applications can test for SQLite capabilities at runtime without
+/// re-implementing Squire’s build-time detection.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LibraryInfo {
+    version: Version,
+    threading: Threading,
+    features: Configuration,
+}
+
+impl LibraryInfo {
+    fn detect() -> Self {
+        let version = env!("SQUIRE_SQLITE_VERSION")
+            .parse()
+            .expect("SQUIRE_SQLITE_VERSION is set by Squire's build script");
+        let threading = env!("SQUIRE_SQLITE_THREADING")
+            .parse()
+            .ok()
+            .and_then(Threading::from_value)
+            .unwrap_or_default();
+        let features = env!("SQUIRE_SQLITE_FEATURES")
+            .split(',')
+            .filter_map(|key| key.parse().ok())
+            .collect();
+
+        Self {
+            version,
+            threading,
+            features,
+        }
+    }
+
+    /// The version of the SQLite library Squire was built against.
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+
+    /// The [threading mode](https://sqlite.org/threadsafe.html) SQLite was
+    /// compiled with.
+    pub const fn threading(&self) -> Threading {
+        self.threading
+    }
+
+    /// Check if a SQLite [feature](FeatureKey) was detected; i.e., if the
+    /// `sqlite_has_*` cfg for the feature was set when Squire was built.
+    pub fn has_feature(&self, key: FeatureKey) -> bool {
+        self.features.is_enabled(key)
+    }
+
+    /// List every SQLite [feature](FeatureKey) which was detected.
+    pub fn features(&self) -> impl Iterator<Item = FeatureKey> + '_ {
+        self.features.enabled()
+    }
+}
+
+/// Describe the SQLite library Squire was [built](LibraryInfo) against.
+pub fn library() -> &'static LibraryInfo {
+    static LIBRARY: OnceLock<LibraryInfo> = OnceLock::new();
+    LIBRARY.get_or_init(LibraryInfo::detect)
+}
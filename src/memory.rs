@@ -0,0 +1,54 @@
+#[cfg(sqlite_has_memory_management)]
+use sqlite::sqlite3_release_memory;
+use sqlite::{sqlite3_hard_heap_limit64, sqlite3_soft_heap_limit64};
+
+/// Set SQLite’s [soft heap limit][limit] to `bytes`, returning the previous
+/// limit. A limit of `0` disables it; a negative `bytes` leaves the limit
+/// unchanged, and only returns it.
+///
+/// When memory allocated by SQLite nears the soft limit, SQLite tries to
+/// free cached pages to stay below it, but allocations still succeed if it
+/// can't. The soft limit can't exceed the [hard limit](set_hard_heap_limit).
+///
+/// The limit is global: it applies to every connection in the process. Unlike
+/// `release_memory`, the heap limits don't need SQLite to be built with
+/// [`SQLITE_ENABLE_MEMORY_MANAGEMENT`][mm], so they're always available.
+///
+/// [limit]: https://sqlite.org/c3ref/hard_heap_limit64.html
+/// [mm]: https://sqlite.org/compile.html#enable_memory_management
+#[doc(alias = "sqlite3_soft_heap_limit64")]
+pub fn set_soft_heap_limit(bytes: i64) -> i64 {
+    unsafe { sqlite3_soft_heap_limit64(bytes) }
+}
+
+/// Set SQLite’s [hard heap limit][limit] to `bytes`, returning the previous
+/// limit. A limit of `0` disables it; a negative `bytes` leaves the limit
+/// unchanged, and only returns it.
+///
+/// Once memory allocated by SQLite reaches the hard limit, further
+/// allocations fail, and SQLite returns [out of memory][oom] errors.
+///
+/// The limit is global: it applies to every connection in the process.
+///
+/// [limit]: https://sqlite.org/c3ref/hard_heap_limit64.html
+/// [oom]: crate::ErrorCategory::OutOfMemory
+#[doc(alias = "sqlite3_hard_heap_limit64")]
+pub fn set_hard_heap_limit(bytes: i64) -> i64 {
+    unsafe { sqlite3_hard_heap_limit64(bytes) }
+}
+
+/// Ask SQLite to [free][release] up to `bytes` of memory held by the caches
+/// of every connection in the process, returning the number of bytes freed.
+///
+/// Only available if SQLite was built with
+/// [`SQLITE_ENABLE_MEMORY_MANAGEMENT`][mm]; without it, SQLite never frees
+/// any memory here.
+///
+/// [release]: https://sqlite.org/c3ref/release_memory.html
+/// [mm]: https://sqlite.org/compile.html#enable_memory_management
+#[cfg(sqlite_has_memory_management)]
+#[cfg_attr(docsrs, doc(cfg(feature = "memory-management")))]
+#[doc(alias = "sqlite3_release_memory")]
+pub fn release_memory(bytes: i32) -> i32 {
+    unsafe { sqlite3_release_memory(bytes) }
+}
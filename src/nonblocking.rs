@@ -0,0 +1,124 @@
+use std::{sync::mpsc, thread};
+
+use tokio::sync::oneshot;
+
+use crate::{
+    column::Columns,
+    connection::{Connection, ConnectionBuilder},
+    endpoint::{Endpoint, IntoEndpoint},
+    error::{Error, ErrorCategory, Result},
+    param::Parameters,
+};
+
+type Job = Box<dyn FnOnce(&Connection) + Send>;
+
+/// A [`Connection`] owned by a dedicated worker thread, which can be used from
+/// `async` code without blocking the runtime.
+///
+/// Each call sends a closure to the worker thread, which runs it against the
+/// connection and sends back the result. Calls run one at a time, in the order
+/// they were made.
+///
+/// Dropping a returned future before it completes does not cancel the call;
+/// the worker still runs it, and discards the result. The worker thread exits
+/// once the `AsyncConnection` is dropped and all pending calls have run. (If
+/// a call panics, the worker thread exits, and later calls return an error.)
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+#[derive(Debug)]
+pub struct AsyncConnection {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl AsyncConnection {
+    /// Open a read/write connection to a [database](Endpoint) on a new worker
+    /// thread.
+    pub async fn open<E>(endpoint: E) -> Result<Self>
+    where
+        E: IntoEndpoint,
+        E::Endpoint: Send + 'static,
+    {
+        Self::open_with(Connection::builder(endpoint)).await
+    }
+
+    /// Open a connection configured by a [`ConnectionBuilder`] on a new worker
+    /// thread.
+    pub async fn open_with<E>(builder: ConnectionBuilder<E>) -> Result<Self>
+    where
+        E: Endpoint + Send + 'static,
+    {
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let (opened, open_result) = oneshot::channel();
+
+        thread::Builder::new()
+            .name("squire-connection".to_owned())
+            .spawn(move || {
+                let connection = match builder.open() {
+                    Ok(connection) => {
+                        let _ = opened.send(Ok(()));
+                        connection
+                    }
+                    Err(err) => {
+                        let _ = opened.send(Err(err));
+                        return;
+                    }
+                };
+
+                for job in queue {
+                    job(&connection);
+                }
+            })
+            .map_err(|_| Error::from(ErrorCategory::CantOpen))?;
+
+        open_result.await.map_err(|_| disconnected())??;
+
+        Ok(Self { jobs })
+    }
+
+    /// Run a closure with the [`Connection`] on the worker thread, and return
+    /// its result.
+    ///
+    /// This is an escape hatch to run any synchronous Squire code without
+    /// blocking the async runtime.
+    pub async fn call<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&Connection) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (send, receive) = oneshot::channel();
+        let job: Job = Box::new(move |connection| {
+            let _ = send.send(f(connection));
+        });
+
+        self.jobs.send(job).map_err(|_| disconnected())?;
+
+        receive.await.map_err(|_| disconnected())
+    }
+
+    /// [Execute](Connection::execute) a SQL statement on the worker thread,
+    /// and return the number of affected rows.
+    pub async fn execute<P>(&self, query: impl Into<String>, parameters: P) -> Result<isize>
+    where
+        P: for<'a> Parameters<'a> + Send + 'static,
+    {
+        let query = query.into();
+        self.call(move |connection| connection.execute(query, parameters))
+            .await?
+    }
+
+    /// Run a SQL query on the worker thread, and return its single row (see
+    /// [`Execution::one`](crate::Execution::one)).
+    pub async fn query_one<C, P>(&self, query: impl Into<String>, parameters: P) -> Result<C>
+    where
+        C: for<'r> Columns<'r> + Send + 'static,
+        P: for<'a> Parameters<'a> + Send + 'static,
+    {
+        let query = query.into();
+        self.call(move |connection| connection.prepare(query)?.query(parameters)?.one())
+            .await?
+    }
+}
+
+#[cold]
+fn disconnected() -> Error {
+    Error::from(ErrorCategory::Misuse)
+}
@@ -1,7 +1,13 @@
+use alloc::{collections::BTreeMap, format, vec, vec::Vec};
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
 use crate::{
     bind::Bind,
-    error::Result,
-    statement::{Binding, Statement},
+    error::{Error, ErrorCode, Result},
+    statement::{Binding, Execute, Statement},
     types::BindIndex,
 };
 
@@ -16,6 +22,13 @@ pub trait Parameters<'s> {
         'c: 's;
 }
 
+/// [`Parameters`] which are bound only by name, and so can be flattened into
+/// another set of named `Parameters` with `#[squire(flatten)]`.
+///
+/// `#[derive(Parameters)]` implements this trait for structs that use named
+/// parameters.
+pub trait NamedParameters<'s>: Parameters<'s> {}
+
 impl<'s, T> Parameters<'s> for T
 where
     T: Bind<'s>,
@@ -109,16 +122,188 @@ macro_rules! tuple {
     };
 }
 
-tuple!(a: A);
-tuple!(a: A, b: B);
-tuple!(a: A, b: B, c: C);
-tuple!(a: A, b: B, c: C, d: D);
-tuple!(a: A, b: B, c: C, d: D, e: E);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L);
-tuple!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M);
+/// Implement [`Parameters`] for each tuple type, up to the arity of the arguments.
+macro_rules! tuples {
+    ($($i:ident: $t:ident),+ $(,)?) => {
+        tuples!(@ [] $($i: $t),+);
+    };
+
+    (@ [$($ai:ident: $at:ident),*] $i:ident: $t:ident $(, $ri:ident: $rt:ident)*) => {
+        tuple!($($ai: $at,)* $i: $t);
+        tuples!(@ [$($ai: $at,)* $i: $t] $($ri: $rt),*);
+    };
+
+    (@ [$($ai:ident: $at:ident),*]) => {};
+}
+
+tuples!(
+    a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H,
+    i: I, j: J, k: K, l: L, m: M, n: N, o: O, p: P,
+    q: Q, r: R, s: S, t: T, u: U, v: V, w: W, x: X,
+    y: Y, z: Z, aa: AA, ab: AB, ac: AC, ad: AD, ae: AE, af: AF,
+);
+
+/// Bind each element of a slice or [`Vec`] to consecutive positional
+/// parameters, starting with `?1`.
+///
+/// This is useful for generated `IN (?, ?, ?)` clauses. (`&[T]` and `Vec<T>`
+/// can't implement [`Parameters`] directly, because `&[u8]` and `Vec<u8>`
+/// already [bind](Bind) as a single BLOB parameter.)
+///
+/// Binding fails with a [resolve error](crate::ParameterError::Resolve) if the
+/// number of elements doesn't match the number of statement parameters.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct Positional<C>(pub C);
+
+impl<'s, T> Parameters<'s> for Positional<&[T]>
+where
+    T: Bind<'s> + Clone,
+{
+    type Indexes = usize;
+
+    #[inline]
+    fn resolve<'c>(statement: &Statement<'c>) -> Option<Self::Indexes> {
+        Some(statement.parameters().len())
+    }
+
+    fn bind<'c>(self, binding: &mut Binding<'c, 's>, count: Self::Indexes) -> Result<()>
+    where
+        'c: 's,
+    {
+        bind_positional(binding, count, self.0.len(), self.0.iter().cloned())
+    }
+}
+
+impl<'s, T> Parameters<'s> for Positional<Vec<T>>
+where
+    T: Bind<'s>,
+{
+    type Indexes = usize;
+
+    #[inline]
+    fn resolve<'c>(statement: &Statement<'c>) -> Option<Self::Indexes> {
+        Some(statement.parameters().len())
+    }
+
+    fn bind<'c>(self, binding: &mut Binding<'c, 's>, count: Self::Indexes) -> Result<()>
+    where
+        'c: 's,
+    {
+        bind_positional(binding, count, self.0.len(), self.0)
+    }
+}
+
+fn bind_positional<'c, 's, T>(
+    binding: &mut Binding<'c, 's>,
+    count: usize,
+    len: usize,
+    values: impl IntoIterator<Item = T>,
+) -> Result<()>
+where
+    T: Bind<'s>,
+    'c: 's,
+{
+    if len != count {
+        return Err(Error::with_detail(
+            ErrorCode::SQUIRE_PARAMETER_RESOLVE,
+            format!("statement has {count} parameters, but {len} values were given"),
+        ));
+    }
+
+    for (index, value) in BindIndex::INITIAL.iter().zip(values) {
+        binding.set(index, value)?;
+    }
+
+    Ok(())
+}
+
+/// Bind named parameters (like `:name`, `@name`, or `$name`) from a map.
+///
+/// Keys may be given with or without their sigil. A key which doesn't name any
+/// statement parameter fails with a
+/// [resolve error](crate::ParameterError::Resolve). Statement parameters with
+/// no corresponding map entry are bound to `NULL`.
+#[cfg(feature = "std")]
+impl<'s, K, V, S> Parameters<'s> for HashMap<K, V, S>
+where
+    K: AsRef<str>,
+    V: Bind<'s>,
+    S: BuildHasher,
+{
+    type Indexes = ();
+
+    #[inline(always)]
+    fn resolve<'c>(_statement: &Statement<'c>) -> Option<Self::Indexes> {
+        Some(())
+    }
+
+    fn bind<'c>(self, binding: &mut Binding<'c, 's>, _indexes: Self::Indexes) -> Result<()>
+    where
+        'c: 's,
+    {
+        bind_named(binding, self)
+    }
+}
+
+/// Bind named parameters from a map, like the [`HashMap`] implementation.
+impl<'s, K, V> Parameters<'s> for BTreeMap<K, V>
+where
+    K: AsRef<str>,
+    V: Bind<'s>,
+{
+    type Indexes = ();
+
+    #[inline(always)]
+    fn resolve<'c>(_statement: &Statement<'c>) -> Option<Self::Indexes> {
+        Some(())
+    }
+
+    fn bind<'c>(self, binding: &mut Binding<'c, 's>, _indexes: Self::Indexes) -> Result<()>
+    where
+        'c: 's,
+    {
+        bind_named(binding, self)
+    }
+}
+
+fn bind_named<'c, 's, K, V>(
+    binding: &mut Binding<'c, 's>,
+    entries: impl IntoIterator<Item = (K, V)>,
+) -> Result<()>
+where
+    K: AsRef<str>,
+    V: Bind<'s>,
+    'c: 's,
+{
+    let parameters = binding.cursor().parameters();
+    let mut unbound = vec![true; parameters.len()];
+
+    let mut values = Vec::with_capacity(unbound.len());
+    for (key, value) in entries {
+        let key = key.as_ref();
+        let index = parameters.index(key).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RESOLVE,
+                    format!("statement has no parameter named {key:?}"),
+                )
+            },
+        )?;
+
+        unbound[usize::from(index) - 1] = false;
+        values.push((index, value));
+    }
+
+    for (index, value) in values {
+        binding.set(index, value)?;
+    }
+
+    for (index, unbound) in BindIndex::INITIAL.iter().zip(unbound) {
+        if unbound {
+            binding.set(index, None::<i64>)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::{
+    connection::{Connection, ConnectionBuilder},
+    endpoint::{Endpoint, IntoEndpoint},
+    error::Error,
+};
+
+/// An [`r2d2`] connection manager, which opens a pool of Squire
+/// [connections](Connection).
+///
+/// Each pooled connection is opened from the same [`ConnectionBuilder`]. A
+/// pooled `Connection` is only ever used by one thread at a time, so the
+/// default `multi-thread` [threading mode][] is sufficient.
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use squire::{Connection, SquireConnectionManager};
+///
+/// let manager = SquireConnectionManager::new(Connection::builder("./data.sqlite3"));
+/// let pool = r2d2::Pool::new(manager)?;
+///
+/// let connection = pool.get()?;
+/// connection.execute("CREATE TABLE IF NOT EXISTS example (id INTEGER PRIMARY KEY);", ())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [threading mode]: https://sqlite.org/threadsafe.html
+#[cfg_attr(docsrs, doc(cfg(feature = "r2d2")))]
+#[derive(Clone, Debug)]
+pub struct SquireConnectionManager<E: Endpoint> {
+    builder: ConnectionBuilder<E>,
+}
+
+impl<E: Endpoint> SquireConnectionManager<E> {
+    /// Create a manager which opens connections using `builder`.
+    pub const fn new(builder: ConnectionBuilder<E>) -> Self {
+        Self { builder }
+    }
+
+    /// Create a manager which opens connections to `endpoint` with the default
+    /// options.
+    pub fn open<I>(endpoint: I) -> Self
+    where
+        I: IntoEndpoint<Endpoint = E>,
+    {
+        Self::new(Connection::builder(endpoint))
+    }
+}
+
+impl<E> r2d2::ManageConnection for SquireConnectionManager<E>
+where
+    E: Endpoint + Send + Sync + 'static,
+{
+    type Connection = Connection;
+    type Error = Error;
+
+    fn connect(&self) -> Result<Connection, Error> {
+        self.builder.open()
+    }
+
+    fn is_valid(&self, connection: &mut Connection) -> Result<(), Error> {
+        let mut statement = connection.prepare("SELECT 1;")?;
+        let _: i32 = statement.query(())?.one()?;
+        Ok(())
+    }
+
+    /// A connection is broken if a thread panicked while using its
+    /// [statement cache](Connection::prepare_cached).
+    fn has_broken(&self, connection: &mut Connection) -> bool {
+        connection.is_poisoned()
+    }
+}
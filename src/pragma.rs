@@ -0,0 +1,435 @@
+use alloc::format;
+use core::fmt;
+
+use crate::{
+    connection::Connection,
+    error::{Error, ErrorCode, Result},
+    fetch::Fetch,
+    types::Borrowed,
+};
+
+/// Typed helpers for commonly-used [`PRAGMA`][pragma] statements.
+///
+/// [pragma]: https://sqlite.org/pragma.html
+impl Connection {
+    /// Query the [journal mode][] of the main database.
+    ///
+    /// [journal mode]: https://sqlite.org/pragma.html#pragma_journal_mode
+    pub fn journal_mode(&self) -> Result<JournalMode> {
+        self.pragma("journal_mode")
+    }
+
+    /// Change the [journal mode][] of the main database.
+    ///
+    /// SQLite may refuse to change the journal mode; for example, an in-memory
+    /// database can only use [`Memory`](JournalMode::Memory) or
+    /// [`Off`](JournalMode::Off). If the mode SQLite reports afterward is not
+    /// `mode`, `set_journal_mode` returns an error.
+    ///
+    /// [journal mode]: https://sqlite.org/pragma.html#pragma_journal_mode
+    pub fn set_journal_mode(&self, mode: JournalMode) -> Result<JournalMode> {
+        let actual: JournalMode = self.pragma(format_args!("journal_mode = {mode}"))?;
+
+        if actual == mode {
+            Ok(actual)
+        } else {
+            Err(Error::with_detail(
+                ErrorCode::ERROR,
+                format!("journal_mode could not be changed to {mode} (it is {actual})"),
+            ))
+        }
+    }
+
+    /// Check if [foreign key constraints][] are enforced.
+    ///
+    /// [foreign key constraints]: https://sqlite.org/foreignkeys.html
+    pub fn foreign_keys(&self) -> Result<bool> {
+        self.pragma("foreign_keys")
+    }
+
+    /// Enable or disable enforcement of [foreign key constraints][].
+    ///
+    /// SQLite ignores this setting while a transaction is open.
+    ///
+    /// [foreign key constraints]: https://sqlite.org/foreignkeys.html
+    pub fn set_foreign_keys(&self, enabled: bool) -> Result<()> {
+        self.set_pragma(format_args!("foreign_keys = {}", enabled as i32))
+    }
+
+    /// Make the [`LIKE`][like] operator [case-sensitive][pragma] (or
+    /// case-insensitive again) for ASCII characters.
+    ///
+    /// `LIKE` is case-insensitive by default, unless SQLite was compiled with
+    /// [`SQLITE_CASE_SENSITIVE_LIKE`][compile] (see
+    /// [`FeatureKey::CaseSensitiveLike`](crate::FeatureKey::CaseSensitiveLike)).
+    /// The setting only lasts for this connection.
+    ///
+    /// The [`LIKE` optimization][optimization] only uses an index for a
+    /// case-insensitive `LIKE` if the indexed column uses `COLLATE NOCASE`,
+    /// and for a case-sensitive `LIKE` if it uses the default `BINARY`
+    /// collation. Changing this setting can stop existing queries from using
+    /// their indexes.
+    ///
+    /// The `case_sensitive_like` pragma is deprecated, and is missing when
+    /// SQLite is compiled with `SQLITE_OMIT_DEPRECATED`; in that case, this
+    /// always returns an error.
+    ///
+    /// [like]: https://sqlite.org/lang_expr.html#like
+    /// [pragma]: https://sqlite.org/pragma.html#pragma_case_sensitive_like
+    /// [compile]: https://sqlite.org/compile.html#case_sensitive_like
+    /// [optimization]: https://sqlite.org/optoverview.html#the_like_optimization
+    pub fn set_case_sensitive_like(&self, enabled: bool) -> Result<()> {
+        #[cfg(sqlite_has_deprecated)]
+        {
+            self.set_pragma(format_args!("case_sensitive_like = {}", enabled as i32))
+        }
+        #[cfg(not(sqlite_has_deprecated))]
+        {
+            let _ = enabled;
+            Err(Error::with_detail(
+                ErrorCode::ERROR,
+                "PRAGMA case_sensitive_like is omitted from this SQLite build",
+            ))
+        }
+    }
+
+    /// Query the [synchronous][] setting of the main database.
+    ///
+    /// [synchronous]: https://sqlite.org/pragma.html#pragma_synchronous
+    pub fn synchronous(&self) -> Result<Synchronous> {
+        self.pragma("synchronous")
+    }
+
+    /// Change the [synchronous][] setting of the main database.
+    ///
+    /// [synchronous]: https://sqlite.org/pragma.html#pragma_synchronous
+    pub fn set_synchronous(&self, synchronous: Synchronous) -> Result<()> {
+        self.set_pragma(format_args!("synchronous = {}", synchronous.value()))
+    }
+
+    /// Read the [user version][] stored in the database header.
+    ///
+    /// [user version]: https://sqlite.org/pragma.html#pragma_user_version
+    pub fn user_version(&self) -> Result<i32> {
+        self.pragma("user_version")
+    }
+
+    /// Write the [user version][] stored in the database header.
+    ///
+    /// [user version]: https://sqlite.org/pragma.html#pragma_user_version
+    pub fn set_user_version(&self, version: i32) -> Result<()> {
+        self.set_pragma(format_args!("user_version = {version}"))
+    }
+
+    /// Query the [page size][] of the main database, in bytes.
+    ///
+    /// [page size]: https://sqlite.org/pragma.html#pragma_page_size
+    pub fn page_size(&self) -> Result<u32> {
+        self.pragma("page_size")
+    }
+
+    /// Query the suggested size of the main database’s [page cache][].
+    ///
+    /// [page cache]: https://sqlite.org/pragma.html#pragma_cache_size
+    pub fn cache_size(&self) -> Result<CacheSize> {
+        self.pragma("cache_size")
+    }
+
+    /// Change the suggested size of the main database’s [page cache][].
+    ///
+    /// The setting only lasts for this connection; it isn’t stored in the
+    /// database. Use [`ConnectionBuilder::cache_size`] to set it on open.
+    ///
+    /// [page cache]: https://sqlite.org/pragma.html#pragma_cache_size
+    /// [`ConnectionBuilder::cache_size`]: crate::ConnectionBuilder::cache_size
+    pub fn set_cache_size(&self, size: CacheSize) -> Result<()> {
+        self.set_pragma(format_args!("cache_size = {}", size.value()))
+    }
+
+    /// Query the maximum number of bytes of the main database which SQLite
+    /// reads using [memory-mapped I/O][mmap].
+    ///
+    /// [mmap]: https://sqlite.org/mmap.html
+    pub fn mmap_size(&self) -> Result<i64> {
+        self.pragma("mmap_size")
+    }
+
+    /// Change the maximum number of bytes of the main database which SQLite
+    /// reads using [memory-mapped I/O][mmap], returning the size SQLite
+    /// applied. `0` disables memory-mapped I/O.
+    ///
+    /// SQLite silently lowers `bytes` to the [`SQLITE_MAX_MMAP_SIZE`][max]
+    /// it was compiled with (which is `0` on some platforms), so the size
+    /// returned may be smaller than `bytes`. A negative `bytes` is invalid.
+    ///
+    /// [mmap]: https://sqlite.org/mmap.html
+    /// [max]: https://sqlite.org/compile.html#max_mmap_size
+    pub fn set_mmap_size(&self, bytes: i64) -> Result<i64> {
+        if bytes < 0 {
+            return Err(Error::with_detail(
+                ErrorCode::SQUIRE_PARAMETER_RANGE,
+                format!("mmap_size must not be negative, got {bytes}"),
+            ));
+        }
+
+        self.pragma(format_args!("mmap_size = {bytes}"))
+    }
+
+    /// Run a [checkpoint][] of the main database’s write-ahead log, copying
+    /// its contents into the database file.
+    ///
+    /// If the database isn’t in [WAL mode](JournalMode::Wal), this does
+    /// nothing, and the returned [`Checkpoint`] counts `-1` frames.
+    ///
+    /// [checkpoint]: https://sqlite.org/pragma.html#pragma_wal_checkpoint
+    pub fn wal_checkpoint(&self, mode: CheckpointMode) -> Result<Checkpoint> {
+        let mut statement = self.prepare(format!("PRAGMA wal_checkpoint({mode});"))?;
+        let (busy, log_frames, checkpointed_frames) = statement.query(())?.one()?;
+
+        Ok(Checkpoint {
+            busy,
+            log_frames,
+            checkpointed_frames,
+        })
+    }
+
+    fn pragma<T>(&self, pragma: impl fmt::Display) -> Result<T>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        let mut statement = self.prepare(format!("PRAGMA {pragma};"))?;
+        statement.query(())?.one()
+    }
+
+    fn set_pragma(&self, pragma: impl fmt::Display) -> Result<()> {
+        self.execute(format!("PRAGMA {pragma};"), ())?;
+        Ok(())
+    }
+}
+
+/// A database [journal mode][].
+///
+/// [journal mode]: https://sqlite.org/pragma.html#pragma_journal_mode
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub enum JournalMode {
+    /// Delete the rollback journal at the end of each transaction.
+    #[default]
+    Delete,
+    /// Truncate the rollback journal to zero-length at the end of each
+    /// transaction, instead of deleting it.
+    Truncate,
+    /// Overwrite the header of the rollback journal with zeros at the end of
+    /// each transaction, instead of deleting it.
+    Persist,
+    /// Store the rollback journal in memory.
+    Memory,
+    /// Use a [write-ahead log][wal] instead of a rollback journal.
+    ///
+    /// [wal]: https://sqlite.org/wal.html
+    Wal,
+    /// Disable the rollback journal.
+    Off,
+}
+
+impl JournalMode {
+    /// The name of the journal mode, as used in `PRAGMA journal_mode`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Truncate => "truncate",
+            Self::Persist => "persist",
+            Self::Memory => "memory",
+            Self::Wal => "wal",
+            Self::Off => "off",
+        }
+    }
+
+    /// Find the journal mode with the given (case-insensitive) `name`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Delete,
+            Self::Truncate,
+            Self::Persist,
+            Self::Memory,
+            Self::Wal,
+            Self::Off,
+        ]
+        .into_iter()
+        .find(|mode| mode.as_str().eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for JournalMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'r> Fetch<'r> for JournalMode {
+    type Value = Borrowed<'r, str>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Self::from_name(&value).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_PARSE,
+                    format!("unknown journal mode {:?}", &*value),
+                )
+            },
+        )
+    }
+}
+
+/// How a [WAL checkpoint](Connection::wal_checkpoint) waits for other
+/// connections.
+///
+/// See the [`sqlite3_wal_checkpoint_v2`][modes] documentation for details.
+///
+/// [modes]: https://sqlite.org/c3ref/wal_checkpoint_v2.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub enum CheckpointMode {
+    /// Checkpoint as many frames as possible without waiting for readers or
+    /// writers to finish.
+    #[default]
+    Passive,
+    /// Wait for writers to finish, then checkpoint every frame.
+    Full,
+    /// Like [`Full`](Self::Full), then also wait for readers to finish, so
+    /// that the next writer restarts the log from the beginning.
+    Restart,
+    /// Like [`Restart`](Self::Restart), then also truncate the log file to
+    /// zero bytes.
+    Truncate,
+}
+
+impl CheckpointMode {
+    /// The name of the mode, as used in `PRAGMA wal_checkpoint`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Passive => "PASSIVE",
+            Self::Full => "FULL",
+            Self::Restart => "RESTART",
+            Self::Truncate => "TRUNCATE",
+        }
+    }
+}
+
+impl fmt::Display for CheckpointMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The outcome of a [WAL checkpoint](Connection::wal_checkpoint).
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Checkpoint {
+    /// `true` if a [`Full`](CheckpointMode::Full) or stronger checkpoint
+    /// couldn’t finish, because another connection was using the database.
+    pub busy: bool,
+    /// The number of frames in the write-ahead log, or `-1` if the database
+    /// isn’t in WAL mode.
+    pub log_frames: i64,
+    /// The number of frames in the log which have been checkpointed, or `-1`
+    /// if the database isn’t in WAL mode.
+    pub checkpointed_frames: i64,
+}
+
+/// A database [synchronous][] setting.
+///
+/// [synchronous]: https://sqlite.org/pragma.html#pragma_synchronous
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+#[repr(i32)]
+pub enum Synchronous {
+    /// Hand off data to the operating system without syncing.
+    Off = 0,
+    /// Sync at the most critical moments, but less often than [`Full`](Self::Full).
+    Normal = 1,
+    /// Sync to ensure all content is safely written to disk.
+    #[default]
+    Full = 2,
+    /// Like [`Full`](Self::Full), but also sync the directory containing the
+    /// rollback journal after it is unlinked.
+    Extra = 3,
+}
+
+impl Synchronous {
+    /// The value of `PRAGMA synchronous` for this setting, from `0` for
+    /// [`Off`](Self::Off) to `3` for [`Extra`](Self::Extra).
+    pub const fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Interpret a `PRAGMA synchronous` value, or return `None` if it isn't
+    /// one SQLite defines.
+    pub const fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Off),
+            1 => Some(Self::Normal),
+            2 => Some(Self::Full),
+            3 => Some(Self::Extra),
+            _ => None,
+        }
+    }
+}
+
+impl<'r> Fetch<'r> for Synchronous {
+    type Value = i32;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Self::from_value(value).ok_or_else(
+            #[cold]
+            || Error::new(ErrorCode::SQUIRE_FETCH_RANGE),
+        )
+    }
+}
+
+/// The suggested size of a database’s [page cache][], set by
+/// [`Connection::set_cache_size`].
+///
+/// SQLite’s `PRAGMA cache_size` takes a number of pages if positive, or a
+/// number of KiB if negative; `CacheSize` keeps the two apart.
+///
+/// [page cache]: https://sqlite.org/pragma.html#pragma_cache_size
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum CacheSize {
+    /// A number of database pages, each the [page size](Connection::page_size)
+    /// of the database.
+    Pages(u32),
+    /// An amount of memory, in KiB (units of 1024 bytes).
+    Kibibytes(u32),
+}
+
+impl CacheSize {
+    /// The value of `PRAGMA cache_size` for this size: positive for
+    /// [`Pages`](Self::Pages), and negative for [`Kibibytes`](Self::Kibibytes).
+    pub const fn value(&self) -> i64 {
+        match *self {
+            Self::Pages(pages) => pages as i64,
+            Self::Kibibytes(kib) => -(kib as i64),
+        }
+    }
+
+    /// Interpret a `PRAGMA cache_size` value.
+    pub const fn from_value(value: i64) -> Option<Self> {
+        let size = value.unsigned_abs();
+        if size > u32::MAX as u64 {
+            None
+        } else if value >= 0 {
+            Some(Self::Pages(size as u32))
+        } else {
+            Some(Self::Kibibytes(size as u32))
+        }
+    }
+}
+
+impl<'r> Fetch<'r> for CacheSize {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        Self::from_value(value).ok_or_else(
+            #[cold]
+            || Error::new(ErrorCode::SQUIRE_FETCH_RANGE),
+        )
+    }
+}
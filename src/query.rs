@@ -1,4 +1,5 @@
 use crate::{
+    column::Columns,
     connection::Connection,
     error::Result,
     param::Parameters,
@@ -17,3 +18,91 @@ pub trait Query<'s>: Parameters<'s> {
     /// Fetch the desired [`Query`] output from the [`Statement`].
     fn output<'c: 's>(execution: Execution<'c, 's>) -> Result<Self::Output>;
 }
+
+/// A query built by [`query!`](crate::query!), whose parameter count was
+/// checked at compile time.
+///
+/// Each method [prepares](Connection::prepare) the SQL, binds the parameters,
+/// and runs the query.
+///
+#[cfg_attr(feature = "derive", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// # fn main() -> squire::Result<()> {
+/// let connection = squire::Connection::open(squire::Memory)?;
+/// // Two parameters, but only one argument.
+/// let sum: i64 = squire::query!(connection, "SELECT ? + ?", 1).one()?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "a CheckedQuery does nothing until it is run"]
+pub struct CheckedQuery<'c, P> {
+    connection: &'c Connection,
+    sql: &'static str,
+    parameters: P,
+}
+
+impl<'c, P> CheckedQuery<'c, P>
+where
+    P: for<'s> Parameters<'s>,
+{
+    #[doc(hidden)]
+    pub fn new(connection: &'c Connection, sql: &'static str, parameters: P) -> Self {
+        Self {
+            connection,
+            sql,
+            parameters,
+        }
+    }
+
+    /// The query's SQL.
+    pub fn sql(&self) -> &'static str {
+        self.sql
+    }
+
+    /// Run the query, returning the number of affected rows.
+    ///
+    /// See [`Execution::run`].
+    pub fn execute(self) -> Result<isize> {
+        self.connection.execute(self.sql, self.parameters)
+    }
+
+    /// Fetch the single row returned by the query.
+    ///
+    /// See [`Execution::one`].
+    pub fn one<C>(self) -> Result<C>
+    where
+        C: for<'r> Columns<'r>,
+    {
+        self.connection
+            .prepare(self.sql)?
+            .query(self.parameters)?
+            .one()
+    }
+
+    /// Fetch the single row returned by the query, if any.
+    ///
+    /// See [`Execution::optional`].
+    pub fn optional<C>(self) -> Result<Option<C>>
+    where
+        C: for<'r> Columns<'r>,
+    {
+        self.connection
+            .prepare(self.sql)?
+            .query(self.parameters)?
+            .optional()
+    }
+
+    /// Fetch every row returned by the query.
+    ///
+    /// See [`Execution::all`].
+    pub fn all<T, C>(self) -> Result<T>
+    where
+        T: FromIterator<C>,
+        C: for<'r> Columns<'r> + 'static,
+    {
+        self.connection
+            .prepare(self.sql)?
+            .query(self.parameters)?
+            .all()
+    }
+}
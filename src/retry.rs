@@ -0,0 +1,112 @@
+use core::{
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use std::{collections::hash_map::RandomState, thread};
+
+use crate::{
+    connection::Connection,
+    error::{ErrorCategory, Result},
+};
+
+/// How [`Connection::with_retry`] retries an operation which failed because
+/// the database was [busy](ErrorCategory::Busy) or [locked](ErrorCategory::Locked).
+///
+/// The delay before each retry doubles, starting from `backoff`. With `jitter`
+/// enabled, each delay is randomly shortened by up to half, so that competing
+/// connections don’t retry in lockstep.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use squire::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_attempts: 10,
+///     backoff: Duration::from_millis(5),
+///     ..RetryPolicy::default()
+/// };
+/// # let _ = policy;
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times to run the operation, including the first
+    /// attempt. (`0` is treated as `1`.)
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub backoff: Duration,
+    /// Randomize each delay, to spread out retries from competing connections.
+    pub jitter: bool,
+    /// The function called to wait between attempts;
+    /// [`std::thread::sleep`] by default.
+    pub sleep: fn(Duration),
+}
+
+impl RetryPolicy {
+    /// Create a [`RetryPolicy`] which makes up to `max_attempts` attempts,
+    /// with jitter enabled.
+    pub const fn new(max_attempts: u32, backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            backoff,
+            jitter: true,
+            sleep: thread::sleep,
+        }
+    }
+
+    /// The delay before retrying after `attempt` attempts have failed.
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .backoff
+            .saturating_mul(1u32.checked_shl(attempt - 1).unwrap_or(u32::MAX));
+
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            let fraction = 0.5 + (random as f64 / u64::MAX as f64) / 2.0;
+            delay.mul_f64(fraction)
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Make up to 5 attempts, starting with a 10ms delay.
+    fn default() -> Self {
+        Self::new(5, Duration::from_millis(10))
+    }
+}
+
+impl Connection {
+    /// Run `f`, retrying it according to `policy` while it fails because the
+    /// database is [busy](ErrorCategory::Busy) or [locked](ErrorCategory::Locked).
+    ///
+    /// Any other error is returned immediately. If every attempt fails, the
+    /// last error is returned.
+    ///
+    /// `f` may be run more than once, so it must be idempotent: an attempt
+    /// which fails partway through must not leave behind changes that a
+    /// retry would repeat. Running `f` within a single transaction (or a
+    /// single statement) is the simplest way to ensure this.
+    pub fn with_retry<T, F>(&self, policy: RetryPolicy, mut f: F) -> Result<T>
+    where
+        F: FnMut(&Connection) -> Result<T>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            match f(self) {
+                Err(err)
+                    if attempt < policy.max_attempts
+                        && matches!(
+                            err.category(),
+                            Some(ErrorCategory::Busy | ErrorCategory::Locked)
+                        ) =>
+                {
+                    (policy.sleep)(policy.delay(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use core::ops::ControlFlow;
+
 use crate::{
     column::{ColumnIndexes, Columns},
     error::{Error, ErrorCode, Result},
-    fetch::Fetch,
-    iter,
+    fetch::{Fetch, check_fetched},
+    ffi, iter,
     statement::{Binding, Execute, Execution, Statement},
-    types::ColumnIndex,
+    types::{ColumnIndex, Type, Value},
 };
 
 /// Access the [`Columns`] of each row returned by a [query](Execution).
@@ -31,6 +34,12 @@ where
             Err(Error::new(ErrorCode::SQUIRE_PARAMETER_RESOLVE))
         }
     }
+
+    /// Step to the next row without fetching it, returning `true` if a row
+    /// was available.
+    pub(crate) fn step(&mut self) -> Result<bool> {
+        unsafe { self.execution.cursor().internal_ref().row() }
+    }
 }
 
 #[allow(clippy::should_implement_trait)]
@@ -72,6 +81,46 @@ where
         iter::FilterMap { rows: self, f }
     }
 
+    /// Call a fallible closure with each row in the result set, stopping early
+    /// if it returns an error or [`ControlFlow::Break`].
+    ///
+    /// Unlike [`into_iter`](IntoIterator::into_iter), this works with
+    /// [`Columns`] which borrow data from the SQLite row, like
+    /// [`Borrowed<str>`](crate::Borrowed), without copying it. Each row is only
+    /// valid during the closure call it's passed to: the closure must not keep
+    /// any data borrowed from it, which the next step overwrites.
+    pub fn try_for_each<F>(&mut self, mut f: F) -> Result<()>
+    where
+        F: FnMut(C) -> Result<ControlFlow<()>>,
+    {
+        // SAFETY: We never hold onto row data across loop iterations
+        while let Some(row) = unsafe { self.advance() }? {
+            if f(row)?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fold every row in the result set into an accumulator, stopping at the
+    /// first error from fetching a row or from the closure.
+    ///
+    /// Like [`try_for_each`](Self::try_for_each), rows may borrow data from
+    /// SQLite, which must not be kept in the accumulator.
+    pub fn fold<B, F>(&mut self, init: B, mut f: F) -> Result<B>
+    where
+        F: FnMut(B, C) -> Result<B>,
+    {
+        let mut acc = init;
+        // SAFETY: We never hold onto row data across loop iterations
+        while let Some(row) = unsafe { self.advance() }? {
+            acc = f(acc, row)?;
+        }
+
+        Ok(acc)
+    }
+
     /// # Safety
     ///
     /// This function must not be called while any data borrowed from a previous
@@ -109,6 +158,24 @@ where
     }
 }
 
+/// Iterate over rows as a [`FallibleIterator`](fallible_iterator::FallibleIterator),
+/// for [`Columns`] which don't borrow from the row.
+#[cfg(feature = "fallible-iterator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fallible-iterator")))]
+impl<'c, 's, C, S> fallible_iterator::FallibleIterator for Rows<'c, 's, C, S>
+where
+    C: for<'r> Columns<'r> + 'static,
+    S: Execute<'c, 's>,
+    'c: 's,
+{
+    type Item = C;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<C>> {
+        Rows::next(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct RowsIterator<'c, 's, C: ColumnIndexes, S = Binding<'c, 's>>
 where
@@ -163,6 +230,110 @@ where
         T::fetch_column(statement, column)
     }
 
+    /// Fetch a single column from the [`Row`] by its [index](ColumnIndex),
+    /// borrowing the row.
+    pub fn get<'a, T: Fetch<'a>>(&'a self, column: ColumnIndex) -> Result<T> {
+        let statement = self.execution.cursor();
+        T::fetch_column(statement, column)
+    }
+
+    /// Fetch a single column from the [`Row`] by its name.
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare("SELECT 1 AS id, 'alice' AS name;")?;
+    /// let mut execution = query.query(())?;
+    ///
+    /// let row = execution.row()?.expect("a row");
+    /// let name: &str = row.get_by_name("name")?;
+    /// assert_eq!("alice", name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If several columns share the name, the first is fetched. Returns an
+    /// [unknown column](crate::FetchError::UnknownColumn) error if no column
+    /// has the name.
+    pub fn get_by_name<'a, T: Fetch<'a>>(&'a self, name: &str) -> Result<T> {
+        let statement = self.execution.cursor();
+        let column = statement.columns().index(name).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_UNKNOWN_COLUMN,
+                    format!("query has no column named {name:?}"),
+                )
+            },
+        )?;
+
+        T::fetch_column(statement, column)
+    }
+
+    /// Read a column from this [`Row`] as text, checking that it's valid UTF-8.
+    ///
+    /// Returns a [parse error](crate::FetchError::Parse) if the column's text
+    /// is not valid UTF-8 (e.g., if invalid bytes were `CAST` to `TEXT`).
+    #[doc(alias = "sqlite3_column_text")]
+    pub fn text(&self, column: ColumnIndex) -> Result<&str> {
+        let statement = self.execution.cursor();
+        let bytes = unsafe { ffi::fetch_column_text(statement.internal_ref(), column) };
+        check_fetched(statement.internal_ref(), column, bytes.len())?;
+
+        str::from_utf8(bytes.into_inner()).map_err(
+            #[cold]
+            |err| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_PARSE,
+                    format!("invalid UTF-8 in column {}: {err}", column.value()),
+                )
+            },
+        )
+    }
+
+    /// Read a column from this [`Row`] as text, replacing any invalid UTF-8
+    /// with [`U+FFFD`](char::REPLACEMENT_CHARACTER).
+    #[doc(alias = "sqlite3_column_text")]
+    pub fn text_lossy(&self, column: ColumnIndex) -> Cow<'_, str> {
+        let statement = self.execution.cursor();
+        let bytes = unsafe { ffi::fetch_column_text(statement.internal_ref(), column) };
+
+        String::from_utf8_lossy(bytes.into_inner())
+    }
+
+    /// Check the [storage class](Type) of a column in this [`Row`].
+    ///
+    /// The type reflects the value as stored, before any conversion performed
+    /// by [fetching](Self::fetch) the column. Out-of-range columns report
+    /// [`Type::Null`].
+    #[doc(alias = "sqlite3_column_type")]
+    pub fn column_type(&self, column: ColumnIndex) -> Type {
+        let statement = self.execution.cursor();
+        unsafe { Type::fetch_column(statement.internal_ref(), column) }
+    }
+
+    /// Measure the size in bytes of a column's `TEXT` or `BLOB` value in this
+    /// [`Row`], without fetching it.
+    ///
+    /// Check this before fetching a potentially enormous value into memory.
+    /// `INTEGER` and `REAL` columns are measured as text; `NULL` and
+    /// out-of-range columns have a size of `0`.
+    #[doc(alias = "sqlite3_column_bytes")]
+    pub fn column_bytes(&self, column: ColumnIndex) -> usize {
+        let statement = self.execution.cursor();
+        statement.internal_ref().column_bytes(column)
+    }
+
+    /// Fetch every column in this [`Row`] as a dynamically-typed [`Value`].
+    ///
+    /// Returns a [parse error](crate::FetchError::Parse) if a `TEXT` column
+    /// isn't valid UTF-8.
+    pub fn to_values(&self) -> Result<Vec<Value>> {
+        let statement = self.execution.cursor();
+        <Vec<Value> as Columns<'_>>::fetch(statement, ())
+    }
+
     /// Unpack a full set of [`Columns`] from this [`Row`].
     pub fn unpack<'a, T: Columns<'r>>(&'a mut self, indexes: T::Indexes) -> Result<T>
     where
@@ -171,4 +342,159 @@ where
         let statement = self.execution.cursor();
         T::fetch(statement, indexes)
     }
+
+    /// Deserialize this [`Row`] into any type implementing Serde’s
+    /// [`Deserialize`](squire_serde::Deserialize).
+    ///
+    /// Each struct field is read from the column of the same name; columns
+    /// with no matching field are ignored. `NULL` values deserialize as
+    /// [`None`] (or unit), and unit enum variants are read from TEXT columns
+    /// by name. A column whose value doesn't match its field’s type fails with
+    /// a [parse error](crate::FetchError::Parse).
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn deserialize<'a, T>(&'a mut self) -> Result<T>
+    where
+        T: squire_serde::Deserialize<'r>,
+        'a: 'r,
+    {
+        let statement = self.execution.cursor();
+        T::deserialize(crate::serde::RowDeserializer::new(statement))
+    }
+
+    /// Deserialize the value at a [JSON pointer][] (e.g., `/user/name`) in
+    /// the JSON TEXT of `column`.
+    ///
+    /// The document is parsed as a stream: values not on the path to
+    /// `pointer` are skipped over without being decoded, so this is cheaper
+    /// than fetching the whole column as [`Json`](crate::Json) when only one
+    /// field is needed. Pointer tokens which index into arrays are parsed as
+    /// `usize`.
+    ///
+    /// ```rust
+    /// # use squire::{ColumnIndex, Connection};
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare(
+    ///     r#"SELECT '{"user": {"name": "alice", "tags": ["a", "b"]}}';"#,
+    /// )?;
+    /// let mut execution = query.query(())?;
+    /// let row = execution.row()?.expect("a row");
+    ///
+    /// let name: &str = row.json_path(ColumnIndex::INITIAL, "/user/name")?;
+    /// let tag: String = row.json_path(ColumnIndex::INITIAL, "/user/tags/1")?;
+    /// let age: Option<u32> = row.json_path(ColumnIndex::INITIAL, "/user/age")?;
+    /// assert_eq!(("alice", "b", None), (name, tag.as_str(), age));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If nothing is found at `pointer`, or the column is `NULL`, an [`Option`]
+    /// is fetched as `None`, and other types fail with a
+    /// [parse error](crate::FetchError::Parse). Invalid JSON fails with an
+    /// [`IntegrationError::Json`](crate::IntegrationError::Json).
+    ///
+    /// With the `jsonb` feature, a `BLOB` column is read as [JSONB][jsonb].
+    /// The whole document is decoded to find the value, so it can't be
+    /// borrowed (e.g., as a `&str`). Without the feature, convert JSONB in
+    /// the query using the [`json()`](https://sqlite.org/json1.html#jmini) SQL
+    /// function.
+    ///
+    /// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    /// [jsonb]: https://sqlite.org/jsonb.html
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+    pub fn json_path<'a, T>(&'a self, column: ColumnIndex, pointer: &str) -> Result<T>
+    where
+        T: squire_serde::Deserialize<'a>,
+    {
+        match self.column_type(column) {
+            Type::Null => crate::serde::extract_json_pointer(None, pointer),
+            #[cfg(feature = "jsonb")]
+            Type::Blob => {
+                let blob: &[u8] = self.get(column)?;
+                crate::serde::extract_jsonb_pointer(blob, pointer)
+            }
+            _ => crate::serde::extract_json_pointer(Some(self.text(column)?), pointer),
+        }
+    }
+}
+
+/// A borrowed view of the current row of an [`Execution`], passed to the
+/// closure given to [`Execution::try_for_each`] or [`Execution::fold`].
+///
+/// Values [borrowed](crate::Borrowed) from a `RowRef` can't outlive it, and a
+/// `RowRef` can't outlive the closure call it was passed to, so the borrow
+/// checker rejects any attempt to hold onto row data after the query steps to
+/// the next row.
+///
+/// ```compile_fail
+/// # use squire::{Borrowed, Connection};
+/// # use core::ops::ControlFlow;
+/// # fn main() -> squire::Result<()> {
+/// # let connection = Connection::open(c":memory:")?;
+/// let mut query = connection.prepare("SELECT 'a' UNION ALL SELECT 'b';")?;
+///
+/// let mut seen: Vec<Borrowed<'_, str>> = Vec::new();
+/// query.query(())?.try_for_each(|row| {
+///     seen.push(row.get(squire::ColumnIndex::INITIAL)?);
+///     Ok(ControlFlow::Continue(()))
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RowRef<'r> {
+    statement: &'r Statement<'r>,
+}
+
+impl<'r> RowRef<'r> {
+    #[inline]
+    pub(crate) const fn new(statement: &'r Statement<'r>) -> Self {
+        Self { statement }
+    }
+
+    /// Fetch a single column from the row by its [index](ColumnIndex).
+    pub fn get<T: Fetch<'r>>(&'r self, column: ColumnIndex) -> Result<T> {
+        T::fetch_column(self.statement, column)
+    }
+
+    /// Fetch a single column from the row by its name; see
+    /// [`Row::get_by_name`].
+    pub fn get_by_name<T: Fetch<'r>>(&'r self, name: &str) -> Result<T> {
+        let column = self.statement.columns().index(name).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_UNKNOWN_COLUMN,
+                    format!("query has no column named {name:?}"),
+                )
+            },
+        )?;
+
+        T::fetch_column(self.statement, column)
+    }
+
+    /// Fetch a full set of [`Columns`] from the row.
+    pub fn columns<C: Columns<'r>>(&'r self) -> Result<C> {
+        let indexes = C::resolve(self.statement).ok_or_else(
+            #[cold]
+            || Error::new(ErrorCode::SQUIRE_PARAMETER_RESOLVE),
+        )?;
+
+        C::fetch(self.statement, indexes)
+    }
+
+    /// Check the [storage class](Type) of a column in the row.
+    #[doc(alias = "sqlite3_column_type")]
+    pub fn column_type(&self, column: ColumnIndex) -> Type {
+        unsafe { Type::fetch_column(self.statement.internal_ref(), column) }
+    }
+
+    /// Measure the size in bytes of a column's `TEXT` or `BLOB` value in the
+    /// row; see [`Row::column_bytes`].
+    #[doc(alias = "sqlite3_column_bytes")]
+    pub fn column_bytes(&self, column: ColumnIndex) -> usize {
+        self.statement.internal_ref().column_bytes(column)
+    }
 }
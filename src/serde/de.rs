@@ -0,0 +1,248 @@
+use core::fmt::Display;
+use std::vec;
+
+use squire_serde::de::{
+    self, DeserializeSeed, IntoDeserializer, MapAccess, Visitor, value::BorrowedStrDeserializer,
+};
+
+use crate::{
+    error::{Error, ErrorCategory, ErrorCode, Result},
+    fetch::Fetch,
+    statement::Statement,
+    types::{Borrowed, ColumnIndex, Type},
+};
+
+/// Deserialize a row as a map from column names to values.
+///
+/// Structs are deserialized by looking up each field by name; columns which
+/// don't correspond to a field are ignored, and fields without a column are
+/// reported to the struct as missing.
+pub(crate) struct RowDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    statement: &'r Statement<'c>,
+}
+
+impl<'r, 'c> RowDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    pub(crate) const fn new(statement: &'r Statement<'c>) -> Self {
+        Self { statement }
+    }
+}
+
+impl<'r, 'c> de::Deserializer<'r> for RowDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        let statement = self.statement.internal_ref();
+        let columns = self
+            .statement
+            .columns()
+            .iter()
+            .filter_map(|column| {
+                let name = statement.column_name(column)?;
+                Some((str::from_utf8(name.to_bytes()).ok()?, column))
+            })
+            .collect();
+
+        visitor.visit_map(ColumnAccess::new(self.statement, columns))
+    }
+
+    fn deserialize_struct<V: Visitor<'r>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let names = self.statement.columns();
+        let columns = fields
+            .iter()
+            .filter_map(|&field| Some((field, names.index(field)?)))
+            .collect();
+
+        visitor.visit_map(ColumnAccess::new(self.statement, columns))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'r>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    squire_serde::forward_to_deserialize_any! {
+        <W: Visitor<'r>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple tuple_struct enum
+        identifier ignored_any
+    }
+}
+
+/// Visit the columns of a row as map entries.
+struct ColumnAccess<'r, 'c>
+where
+    'c: 'r,
+{
+    statement: &'r Statement<'c>,
+    columns: vec::IntoIter<(&'r str, ColumnIndex)>,
+    column: Option<ColumnIndex>,
+}
+
+impl<'r, 'c> ColumnAccess<'r, 'c>
+where
+    'c: 'r,
+{
+    fn new(statement: &'r Statement<'c>, columns: Vec<(&'r str, ColumnIndex)>) -> Self {
+        Self {
+            statement,
+            columns: columns.into_iter(),
+            column: None,
+        }
+    }
+}
+
+impl<'r, 'c> MapAccess<'r> for ColumnAccess<'r, 'c>
+where
+    'c: 'r,
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'r>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((name, column)) = self.columns.next() else {
+            return Ok(None);
+        };
+
+        self.column = Some(column);
+        seed.deserialize(BorrowedStrDeserializer::new(name))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'r>>(&mut self, seed: V) -> Result<V::Value> {
+        let column = self.column.take().ok_or_else(
+            #[cold]
+            || Error::from(ErrorCategory::Misuse),
+        )?;
+
+        seed.deserialize(ColumnDeserializer {
+            statement: self.statement,
+            column,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len())
+    }
+}
+
+/// Deserialize a single column value, according to its [storage class](Type).
+struct ColumnDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    statement: &'r Statement<'c>,
+    column: ColumnIndex,
+}
+
+impl<'r, 'c> ColumnDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    fn column_type(&self) -> Type {
+        unsafe { Type::fetch_column(self.statement.internal_ref(), self.column) }
+    }
+
+    fn fetch<T: Fetch<'r>>(&self) -> Result<T> {
+        T::fetch_column(self.statement, self.column)
+    }
+}
+
+impl<'r, 'c> de::Deserializer<'r> for ColumnDeserializer<'r, 'c>
+where
+    'c: 'r,
+{
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        match self.column_type() {
+            Type::Integer => visitor.visit_i64(self.fetch()?),
+            Type::Float => visitor.visit_f64(self.fetch()?),
+            Type::Text => {
+                visitor.visit_borrowed_str(self.fetch::<Borrowed<'r, str>>()?.into_inner())
+            }
+            Type::Blob => {
+                visitor.visit_borrowed_bytes(self.fetch::<Borrowed<'r, [u8]>>()?.into_inner())
+            }
+            Type::Null => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        match self.column_type() {
+            Type::Integer => visitor.visit_bool(self.fetch()?),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        match self.column_type() {
+            Type::Text => visitor.visit_borrowed_bytes(self.fetch::<&'r [u8]>()?),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'r>>(self, visitor: V) -> Result<V::Value> {
+        match self.column_type() {
+            Type::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'r>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit enum variants are read from TEXT columns by name.
+    fn deserialize_enum<V: Visitor<'r>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.column_type() {
+            Type::Text => visitor.visit_enum(self.fetch::<&'r str>()?.into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    squire_serde::forward_to_deserialize_any! {
+        <W: Visitor<'r>>
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error::with_detail(ErrorCode::SQUIRE_FETCH_PARSE, message.to_string())
+    }
+}
@@ -0,0 +1,12 @@
+//! Bridges between [Serde](squire_serde) and Squire’s parameters and columns.
+
+mod de;
+#[cfg(feature = "json")]
+mod pointer;
+mod ser;
+
+pub(crate) use de::RowDeserializer;
+#[cfg(feature = "json")]
+pub(crate) use pointer::extract as extract_json_pointer;
+#[cfg(feature = "jsonb")]
+pub(crate) use pointer::extract_jsonb as extract_jsonb_pointer;
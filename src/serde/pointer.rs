@@ -0,0 +1,221 @@
+use core::{fmt, marker::PhantomData};
+use std::borrow::Cow;
+
+#[cfg(feature = "jsonb")]
+use squire_serde::jsonb;
+use squire_serde::{
+    Deserialize,
+    de::{
+        self, DeserializeSeed, Deserializer, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess,
+        Visitor,
+    },
+    json,
+};
+
+use crate::error::{Error, ErrorCode, Result};
+
+/// Deserialize the value at a [JSON pointer][] in `text`, without building
+/// the rest of the document.
+///
+/// If nothing is found at `pointer` (or `text` is `None`, for a `NULL`
+/// column), `T` is deserialized from unit, so an [`Option`] becomes `None`.
+///
+/// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+pub(crate) fn extract<'de, T>(text: Option<&'de str>, pointer: &str) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    let tokens = parse(pointer)?;
+
+    let found = match text {
+        Some(text) => {
+            let mut deserializer = json::Deserializer::from_str(text);
+            PointerSeed::new(&tokens)
+                .deserialize(&mut deserializer)
+                .and_then(|found| deserializer.end().map(|()| found))
+                .map_err(Error::from_fetch)?
+        }
+        None => None,
+    };
+
+    match found {
+        Some(value) => Ok(value),
+        None => missing(pointer),
+    }
+}
+
+/// Deserialize the value at a [JSON pointer][] in a [JSONB][jsonb] `blob`.
+///
+/// Unlike [`extract`], the whole document is decoded first, so `T` can't
+/// borrow from it.
+///
+/// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+/// [jsonb]: https://sqlite.org/jsonb.html
+#[cfg(feature = "jsonb")]
+pub(crate) fn extract_jsonb<'de, T>(blob: &[u8], pointer: &str) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    parse(pointer)?;
+
+    let mut document: json::Value = jsonb::from_slice(blob).map_err(Error::from_fetch)?;
+    match document.pointer_mut(pointer) {
+        Some(value) => T::deserialize(value.take()).map_err(Error::from_fetch),
+        None => missing(pointer),
+    }
+}
+
+/// Deserialize `T` from unit when nothing is found at `pointer`, so that an
+/// [`Option`] becomes `None`.
+fn missing<'de, T: Deserialize<'de>>(pointer: &str) -> Result<T> {
+    T::deserialize(().into_deserializer()).map_err(
+        #[cold]
+        |_: de::value::Error| {
+            Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_PARSE,
+                format!("no JSON value at {pointer:?}"),
+            )
+        },
+    )
+}
+
+/// Split a JSON pointer into its unescaped reference tokens.
+fn parse(pointer: &str) -> Result<Vec<Cow<'_, str>>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let Some(pointer) = pointer.strip_prefix('/') else {
+        return Err(Error::with_detail(
+            ErrorCode::MISUSE,
+            format!("JSON pointer {pointer:?} must be empty or start with '/'"),
+        ));
+    };
+
+    Ok(pointer
+        .split('/')
+        .map(|token| {
+            if token.contains('~') {
+                Cow::Owned(token.replace("~1", "/").replace("~0", "~"))
+            } else {
+                Cow::Borrowed(token)
+            }
+        })
+        .collect())
+}
+
+/// Deserializes `T` at the end of a path of `tokens`, skipping over every
+/// other value along the way with [`IgnoredAny`].
+struct PointerSeed<'p, T> {
+    tokens: &'p [Cow<'p, str>],
+    target: PhantomData<T>,
+}
+
+impl<'p, T> PointerSeed<'p, T> {
+    const fn new(tokens: &'p [Cow<'p, str>]) -> Self {
+        Self {
+            tokens,
+            target: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for PointerSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        if self.tokens.is_empty() {
+            T::deserialize(deserializer).map(Some)
+        } else {
+            deserializer.deserialize_any(self)
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for PointerSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (token, rest) = (&self.tokens[0], &self.tokens[1..]);
+        let mut found = None;
+
+        while let Some(matched) = map.next_key_seed(KeySeed(token))? {
+            if matched && found.is_none() {
+                found = map.next_value_seed(PointerSeed::new(rest))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        Ok(found)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let (token, rest) = (&self.tokens[0], &self.tokens[1..]);
+        let mut found = None;
+
+        if let Ok(index) = token.parse::<usize>() {
+            for _ in 0..index {
+                if seq.next_element::<IgnoredAny>()?.is_none() {
+                    return Ok(None);
+                }
+            }
+            found = seq.next_element_seed(PointerSeed::new(rest))?.flatten();
+        }
+
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+        Ok(found)
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
+
+/// Compares an object key to a pointer token, without allocating the key.
+struct KeySeed<'p>(&'p str);
+
+impl<'de> DeserializeSeed<'de> for KeySeed<'_> {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeySeed<'_> {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON object key")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E> {
+        Ok(key == self.0)
+    }
+}
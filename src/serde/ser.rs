@@ -0,0 +1,417 @@
+use core::fmt::Display;
+
+use squire_serde::ser::{self, Impossible, Serialize};
+
+use crate::{
+    error::{Error, ErrorCode, Result},
+    statement::{Binding, Execute},
+    types::BindIndex,
+};
+
+impl<'c, 's> Binding<'c, 's>
+where
+    'c: 's,
+{
+    /// Bind the fields of a [`Serialize`] struct to the statement’s named
+    /// parameters.
+    ///
+    /// Each field is bound to the parameter with the same name (ignoring its
+    /// `:`, `@`, or `$` sigil), so `#[serde(rename)]` can be used to match a
+    /// differently-named parameter. Fields may be integers, floats, booleans,
+    /// strings, bytes, unit enum variants (bound as their name as TEXT), or
+    /// an [`Option`] of any of these.
+    ///
+    /// A field which doesn't name any statement parameter fails with a
+    /// [resolve error](crate::ParameterError::Resolve). Statement parameters
+    /// with no corresponding field (including fields skipped by
+    /// `#[serde(skip_serializing_if)]`) are bound to `NULL`. Serializing
+    /// anything other than a struct fails with a
+    /// [bind error](crate::ParameterError::Bind).
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(ParameterSerializer { binding: self })
+    }
+}
+
+/// Serialize parameter values into a [`Binding`].
+struct ParameterSerializer<'b, 'c, 's>
+where
+    'c: 's,
+{
+    binding: &'b mut Binding<'c, 's>,
+}
+
+macro_rules! unsupported {
+    ($($method:ident($($arg:ty),*) -> $ret:ty: $kind:literal;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ret> {
+                Err(unsupported($kind))
+            }
+        )*
+    };
+}
+
+impl<'b, 'c, 's> ser::Serializer for ParameterSerializer<'b, 'c, 's>
+where
+    'c: 's,
+{
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = StructSerializer<'b, 'c, 's>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        let count = self.binding.cursor().parameters().len();
+
+        Ok(StructSerializer {
+            binding: self.binding,
+            unbound: vec![true; count],
+        })
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported("an option"))
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported("an enum"))
+    }
+
+    unsupported! {
+        serialize_bool(bool) -> (): "a bool";
+        serialize_i8(i8) -> (): "an integer";
+        serialize_i16(i16) -> (): "an integer";
+        serialize_i32(i32) -> (): "an integer";
+        serialize_i64(i64) -> (): "an integer";
+        serialize_i128(i128) -> (): "an integer";
+        serialize_u8(u8) -> (): "an integer";
+        serialize_u16(u16) -> (): "an integer";
+        serialize_u32(u32) -> (): "an integer";
+        serialize_u64(u64) -> (): "an integer";
+        serialize_u128(u128) -> (): "an integer";
+        serialize_f32(f32) -> (): "a float";
+        serialize_f64(f64) -> (): "a float";
+        serialize_char(char) -> (): "a char";
+        serialize_str(&str) -> (): "a string";
+        serialize_bytes(&[u8]) -> (): "bytes";
+        serialize_none() -> (): "an option";
+        serialize_unit() -> (): "unit";
+        serialize_unit_struct(&'static str) -> (): "a unit struct";
+        serialize_unit_variant(&'static str, u32, &'static str) -> (): "an enum";
+        serialize_seq(Option<usize>) -> Self::SerializeSeq: "a sequence";
+        serialize_tuple(usize) -> Self::SerializeTuple: "a tuple";
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct: "a tuple struct";
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant: "an enum";
+        serialize_map(Option<usize>) -> Self::SerializeMap: "a map";
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant: "an enum";
+    }
+}
+
+/// Bind each field of a struct to the parameter of the same name.
+struct StructSerializer<'b, 'c, 's>
+where
+    'c: 's,
+{
+    binding: &'b mut Binding<'c, 's>,
+    unbound: Vec<bool>,
+}
+
+impl<'c, 's> ser::SerializeStruct for StructSerializer<'_, 'c, 's>
+where
+    'c: 's,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let index = self.binding.cursor().parameters().index(key).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RESOLVE,
+                    format!("statement has no parameter named {key:?}"),
+                )
+            },
+        )?;
+
+        self.unbound[usize::from(index) - 1] = false;
+        value.serialize(ValueSerializer)?.bind(self.binding, index)
+    }
+
+    fn end(self) -> Result<()> {
+        for (index, unbound) in BindIndex::INITIAL.iter().zip(self.unbound) {
+            if unbound {
+                self.binding.set(index, None::<i64>)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A single parameter value, copied out of a [`Serialize`] type.
+enum Value {
+    Null,
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl Value {
+    fn bind<'c, 's>(self, binding: &mut Binding<'c, 's>, index: BindIndex) -> Result<()>
+    where
+        'c: 's,
+    {
+        match self {
+            Value::Null => binding.set(index, None::<i64>),
+            Value::Integer(value) => binding.set(index, value),
+            Value::Float(value) => binding.set(index, value),
+            Value::Text(value) => binding.set(index, value),
+            Value::Blob(value) => binding.set(index, value),
+        }
+    }
+}
+
+/// Serialize one field of a struct into a [`Value`].
+struct ValueSerializer;
+
+impl ValueSerializer {
+    fn integer<T>(value: T) -> Result<Value>
+    where
+        T: TryInto<i64> + Display + Copy,
+    {
+        value.try_into().map(Value::Integer).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RANGE,
+                    format!("{value} cannot fit in i64 parameter"),
+                )
+            },
+        )
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = Impossible<Value, Error>;
+    type SerializeTuple = Impossible<Value, Error>;
+    type SerializeTupleStruct = Impossible<Value, Error>;
+    type SerializeTupleVariant = Impossible<Value, Error>;
+    type SerializeMap = Impossible<Value, Error>;
+    type SerializeStruct = Impossible<Value, Error>;
+    type SerializeStructVariant = Impossible<Value, Error>;
+
+    fn serialize_bool(self, value: bool) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value> {
+        Ok(Value::Integer(value))
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<Value> {
+        Self::integer(value)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value> {
+        Ok(Value::Integer(value.into()))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value> {
+        Self::integer(value)
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<Value> {
+        Self::integer(value)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value> {
+        Ok(Value::Float(value.into()))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value> {
+        Ok(Value::Float(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value> {
+        Ok(Value::Text(value.into()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value> {
+        Ok(Value::Text(value.to_owned()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value> {
+        Ok(Value::Blob(value.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value> {
+        Ok(Value::Text(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Value>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported_value("an enum with data"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(unsupported_value("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(unsupported_value("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(unsupported_value("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported_value("an enum with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported_value("a map"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(unsupported_value("a struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported_value("an enum with data"))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl ser::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Error::with_detail(ErrorCode::SQUIRE_PARAMETER_BIND, message.to_string())
+    }
+}
+
+#[cold]
+fn unsupported(kind: &str) -> Error {
+    Error::with_detail(
+        ErrorCode::SQUIRE_PARAMETER_BIND,
+        format!("cannot bind {kind} as named parameters; expected a struct"),
+    )
+}
+
+#[cold]
+fn unsupported_value(kind: &str) -> Error {
+    Error::with_detail(
+        ErrorCode::SQUIRE_PARAMETER_BIND,
+        format!("cannot bind {kind} as a parameter value"),
+    )
+}
@@ -0,0 +1,314 @@
+use core::{
+    ffi::{CStr, c_int, c_void},
+    fmt,
+    marker::PhantomData,
+    ptr,
+};
+use std::{
+    ffi::CString,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use sqlite::{
+    SQLITE_CHANGESET_ABORT, SQLITE_CHANGESET_CONFLICT, SQLITE_CHANGESET_CONSTRAINT,
+    SQLITE_CHANGESET_DATA, SQLITE_CHANGESET_FOREIGN_KEY, SQLITE_CHANGESET_NOTFOUND,
+    SQLITE_CHANGESET_OMIT, SQLITE_CHANGESET_REPLACE, sqlite3_changeset_iter, sqlite3_session,
+    sqlite3changeset_apply, sqlite3changeset_op, sqlite3session_attach, sqlite3session_changeset,
+    sqlite3session_create, sqlite3session_delete, sqlite3session_enable, sqlite3session_isempty,
+};
+
+use crate::{
+    connection::Connection,
+    error::{Error, ErrorCategory, ErrorCode, Result},
+    ffi,
+};
+
+impl Connection {
+    /// Create a [`Session`] to record changes made to the `schema` database
+    /// (e.g., `"main"`) through this connection.
+    ///
+    /// A new session records nothing until tables are
+    /// [attached](Session::attach) to it.
+    #[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+    #[doc(alias = "sqlite3session_create")]
+    pub fn session(&self, schema: &str) -> Result<Session<'_>> {
+        let schema = CString::new(schema)?;
+        let mut session = ptr::null_mut();
+
+        let result = unsafe {
+            sqlite3session_create(self.internal_ref().as_ptr(), schema.as_ptr(), &mut session)
+        };
+
+        if let Some(err) = Error::from_connection(self.internal_ref(), result) {
+            return Err(err);
+        }
+
+        match ptr::NonNull::new(session) {
+            Some(handle) => Ok(Session {
+                handle,
+                _connection: PhantomData,
+            }),
+            None => Err(Error::from(ErrorCategory::OutOfMemory)),
+        }
+    }
+
+    /// [Apply][apply] a [changeset](Session::changeset) to this connection’s
+    /// `main` database.
+    ///
+    /// When a change can’t be applied cleanly, `conflict` is called with the
+    /// [kind of conflict](ConflictType) and the name of the table, and decides
+    /// how to [resolve](ConflictResolution) it. If `conflict` returns
+    /// [`Abort`](ConflictResolution::Abort), every change applied so far is
+    /// rolled back, and `apply_changeset` returns an
+    /// [aborted](ErrorCategory::Aborted) error.
+    ///
+    /// [apply]: https://sqlite.org/session/sqlite3changeset_apply.html
+    #[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+    #[doc(alias = "sqlite3changeset_apply")]
+    pub fn apply_changeset<F>(&self, data: &[u8], mut conflict: F) -> Result<()>
+    where
+        F: FnMut(ConflictType, &str) -> ConflictResolution,
+    {
+        let len = c_int::try_from(data.len()).map_err(
+            #[cold]
+            |_| Error::from(ErrorCategory::TooBig),
+        )?;
+
+        let result = unsafe {
+            sqlite3changeset_apply(
+                self.internal_ref().as_ptr(),
+                len,
+                data.as_ptr().cast_mut().cast::<c_void>(),
+                None,
+                Some(call_conflict::<F>),
+                ptr::from_mut(&mut conflict).cast::<c_void>(),
+            )
+        };
+
+        match Error::from_connection(self.internal_ref(), result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+}
+
+unsafe extern "C" fn call_conflict<F>(
+    context: *mut c_void,
+    kind: c_int,
+    iter: *mut sqlite3_changeset_iter,
+) -> c_int
+where
+    F: FnMut(ConflictType, &str) -> ConflictResolution,
+{
+    let conflict = unsafe { &mut *context.cast::<F>() };
+    let Some(kind) = ConflictType::from_raw(kind) else {
+        return SQLITE_CHANGESET_ABORT;
+    };
+
+    let mut table = ptr::null();
+    let mut columns = 0;
+    let mut op = 0;
+    let mut indirect = 0;
+    let result =
+        unsafe { sqlite3changeset_op(iter, &mut table, &mut columns, &mut op, &mut indirect) };
+    if result != sqlite::SQLITE_OK || table.is_null() {
+        return SQLITE_CHANGESET_ABORT;
+    }
+
+    let table = unsafe { CStr::from_ptr(table) };
+    let table = String::from_utf8_lossy(table.to_bytes());
+
+    catch_unwind(AssertUnwindSafe(|| conflict(kind, &table)))
+        .map_or(SQLITE_CHANGESET_ABORT, ConflictResolution::raw)
+}
+
+/// A [session][] recording changes made to a database, which can be
+/// exported as a [changeset](Self::changeset) and
+/// [applied](Connection::apply_changeset) to another database.
+///
+/// Changes are only recorded to tables which have been
+/// [attached](Self::attach), and which have a `PRIMARY KEY`.
+///
+/// ```rust
+/// # use squire::{Connection, ConflictResolution};
+/// # fn main() -> squire::Result<()> {
+/// # let source = Connection::open(c":memory:")?;
+/// # let replica = Connection::open(c":memory:")?;
+/// for connection in [&source, &replica] {
+///     connection.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);", ())?;
+/// }
+///
+/// let mut session = source.session("main")?;
+/// session.attach(None)?;
+/// source.execute("INSERT INTO users (name) VALUES ('alice');", ())?;
+///
+/// let changeset = session.changeset()?;
+/// replica.apply_changeset(&changeset, |_, _| ConflictResolution::Abort)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [session]: https://sqlite.org/sessionintro.html
+#[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+pub struct Session<'c> {
+    handle: ptr::NonNull<sqlite3_session>,
+    _connection: PhantomData<&'c Connection>,
+}
+
+impl Session<'_> {
+    /// Start recording changes to `table`, or to every table in the database
+    /// if `table` is `None`.
+    #[doc(alias = "sqlite3session_attach")]
+    pub fn attach(&mut self, table: Option<&str>) -> Result<()> {
+        let table = table.map(CString::new).transpose()?;
+        let table = table.as_deref().map_or(ptr::null(), CStr::as_ptr);
+
+        let result = unsafe { sqlite3session_attach(self.as_ptr(), table) };
+
+        match Error::from_code(result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Pause or resume recording changes.
+    #[doc(alias = "sqlite3session_enable")]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        unsafe { sqlite3session_enable(self.as_ptr(), enabled as c_int) };
+    }
+
+    /// Check whether recording changes is enabled.
+    #[doc(alias = "sqlite3session_enable")]
+    pub fn is_enabled(&self) -> bool {
+        unsafe { sqlite3session_enable(self.as_ptr(), -1) != 0 }
+    }
+
+    /// Check whether no changes have been recorded.
+    #[doc(alias = "sqlite3session_isempty")]
+    pub fn is_empty(&self) -> bool {
+        unsafe { sqlite3session_isempty(self.as_ptr()) != 0 }
+    }
+
+    /// Export the changes recorded so far as a [changeset][].
+    ///
+    /// [changeset]: https://sqlite.org/sessionintro.html#changesets_and_patchsets
+    #[doc(alias = "sqlite3session_changeset")]
+    pub fn changeset(&self) -> Result<Vec<u8>> {
+        let mut len = 0;
+        let mut data = ptr::null_mut();
+
+        let result = unsafe { sqlite3session_changeset(self.as_ptr(), &mut len, &mut data) };
+        if let Some(err) = Error::from_code(result) {
+            return Err(err);
+        }
+
+        if data.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let len = usize::try_from(len).map_err(
+            #[cold]
+            |_| Error::new(ErrorCode::SQUIRE_FETCH_RANGE),
+        )?;
+        let data = unsafe { ffi::Bytes::from_raw_parts(data.cast(), len) };
+
+        Ok(data.to_vec())
+    }
+
+    /// Access the raw [`sqlite3_session`] pointer.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut sqlite3_session {
+        self.handle.as_ptr()
+    }
+}
+
+impl fmt::Debug for Session<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Session({:p})", self.as_ptr())
+    }
+}
+
+impl Drop for Session<'_> {
+    #[doc(alias = "sqlite3session_delete")]
+    fn drop(&mut self) {
+        unsafe { sqlite3session_delete(self.as_ptr()) };
+    }
+}
+
+/// Why a change couldn’t be [applied](Connection::apply_changeset) cleanly.
+#[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum ConflictType {
+    /// The row to update or delete exists, but its values don’t match the
+    /// changeset’s “before” values.
+    #[doc(alias = "SQLITE_CHANGESET_DATA")]
+    Data = SQLITE_CHANGESET_DATA,
+    /// The row to update or delete doesn’t exist.
+    #[doc(alias = "SQLITE_CHANGESET_NOTFOUND")]
+    NotFound = SQLITE_CHANGESET_NOTFOUND,
+    /// A row to insert has the same primary key as an existing row.
+    #[doc(alias = "SQLITE_CHANGESET_CONFLICT")]
+    Conflict = SQLITE_CHANGESET_CONFLICT,
+    /// Applying the change would violate a constraint.
+    #[doc(alias = "SQLITE_CHANGESET_CONSTRAINT")]
+    Constraint = SQLITE_CHANGESET_CONSTRAINT,
+    /// Applying the changeset left foreign key violations.
+    #[doc(alias = "SQLITE_CHANGESET_FOREIGN_KEY")]
+    ForeignKey = SQLITE_CHANGESET_FOREIGN_KEY,
+}
+
+impl ConflictType {
+    /// The `SQLITE_CHANGESET_*` constant for this conflict.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`ConflictType`] for a `SQLITE_CHANGESET_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_CHANGESET_DATA => Some(Self::Data),
+            SQLITE_CHANGESET_NOTFOUND => Some(Self::NotFound),
+            SQLITE_CHANGESET_CONFLICT => Some(Self::Conflict),
+            SQLITE_CHANGESET_CONSTRAINT => Some(Self::Constraint),
+            SQLITE_CHANGESET_FOREIGN_KEY => Some(Self::ForeignKey),
+            _ => None,
+        }
+    }
+}
+
+/// How to resolve a [conflict](ConflictType) while
+/// [applying](Connection::apply_changeset) a changeset.
+#[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum ConflictResolution {
+    /// Skip the conflicting change.
+    #[doc(alias = "SQLITE_CHANGESET_OMIT")]
+    Omit = SQLITE_CHANGESET_OMIT,
+    /// Apply the change anyway, replacing the existing row. Only allowed for
+    /// [`Data`](ConflictType::Data) and [`Conflict`](ConflictType::Conflict)
+    /// conflicts.
+    #[doc(alias = "SQLITE_CHANGESET_REPLACE")]
+    Replace = SQLITE_CHANGESET_REPLACE,
+    /// Stop, and roll back every change applied so far.
+    #[doc(alias = "SQLITE_CHANGESET_ABORT")]
+    Abort = SQLITE_CHANGESET_ABORT,
+}
+
+impl ConflictResolution {
+    /// The `SQLITE_CHANGESET_*` constant for this resolution.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`ConflictResolution`] for a `SQLITE_CHANGESET_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_CHANGESET_OMIT => Some(Self::Omit),
+            SQLITE_CHANGESET_REPLACE => Some(Self::Replace),
+            SQLITE_CHANGESET_ABORT => Some(Self::Abort),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use core::{cmp::Ordering, fmt, ptr};
+use std::ffi::CString;
+
+use sqlite::{
+    sqlite3_snapshot, sqlite3_snapshot_cmp, sqlite3_snapshot_free, sqlite3_snapshot_get,
+    sqlite3_snapshot_open,
+};
+
+use crate::{
+    connection::Connection,
+    error::{Error, ErrorCategory, Result},
+};
+
+impl Connection {
+    /// Record a [`Snapshot`] of the `schema` database (e.g., `"main"`), which
+    /// can later be [opened](Self::open_snapshot) to read the database as it
+    /// was when the snapshot was taken.
+    ///
+    /// The database must be in [WAL mode](crate::JournalMode::Wal), and this
+    /// connection must already have a read transaction open on it; begin a
+    /// transaction with `BEGIN` and read from the database (e.g., `SELECT`
+    /// from any table) *before* taking the snapshot. The connection must not
+    /// have written to the database in the transaction.
+    ///
+    /// See the [SQLite reference][get] for the full requirements.
+    ///
+    /// [get]: https://sqlite.org/c3ref/snapshot_get.html
+    #[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+    #[doc(alias = "sqlite3_snapshot_get")]
+    pub fn snapshot(&self, schema: &str) -> Result<Snapshot> {
+        let schema = CString::new(schema)?;
+        let mut snapshot = ptr::null_mut();
+
+        let result = unsafe {
+            sqlite3_snapshot_get(self.internal_ref().as_ptr(), schema.as_ptr(), &mut snapshot)
+        };
+
+        if let Some(err) = Error::from_connection(self.internal_ref(), result) {
+            return Err(err);
+        }
+
+        ptr::NonNull::new(snapshot).map(Snapshot).ok_or_else(
+            #[cold]
+            || Error::from(ErrorCategory::OutOfMemory),
+        )
+    }
+
+    /// Start reading the `schema` database (e.g., `"main"`) as it was when
+    /// `snapshot` was [taken](Self::snapshot).
+    ///
+    /// Call `open_snapshot` after `BEGIN`, but before reading from the
+    /// database in the transaction. The snapshot stays open until the
+    /// transaction ends.
+    ///
+    /// If the WAL file has been [checkpointed][] past the snapshot, it can no
+    /// longer be opened, and this fails with a
+    /// [`Snapshot`](crate::GeneralError::Snapshot) error.
+    ///
+    /// [checkpointed]: https://sqlite.org/wal.html#ckpt
+    #[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+    #[doc(alias = "sqlite3_snapshot_open")]
+    pub fn open_snapshot(&self, schema: &str, snapshot: &Snapshot) -> Result<()> {
+        let schema = CString::new(schema)?;
+
+        let result = unsafe {
+            sqlite3_snapshot_open(
+                self.internal_ref().as_ptr(),
+                schema.as_ptr(),
+                snapshot.as_ptr(),
+            )
+        };
+
+        match Error::from_connection(self.internal_ref(), result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+}
+
+/// A [snapshot][] of a WAL-mode database, recorded by
+/// [`Connection::snapshot`].
+///
+/// Snapshots of the same database can be compared: an earlier snapshot is
+/// less than a later one. Comparing snapshots of different databases is
+/// meaningless.
+///
+/// [snapshot]: https://sqlite.org/c3ref/snapshot.html
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+pub struct Snapshot(ptr::NonNull<sqlite3_snapshot>);
+
+impl Snapshot {
+    /// Access the raw [`sqlite3_snapshot`] pointer.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut sqlite3_snapshot {
+        self.0.as_ptr()
+    }
+}
+
+// SAFETY: A `sqlite3_snapshot` is a plain heap allocation owned by this
+// handle, not tied to the connection or thread that created it. SQLite only
+// reads it (in `sqlite3_snapshot_open` and `sqlite3_snapshot_cmp`) and frees it
+// in `sqlite3_snapshot_free`, so it may be moved to another thread.
+unsafe impl Send for Snapshot {}
+
+impl PartialEq for Snapshot {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Snapshot {
+    #[doc(alias = "sqlite3_snapshot_cmp")]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let result = unsafe { sqlite3_snapshot_cmp(self.as_ptr(), other.as_ptr()) };
+        Some(result.cmp(&0))
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Snapshot({:p})", self.as_ptr())
+    }
+}
+
+impl Drop for Snapshot {
+    #[doc(alias = "sqlite3_snapshot_free")]
+    fn drop(&mut self) {
+        unsafe { sqlite3_snapshot_free(self.as_ptr()) };
+    }
+}
@@ -1,4 +1,12 @@
-use core::{ffi::c_int, fmt, marker::PhantomData, mem};
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(sqlite_has_column_metadata)]
+use core::ffi::CStr;
+use core::{ffi::c_int, fmt, marker::PhantomData, mem, ops::ControlFlow};
 use sqlite::{SQLITE_PREPARE_NO_VTAB, SQLITE_PREPARE_PERSISTENT, sqlite3};
 
 use crate::{
@@ -6,26 +14,33 @@ use crate::{
     column::{ColumnIndexes, Columns},
     connection::Connection,
     error::{Error, ErrorCode, Result},
+    fetch::Fetch,
     ffi,
     param::Parameters,
-    row::{Row, Rows},
-    types::{BindIndex, ColumnIndex, RowId},
+    row::{Row, RowRef, Rows},
+    status::StmtCounter,
+    types::{Affinity, BindIndex, ColumnIndex, RowId, Type},
 };
 
 /// A [prepared statement][]; a SQL statement that SQLite has compiled and made
 /// ready to [bind](Self::bind()) and [execute](Execution).
 ///
 /// [prepared statement]: https://sqlite.org/c3ref/stmt.html
-#[repr(transparent)]
 pub struct Statement<'c> {
     inner: ffi::Statement<'c>,
+    /// The error from resetting the statement after its last [`Execution`],
+    /// which [`reset`](Self::reset) returns.
+    deferred: Option<Error>,
 }
 
 impl<'c> Statement<'c> {
     #[inline]
     #[must_use]
     pub(crate) const fn new(inner: ffi::Statement<'c>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            deferred: None,
+        }
     }
 
     /// Compile SQL `query` text into a [prepared statement](Self) that SQLite
@@ -44,17 +59,19 @@ impl<'c> Statement<'c> {
         query: impl AsRef<str>,
         options: PrepareOptions,
     ) -> Result<Self> {
-        ffi::Statement::prepare(
-            connection.internal_ref(),
-            query.as_ref(),
-            options.into_inner(),
-        )
-        .map(|(statement, _)| Self::new(statement))
+        let query = query.as_ref();
+        options.check_length(query)?;
+
+        ffi::Statement::prepare(connection.internal_ref(), query, options.into_inner())
+            .map(|(statement, _)| Self::new(statement))
     }
 
     /// Create a mutable [`Binding`] to set parameters individually.
     pub fn binding(&mut self) -> Binding<'c, '_> {
-        Binding { statement: self }
+        Binding {
+            statement: self,
+            bound: BoundSet::default(),
+        }
     }
 
     /// Create a mutable [`Binding`] initialized with [`Parameters`].
@@ -88,6 +105,137 @@ impl<'c> Statement<'c> {
         self.query(parameters)?.run()
     }
 
+    /// Execute the statement once for each set of parameters in `rows`, and
+    /// return the total number of affected rows.
+    ///
+    /// Parameter names are resolved once, then each set of parameters is
+    /// bound and executed in turn; the statement is reset and its bindings
+    /// cleared between each. This is the fastest way to run the same `INSERT`
+    /// (or `UPDATE`, …) many times.
+    ///
+    /// Execution stops at the first error, whose message notes how many sets
+    /// of parameters were executed successfully before it. Changes made by
+    /// those earlier executions are not rolled back; run `execute_many` in a
+    /// transaction to make it all-or-nothing.
+    pub fn execute_many<P, I, R>(&mut self, rows: R) -> Result<usize>
+    where
+        P: for<'a> Parameters<'a, Indexes = I>,
+        I: Copy,
+        R: IntoIterator<Item = P>,
+    {
+        let indexes = P::resolve(self).ok_or_else(
+            #[cold]
+            || Error::new(ErrorCode::SQUIRE_PARAMETER_RESOLVE),
+        )?;
+
+        let mut changes = 0;
+        for (completed, parameters) in rows.into_iter().enumerate() {
+            let result = self.execute_one(parameters, indexes);
+            self.clear_bindings()?;
+
+            match result {
+                Ok(count) => changes += count.unsigned_abs(),
+                Err(err) => return Err(err.after_rows(completed)),
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Execute the statement once for each set of parameters in `rows`, like
+    /// [`execute_many`](Self::execute_many), and collect every row returned
+    /// by its `RETURNING` clause.
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// connection.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);", ())?;
+    ///
+    /// let mut insert = connection.prepare("INSERT INTO users (name) VALUES (?) RETURNING id;")?;
+    /// let ids: Vec<i64> = insert.execute_many_returning([("alice",), ("bob",)])?;
+    /// assert_eq!(vec![1, 2], ids);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Rows are returned in order: every row returned for the first set of
+    /// parameters, then every row for the second, and so on. An execution
+    /// may return any number of rows, including none (e.g., because of
+    /// `ON CONFLICT DO NOTHING`).
+    ///
+    /// As with `execute_many`, execution stops at the first error, and
+    /// earlier executions are not rolled back. `RETURNING` requires SQLite
+    /// 3.35 or later; with an older SQLite, `execute_many_returning` always
+    /// returns an error.
+    pub fn execute_many_returning<P, I, R, C>(&mut self, rows: R) -> Result<Vec<C>>
+    where
+        P: for<'a> Parameters<'a, Indexes = I>,
+        I: Copy,
+        R: IntoIterator<Item = P>,
+        C: for<'r> Columns<'r> + 'static,
+    {
+        #[cfg(sqlite_has_returning)]
+        {
+            let indexes = P::resolve(self).ok_or_else(
+                #[cold]
+                || Error::new(ErrorCode::SQUIRE_PARAMETER_RESOLVE),
+            )?;
+
+            let mut returned = Vec::new();
+            for (completed, parameters) in rows.into_iter().enumerate() {
+                let result = self.execute_one_returning(parameters, indexes, &mut returned);
+                self.clear_bindings()?;
+
+                if let Err(err) = result {
+                    return Err(err.after_rows(completed));
+                }
+            }
+
+            Ok(returned)
+        }
+        #[cfg(not(sqlite_has_returning))]
+        {
+            let _ = rows;
+            Err(Error::with_detail(
+                ErrorCode::ERROR,
+                "RETURNING requires SQLite 3.35 or later",
+            ))
+        }
+    }
+
+    #[cfg(sqlite_has_returning)]
+    fn execute_one_returning<P, I, C>(
+        &mut self,
+        parameters: P,
+        indexes: I,
+        returned: &mut Vec<C>,
+    ) -> Result<()>
+    where
+        P: for<'a> Parameters<'a, Indexes = I>,
+        I: Copy,
+        C: for<'r> Columns<'r> + 'static,
+    {
+        let mut binding = self.binding();
+        parameters.bind(&mut binding, indexes)?;
+
+        for row in binding.done().rows::<C>()? {
+            returned.push(row?);
+        }
+
+        Ok(())
+    }
+
+    fn execute_one<P, I>(&mut self, parameters: P, indexes: I) -> Result<isize>
+    where
+        P: for<'a> Parameters<'a, Indexes = I>,
+        I: Copy,
+    {
+        let mut binding = self.binding();
+        parameters.bind(&mut binding, indexes)?;
+        binding.done().run()
+    }
+
     /// Execute an `INSERT` statement, and return the ID of the last
     /// newly-inserted row.
     ///
@@ -104,6 +252,101 @@ impl<'c> Statement<'c> {
         self.query(parameters)?.insert()
     }
 
+    /// [Reset][reset] the statement, so it can be executed again from the
+    /// beginning.
+    ///
+    /// Bound parameter values are retained; use
+    /// [`clear_bindings`](Self::clear_bindings) to reset them to `NULL`.
+    ///
+    /// If the most recent step of the statement failed (like a constraint
+    /// violation), `reset` returns that error again, even after the
+    /// [`Execution`] which stepped it has ended.
+    ///
+    /// [reset]: https://sqlite.org/c3ref/reset.html
+    #[doc(alias = "sqlite3_reset")]
+    pub fn reset(&mut self) -> Result<()> {
+        let result = unsafe { self.internal_mut().reset() };
+
+        match self.deferred.take() {
+            Some(err) => Err(err),
+            None => result,
+        }
+    }
+
+    /// Reset all bound parameters to `NULL`.
+    #[doc(alias = "sqlite3_clear_bindings")]
+    pub fn clear_bindings(&mut self) -> Result<()> {
+        self.internal_mut().clear()
+    }
+
+    /// The SQL text used to [prepare](Self::prepare) the statement.
+    #[doc(alias = "sqlite3_sql")]
+    pub fn sql(&self) -> Option<&str> {
+        self.internal_ref().sql().and_then(|sql| sql.to_str().ok())
+    }
+
+    /// The SQL text of the statement, with the currently-bound parameter values
+    /// substituted for their placeholders.
+    ///
+    /// Parameters which have not been bound are shown as `NULL`. Returns `None`
+    /// if SQLite could not allocate the expanded text.
+    #[doc(alias = "sqlite3_expanded_sql")]
+    pub fn expanded_sql(&self) -> Option<String> {
+        self.internal_ref()
+            .expanded_sql()
+            .map(|sql| sql.as_str().to_owned())
+    }
+
+    /// The [normalized][] SQL text of the statement, with literal values
+    /// replaced by `?` and whitespace and keyword case standardized.
+    ///
+    /// [normalized]: https://sqlite.org/c3ref/expanded_sql.html
+    #[cfg(sqlite_has_normalize_sql)]
+    #[doc(alias = "sqlite3_normalized_sql")]
+    pub fn normalized_sql(&self) -> Option<&str> {
+        self.internal_ref()
+            .normalized_sql()
+            .and_then(|sql| sql.to_str().ok())
+    }
+
+    /// Check if the statement makes no direct changes to the database.
+    ///
+    /// Statements like `BEGIN` and `COMMIT` are considered read-only, since
+    /// they don't write to the database themselves. (See the
+    /// [SQLite reference][readonly] for details.)
+    ///
+    /// [readonly]: https://sqlite.org/c3ref/stmt_readonly.html
+    #[doc(alias = "sqlite3_stmt_readonly")]
+    pub fn is_readonly(&self) -> bool {
+        self.internal_ref().is_readonly()
+    }
+
+    /// Check if the statement is an `EXPLAIN` or `EXPLAIN QUERY PLAN`
+    /// statement.
+    #[doc(alias = "sqlite3_stmt_isexplain")]
+    pub fn explain_mode(&self) -> ExplainMode {
+        ExplainMode::from_value(self.internal_ref().explain_mode()).unwrap_or_default()
+    }
+
+    /// Check if the statement has begun executing, and has not yet run to
+    /// completion or been [reset](Self::reset).
+    #[doc(alias = "sqlite3_stmt_busy")]
+    pub fn is_busy(&self) -> bool {
+        self.internal_ref().is_busy()
+    }
+
+    /// Read a [statement status](StmtCounter) counter. If `reset` is `true`,
+    /// the counter is reset to zero.
+    ///
+    /// Counters accumulate across executions of the statement, so a
+    /// [cached](crate::CachedStatement) statement can be checked after it has
+    /// run, e.g. to find queries which [scan](StmtCounter::FullscanStep)
+    /// entire tables for lack of an index.
+    #[doc(alias = "sqlite3_stmt_status")]
+    pub fn counter(&self, counter: StmtCounter, reset: bool) -> i32 {
+        self.internal_ref().status(counter.raw(), reset)
+    }
+
     /// Inspect the [columns](StatementColumns) returned by this statement.
     pub fn columns<'s>(&'s self) -> StatementColumns<'c, 's> {
         StatementColumns::new(self)
@@ -119,10 +362,19 @@ impl<'c> Statement<'c> {
     /// [Finalize]: https://sqlite.org/c3ref/finalize.html
     pub fn finalize(mut self) -> Result<()> {
         let result = unsafe { self.internal_mut().finalize() };
+        drop(self.deferred.take()); // forget would leak the boxed error
         mem::forget(self); // or Drop will also try to finalize
         result
     }
 
+    /// Unwrap the [`ffi::Statement`], without finalizing it.
+    pub(crate) fn into_inner(mut self) -> ffi::Statement<'c> {
+        drop(self.deferred.take()); // forget would leak the boxed error
+        let inner = ffi::Statement::new(self.internal_ref().as_ptr());
+        mem::forget(self); // or Drop will finalize the statement
+        inner.expect("statement pointer is non-null")
+    }
+
     /// Access the [`ffi::Statement`] underlying this [`Statement`].
     #[inline]
     pub(crate) fn internal_ref(&self) -> &ffi::Statement<'c> {
@@ -222,7 +474,10 @@ impl<'c, 's> Execute<'c, 's> for &'s mut Statement<'c> {
 
 /// Controls the behavior of [preparing](Statement::prepare()) a [`Statement`].
 #[derive(PartialEq, Eq, Default, Clone, Copy)]
-pub struct PrepareOptions(u32);
+pub struct PrepareOptions {
+    flags: u32,
+    max_length: Option<usize>,
+}
 
 impl PrepareOptions {
     #[cfg(sqlite_has_prepare_quiet)]
@@ -235,7 +490,10 @@ impl PrepareOptions {
     /// Hint to the query planner that the [`Statement`] will be quickly
     /// disposed of, and will not be retained.
     pub const fn transient() -> Self {
-        Self(0)
+        Self {
+            flags: 0,
+            max_length: None,
+        }
     }
 
     /// Hint to the query planner that the [`Statement`] will be retained.
@@ -248,7 +506,10 @@ impl PrepareOptions {
     /// [lookaside memory]: https://sqlite.org/malloc.html#lookaside
     #[doc(alias = "SQLITE_PREPARE_PERSISTENT")]
     pub const fn persistent() -> Self {
-        Self(Self::PERSISTENT)
+        Self {
+            flags: Self::PERSISTENT,
+            max_length: None,
+        }
     }
 
     /// Return an [error](crate::ErrorCategory::Unknown) if the statement uses
@@ -256,9 +517,9 @@ impl PrepareOptions {
     #[doc(alias = "SQLITE_PREPARE_NO_VTAB")]
     pub const fn allow_virtual_tables(&self, allowed: bool) -> Self {
         if allowed {
-            Self(self.0 & !Self::NO_VTAB)
+            self.with_flags(self.flags & !Self::NO_VTAB)
         } else {
-            Self(self.0 | Self::NO_VTAB)
+            self.with_flags(self.flags | Self::NO_VTAB)
         }
     }
 
@@ -272,9 +533,9 @@ impl PrepareOptions {
     #[cfg(sqlite_has_prepare_from_ddl)]
     pub const fn from_ddl(&self, strict: bool) -> Self {
         if strict {
-            Self(self.0 | Self::FROM_DDL)
+            self.with_flags(self.flags | Self::FROM_DDL)
         } else {
-            Self(self.0 & !Self::FROM_DDL)
+            self.with_flags(self.flags & !Self::FROM_DDL)
         }
     }
 
@@ -282,25 +543,119 @@ impl PrepareOptions {
     #[cfg(sqlite_has_prepare_quiet)]
     pub const fn log(&self, enabled: bool) -> Self {
         if enabled {
-            Self(self.0 & !Self::DONT_LOG)
+            self.with_flags(self.flags & !Self::DONT_LOG)
         } else {
-            Self(self.0 | Self::DONT_LOG)
+            self.with_flags(self.flags | Self::DONT_LOG)
+        }
+    }
+
+    /// Return a [too big](crate::ErrorCategory::TooBig) error, without
+    /// calling SQLite, if the SQL text is longer than `bytes`.
+    ///
+    /// SQLite already rejects statements longer than its
+    /// [`SqlLength`](crate::Limit::SqlLength) limit, but only once it begins
+    /// parsing them. Use `max_length` to cap the size of untrusted SQL up front.
+    pub const fn max_length(&self, bytes: usize) -> Self {
+        Self {
+            flags: self.flags,
+            max_length: Some(bytes),
         }
     }
 
     pub const fn into_inner(self) -> u32 {
-        self.0
+        self.flags
+    }
+
+    const fn with_flags(&self, flags: u32) -> Self {
+        Self {
+            flags,
+            max_length: self.max_length,
+        }
+    }
+
+    fn check_length(&self, query: &str) -> Result<()> {
+        match self.max_length {
+            Some(max) if query.len() > max => Err(Error::with_detail(
+                ErrorCode::TOOBIG,
+                format!(
+                    "SQL is {} bytes, longer than the maximum of {max}",
+                    query.len()
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The outcome of [executing](Execution::complete) a statement to completion.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Completion {
+    /// The number of rows [changed][changes] by the statement.
+    ///
+    /// [changes]: https://sqlite.org/c3ref/changes.html
+    pub changes: i64,
+    /// The [most recently inserted][rowid] row ID on the connection, or `None`
+    /// if no row has been inserted.
+    ///
+    /// [rowid]: https://sqlite.org/c3ref/last_insert_rowid.html
+    pub last_insert_rowid: Option<RowId>,
+}
+
+impl ffi::Conclusion for Completion {
+    #[inline]
+    unsafe fn from_connection_ptr(connection: *mut sqlite3) -> Self {
+        Self {
+            changes: unsafe { isize::from_connection_ptr(connection) } as i64,
+            last_insert_rowid: unsafe { Option::<RowId>::from_connection_ptr(connection) },
+        }
+    }
+}
+
+/// Whether a [`Statement`] is prefixed with [`EXPLAIN`][explain].
+///
+/// [explain]: https://sqlite.org/lang_explain.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+#[repr(i32)]
+pub enum ExplainMode {
+    /// An ordinary statement.
+    #[default]
+    Normal = 0,
+    /// An `EXPLAIN` statement, which returns the bytecode of the statement.
+    Explain = 1,
+    /// An `EXPLAIN QUERY PLAN` statement, which describes the query plan.
+    ExplainQueryPlan = 2,
+}
+
+impl ExplainMode {
+    /// The value [`sqlite3_stmt_isexplain`][isexplain] returns for this mode.
+    ///
+    /// [isexplain]: https://sqlite.org/c3ref/stmt_isexplain.html
+    pub const fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Find the [`ExplainMode`] for a value returned by
+    /// [`sqlite3_stmt_isexplain`][isexplain], or `None` if it is out of range.
+    ///
+    /// [isexplain]: https://sqlite.org/c3ref/stmt_isexplain.html
+    pub const fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Normal),
+            1 => Some(Self::Explain),
+            2 => Some(Self::ExplainQueryPlan),
+            _ => None,
+        }
     }
 }
 
 /// A mutable set of parameters bound to a [`Statement`].
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct Binding<'c, 's>
 where
     'c: 's,
 {
     statement: &'s mut Statement<'c>,
+    bound: BoundSet,
 }
 
 impl<'c, 's> Binding<'c, 's>
@@ -314,8 +669,34 @@ where
         unsafe {
             self.statement
                 .internal_mut()
-                .bind(index, value.into_bind_value()?)
+                .bind(index, value.into_bind_value()?)?;
         }
+
+        self.bound.insert(index);
+        Ok(())
+    }
+
+    /// Bind `value` to the parameter called `name`.
+    ///
+    /// The name can be given with its sigil (e.g., `:id`) or without it
+    /// (`id`), as in [`StatementParameters::index`]. Fails with a
+    /// [resolve error](crate::ParameterError::Resolve) if the statement has
+    /// no parameter called `name`.
+    pub fn set_named<B>(&mut self, name: &str, value: B) -> Result<()>
+    where
+        B: Bind<'s>,
+    {
+        let index = self.statement.parameters().index(name).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RESOLVE,
+                    format!("statement has no parameter named {name:?}"),
+                )
+            },
+        )?;
+
+        self.set(index, value)
     }
 
     pub fn ready<'b>(&'b mut self) -> Execution<'c, 's, &'b mut Self> {
@@ -325,6 +706,91 @@ where
     pub fn done(self) -> Execution<'c, 's> {
         Execution::new(self)
     }
+
+    /// Check that every parameter of the statement was [set](Self::set)
+    /// through this `Binding`, then begin [executing](Execution) it.
+    ///
+    /// SQLite keeps the values bound to a statement until they're
+    /// [cleared](Statement::clear_bindings), so a cached statement that's
+    /// rebound without one of its parameters silently reuses the value from
+    /// its previous run. `strict` fails with a
+    /// [resolve error](crate::ParameterError::Resolve) listing the indexes of
+    /// any parameters which weren't set instead.
+    ///
+    /// ```rust
+    /// # use squire::{BindIndex, Connection};
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut statement = connection.prepare("SELECT ?1 + ?2;")?;
+    ///
+    /// let mut binding = statement.binding();
+    /// binding.set(BindIndex::INITIAL, 1)?;
+    /// assert!(binding.strict().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Parameter numbers skipped by the SQL (e.g., `2` in `?1, ?3`) must
+    /// also be set.
+    pub fn strict(self) -> Result<Execution<'c, 's>> {
+        let unbound: Vec<String> = self
+            .statement
+            .parameters()
+            .iter()
+            .filter(|&index| !self.bound.contains(index))
+            .map(|index| index.value().to_string())
+            .collect();
+
+        if unbound.is_empty() {
+            Ok(self.done())
+        } else {
+            Err(Error::with_detail(
+                ErrorCode::SQUIRE_PARAMETER_RESOLVE,
+                format!("parameters not bound: {}", unbound.join(", ")),
+            ))
+        }
+    }
+}
+
+/// The set of parameter indexes bound through a [`Binding`].
+///
+/// Indexes up to 64 are tracked without allocating.
+#[derive(Default, Debug)]
+struct BoundSet {
+    low: u64,
+    high: Vec<u64>,
+}
+
+impl BoundSet {
+    fn insert(&mut self, index: BindIndex) {
+        let (word, bit) = Self::position(index);
+
+        match word.checked_sub(1) {
+            None => self.low |= bit,
+            Some(word) => {
+                if self.high.len() <= word {
+                    self.high.resize(word + 1, 0);
+                }
+                self.high[word] |= bit;
+            }
+        }
+    }
+
+    fn contains(&self, index: BindIndex) -> bool {
+        let (word, bit) = Self::position(index);
+
+        let word = match word.checked_sub(1) {
+            None => self.low,
+            Some(word) => self.high.get(word).copied().unwrap_or(0),
+        };
+        word & bit != 0
+    }
+
+    #[inline]
+    fn position(index: BindIndex) -> (usize, u64) {
+        let offset = usize::from(index) - 1;
+        (offset / 64, 1 << (offset % 64))
+    }
 }
 
 impl<'c, 's> ffi::Connected for Binding<'c, 's>
@@ -449,6 +915,103 @@ where
         self.rows()?.into_iter().collect()
     }
 
+    /// Fetch every row into a [`Vec`], reserving room for `capacity` rows
+    /// up front.
+    ///
+    /// The `capacity` is only a hint; the `Vec` grows as usual if more rows
+    /// are returned.
+    pub fn all_with_capacity<C>(self, capacity: usize) -> Result<Vec<C>>
+    where
+        C: for<'r> Columns<'r> + 'static,
+    {
+        let mut rows = Vec::with_capacity(capacity);
+        for row in self.rows()? {
+            rows.push(row?);
+        }
+
+        Ok(rows)
+    }
+
+    /// Fetch a single column from every row returned by the query.
+    ///
+    /// ```rust
+    /// # use squire::{ColumnIndex, Connection};
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare("SELECT 1, 'a' UNION ALL SELECT 2, 'b';")?;
+    /// let ids: Vec<i64> = query.query(())?.column(ColumnIndex::INITIAL)?;
+    /// assert_eq!(vec![1, 2], ids);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// This consumes the whole result set, and returns an empty `Vec` if the
+    /// query returned no rows. Returns an
+    /// [unknown column](crate::FetchError::UnknownColumn) error if the query
+    /// has no column at `index`.
+    pub fn column<T>(self, index: ColumnIndex) -> Result<Vec<T>>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        let statement = self.cursor();
+        if !statement.columns().iter().any(|column| column == index) {
+            return Err(Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_UNKNOWN_COLUMN,
+                format!("query has no column {}", index.value()),
+            ));
+        }
+
+        let mut values = Vec::new();
+        while unsafe { statement.internal_ref().row() }? {
+            values.push(T::fetch_column(statement, index)?);
+        }
+
+        Ok(values)
+    }
+
+    /// Fetch a single column, found by its name, from every row returned by
+    /// the query; see [`column`](Self::column).
+    ///
+    /// If several columns share the name, the first is fetched.
+    pub fn column_named<T>(self, name: &str) -> Result<Vec<T>>
+    where
+        T: for<'r> Fetch<'r>,
+    {
+        let index = self.cursor().columns().index(name).ok_or_else(
+            #[cold]
+            || {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_FETCH_UNKNOWN_COLUMN,
+                    format!("query has no column named {name:?}"),
+                )
+            },
+        )?;
+
+        self.column(index)
+    }
+
+    /// Collect each row's [result](Result) into any collection.
+    ///
+    /// Unlike [`all`](Self::all), fetching doesn't stop at the first error;
+    /// each row's error is passed to the collection.
+    pub fn collect_into<B, C>(self) -> Result<B>
+    where
+        B: FromIterator<Result<C>>,
+        C: for<'r> Columns<'r> + 'static,
+    {
+        Ok(self.rows()?.into_iter().collect())
+    }
+
+    /// Fetch the first row returned by the query.
+    ///
+    /// Returns a [`NotReturned`](crate::RowError::NotReturned) error if the
+    /// query returned no rows.
+    ///
+    /// An aggregate query like `SELECT max(x) FROM t` always returns a row,
+    /// but its value is `NULL` when `t` is empty. Fetch such a column as an
+    /// [`Option`] (e.g., `one::<Option<i64>>()` or `one::<(Option<i64>,)>()`)
+    /// to tell `NULL` apart; like the SQLite C API, fetching `NULL` as a plain
+    /// number reads it as zero.
     pub fn one<C>(self) -> Result<C>
     where
         C: for<'r> Columns<'r>,
@@ -460,6 +1023,80 @@ where
         }
     }
 
+    /// Fetch the single row returned by the query, if any.
+    ///
+    /// Returns `Ok(None)` if the query returned no rows, and a
+    /// [`TooMany`](crate::RowError::TooMany) error if it returned more than one.
+    pub fn optional<C>(self) -> Result<Option<C>>
+    where
+        C: for<'r> Columns<'r>,
+    {
+        let mut rows = Rows::new(self)?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+
+        if rows.step()? {
+            Err(Error::row_too_many())
+        } else {
+            Ok(Some(row))
+        }
+    }
+
+    /// Call a fallible closure with each row returned by the query, stopping
+    /// early if it returns an error or [`ControlFlow::Break`].
+    ///
+    /// Each row is passed as a [`RowRef`], which can fetch [`Columns`] that
+    /// borrow data from the SQLite row, like [`Borrowed<str>`](crate::Borrowed),
+    /// without copying. Borrowed data can't be kept past the closure call
+    /// that received the row.
+    ///
+    /// ```rust
+    /// # use squire::{Borrowed, Connection};
+    /// # use core::ops::ControlFlow;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// let mut query = connection.prepare("SELECT 'alice' UNION ALL SELECT 'bob';")?;
+    ///
+    /// let mut total = 0;
+    /// query.query(())?.try_for_each(|row| {
+    ///     let name: Borrowed<str> = row.get(squire::ColumnIndex::INITIAL)?;
+    ///     total += name.len();
+    ///     Ok(ControlFlow::Continue(()))
+    /// })?;
+    /// assert_eq!(8, total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_for_each<F>(self, mut f: F) -> Result<()>
+    where
+        F: for<'r> FnMut(&'r RowRef<'r>) -> Result<ControlFlow<()>>,
+    {
+        while unsafe { self.cursor().internal_ref().row() }? {
+            if f(&RowRef::new(self.cursor()))?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fold every row returned by the query into an accumulator.
+    ///
+    /// Like [`try_for_each`](Self::try_for_each), each row is passed as a
+    /// [`RowRef`], and data borrowed from it can't be kept in the accumulator.
+    pub fn fold<B, F>(self, init: B, mut f: F) -> Result<B>
+    where
+        F: for<'r> FnMut(B, &'r RowRef<'r>) -> Result<B>,
+    {
+        let mut acc = init;
+        while unsafe { self.cursor().internal_ref().row() }? {
+            acc = f(acc, &RowRef::new(self.cursor()))?;
+        }
+
+        Ok(acc)
+    }
+
     pub fn run(self) -> Result<isize> {
         unsafe { self.cursor().internal_ref().execute() }
     }
@@ -468,6 +1105,70 @@ where
         unsafe { self.cursor().internal_ref().execute() }
     }
 
+    /// Execute an `INSERT ... RETURNING` statement, and fetch the
+    /// [`Columns`] returned for the inserted row.
+    ///
+    /// Unlike [`insert`](Self::insert), this works for `WITHOUT ROWID` tables
+    /// and composite keys, and can return columns filled in by defaults:
+    ///
+    /// ```rust
+    /// # use squire::Connection;
+    /// # fn main() -> squire::Result<()> {
+    /// # let connection = Connection::open(c":memory:")?;
+    /// connection.execute(
+    ///     "CREATE TABLE members (org TEXT, user TEXT, role TEXT DEFAULT 'member', PRIMARY KEY (org, user)) WITHOUT ROWID;",
+    ///     (),
+    /// )?;
+    ///
+    /// let mut insert = connection.prepare(
+    ///     "INSERT INTO members (org, user) VALUES (?, ?) RETURNING org, user, role;",
+    /// )?;
+    /// let (org, user, role): (String, String, String) =
+    ///     insert.query(("acme", "alice"))?.insert_returning()?;
+    /// assert_eq!(("acme", "alice", "member"), (&*org, &*user, &*role));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If the statement inserts several rows, only the first row returned is
+    /// fetched, but every row is still inserted. Returns a
+    /// [`NotReturned`](crate::RowError::NotReturned) error if no row was
+    /// returned (e.g., because of `ON CONFLICT DO NOTHING`).
+    ///
+    /// `RETURNING` requires SQLite 3.35 or later; with an older SQLite,
+    /// `insert_returning` always returns an error.
+    pub fn insert_returning<C>(self) -> Result<C>
+    where
+        C: for<'r> Columns<'r>,
+    {
+        #[cfg(sqlite_has_returning)]
+        {
+            self.one()
+        }
+        #[cfg(not(sqlite_has_returning))]
+        {
+            Err(Error::with_detail(
+                ErrorCode::ERROR,
+                "RETURNING requires SQLite 3.35 or later",
+            ))
+        }
+    }
+
+    /// Execute the statement, and return both the number of affected rows and
+    /// the last-inserted row ID as a [`Completion`].
+    ///
+    /// Both values are read from SQLite immediately after the statement
+    /// finishes, before any other statement can change them.
+    pub fn complete(self) -> Result<Completion> {
+        unsafe { self.cursor().internal_ref().execute() }
+    }
+
+    /// The [`Statement`] being executed.
+    #[inline]
+    pub fn statement(&self) -> &Statement<'c> {
+        self.cursor()
+    }
+
     #[inline]
     pub(crate) fn cursor<'e>(&'e self) -> &'e Statement<'c>
     where
@@ -495,7 +1196,8 @@ where
     'c: 's,
 {
     fn drop(&mut self) {
-        let _ = self.inner.reset();
+        let deferred = self.inner.reset().err();
+        self.inner.cursor_mut().deferred = deferred;
     }
 }
 
@@ -516,17 +1218,85 @@ where
         Self { statement }
     }
 
+    /// The name of `column`, or `None` if `column` is out of range (or its
+    /// name is not valid UTF-8).
     pub fn name(&self, column: ColumnIndex) -> Option<&str> {
         self.statement
             .internal_ref()
             .column_name(column)
-            .map(|name| unsafe { str::from_utf8_unchecked(name.to_bytes()) })
+            .and_then(|name| str::from_utf8(name.to_bytes()).ok())
     }
 
     pub fn index(&self, name: impl AsRef<str>) -> Option<ColumnIndex> {
+        self.index_from(name, ColumnIndex::INITIAL)
+    }
+
+    /// The declared type of the table column returned as `column`.
+    ///
+    /// Returns `None` if `column` is an expression or subquery rather than a
+    /// table column, if the declared type is not valid UTF-8, or if SQLite was
+    /// built with
+    /// [`SQLITE_OMIT_DECLTYPE`](https://sqlite.org/compile.html#omit_decltype).
+    #[doc(alias = "sqlite3_column_decltype")]
+    pub fn declared_type(&self, column: ColumnIndex) -> Option<&str> {
+        #[cfg(sqlite_has_column_declared_type)]
+        {
+            self.statement
+                .internal_ref()
+                .column_declared_type(column)
+                .and_then(|name| str::from_utf8(name.to_bytes()).ok())
+        }
+
+        #[cfg(not(sqlite_has_column_declared_type))]
+        {
+            let _ = column;
+            None
+        }
+    }
+
+    /// The [type affinity](Affinity) of the table column returned as
+    /// `column`, determined from its [declared type](Self::declared_type).
+    ///
+    /// Returns `None` whenever [`declared_type`](Self::declared_type) does.
+    pub fn affinity(&self, column: ColumnIndex) -> Option<Affinity> {
+        self.declared_type(column).map(Type::affinity_of)
+    }
+
+    /// The database, table, and column that `column` was read from.
+    ///
+    /// Returns `None` if `column` is an expression or subquery rather than a
+    /// table column, if any of the names is not valid UTF-8, or if SQLite was
+    /// built without
+    /// [`SQLITE_ENABLE_COLUMN_METADATA`](https://sqlite.org/compile.html#enable_column_metadata).
+    #[doc(alias = "sqlite3_column_table_name")]
+    pub fn origin(&self, column: ColumnIndex) -> Option<ColumnOrigin<'_>> {
+        #[cfg(sqlite_has_column_metadata)]
+        {
+            fn text(name: &CStr) -> Option<&str> {
+                str::from_utf8(name.to_bytes()).ok()
+            }
+
+            let statement = self.statement.internal_ref();
+
+            Some(ColumnOrigin {
+                database: text(statement.column_database_name(column)?)?,
+                table: text(statement.column_table_name(column)?)?,
+                column: text(statement.column_origin_name(column)?)?,
+            })
+        }
+
+        #[cfg(not(sqlite_has_column_metadata))]
+        {
+            let _ = column;
+            None
+        }
+    }
+
+    /// Find the first column named `name` at or after `start`.
+    pub fn index_from(&self, name: impl AsRef<str>, start: ColumnIndex) -> Option<ColumnIndex> {
         let name = name.as_ref();
 
-        for index in self.iter() {
+        for index in self.iter().skip(usize::from(start)) {
             if let Some(n) = self.name(index)
                 && name == n
             {
@@ -555,6 +1325,33 @@ where
     }
 }
 
+/// The table column from which a [statement column](StatementColumns) is
+/// read; returned by [`StatementColumns::origin`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct ColumnOrigin<'s> {
+    database: &'s str,
+    table: &'s str,
+    column: &'s str,
+}
+
+impl<'s> ColumnOrigin<'s> {
+    /// The name of the database containing the [table](Self::table), such as
+    /// `main` or `temp`.
+    pub const fn database(&self) -> &'s str {
+        self.database
+    }
+
+    /// The name of the table containing the [column](Self::column).
+    pub const fn table(&self) -> &'s str {
+        self.table
+    }
+
+    /// The name of the column in its [table](Self::table).
+    pub const fn column(&self) -> &'s str {
+        self.column
+    }
+}
+
 impl<'c, 's> IntoIterator for StatementColumns<'c, 's>
 where
     'c: 's,
@@ -620,7 +1417,7 @@ where
         self.statement
             .internal_ref()
             .parameter_name(index)
-            .map(|name| unsafe { str::from_utf8_unchecked(name.to_bytes()) })
+            .and_then(|name| str::from_utf8(name.to_bytes()).ok())
     }
 
     pub fn index(&self, name: impl AsRef<str>) -> Option<BindIndex> {
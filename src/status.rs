@@ -0,0 +1,231 @@
+use sqlite::{
+    SQLITE_DBSTATUS_CACHE_HIT, SQLITE_DBSTATUS_CACHE_MISS, SQLITE_DBSTATUS_CACHE_SPILL,
+    SQLITE_DBSTATUS_CACHE_USED, SQLITE_DBSTATUS_CACHE_USED_SHARED, SQLITE_DBSTATUS_CACHE_WRITE,
+    SQLITE_DBSTATUS_DEFERRED_FKS, SQLITE_DBSTATUS_LOOKASIDE_HIT,
+    SQLITE_DBSTATUS_LOOKASIDE_MISS_FULL, SQLITE_DBSTATUS_LOOKASIDE_MISS_SIZE,
+    SQLITE_DBSTATUS_LOOKASIDE_USED, SQLITE_DBSTATUS_SCHEMA_USED, SQLITE_DBSTATUS_STMT_USED,
+    SQLITE_STATUS_MALLOC_COUNT, SQLITE_STATUS_MALLOC_SIZE, SQLITE_STATUS_MEMORY_USED,
+    SQLITE_STATUS_PAGECACHE_OVERFLOW, SQLITE_STATUS_PAGECACHE_SIZE, SQLITE_STATUS_PAGECACHE_USED,
+    SQLITE_STATUS_PARSER_STACK, SQLITE_STMTSTATUS_AUTOINDEX, SQLITE_STMTSTATUS_FULLSCAN_STEP,
+    SQLITE_STMTSTATUS_MEMUSED, SQLITE_STMTSTATUS_REPREPARE, SQLITE_STMTSTATUS_RUN,
+    SQLITE_STMTSTATUS_SORT, SQLITE_STMTSTATUS_VM_STEP, sqlite3_status64,
+};
+
+use crate::error::{Error, Result};
+
+/// A global [runtime status][status] counter, read with [`status`].
+///
+/// [status]: https://sqlite.org/c3ref/c_status_malloc_count.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum Status {
+    /// Bytes of memory currently allocated by SQLite’s memory allocator.
+    #[doc(alias = "SQLITE_STATUS_MEMORY_USED")]
+    MemoryUsed = SQLITE_STATUS_MEMORY_USED,
+    /// Pages allocated from the page cache memory configured with
+    /// `SQLITE_CONFIG_PAGECACHE`.
+    #[doc(alias = "SQLITE_STATUS_PAGECACHE_USED")]
+    PageCacheUsed = SQLITE_STATUS_PAGECACHE_USED,
+    /// Bytes of page cache allocations which didn't fit in the configured
+    /// page cache memory, and were allocated from the heap instead.
+    #[doc(alias = "SQLITE_STATUS_PAGECACHE_OVERFLOW")]
+    PageCacheOverflow = SQLITE_STATUS_PAGECACHE_OVERFLOW,
+    /// The size in bytes of the largest single memory allocation. (Only the
+    /// highwater value is meaningful.)
+    #[doc(alias = "SQLITE_STATUS_MALLOC_SIZE")]
+    MallocSize = SQLITE_STATUS_MALLOC_SIZE,
+    /// The deepest parser stack. (Only the highwater value is meaningful, and
+    /// only when SQLite was built with `YYTRACKMAXSTACKDEPTH`.)
+    #[doc(alias = "SQLITE_STATUS_PARSER_STACK")]
+    ParserStack = SQLITE_STATUS_PARSER_STACK,
+    /// The size in bytes of the largest page cache allocation. (Only the
+    /// highwater value is meaningful.)
+    #[doc(alias = "SQLITE_STATUS_PAGECACHE_SIZE")]
+    PageCacheSize = SQLITE_STATUS_PAGECACHE_SIZE,
+    /// The number of separate memory allocations currently held.
+    #[doc(alias = "SQLITE_STATUS_MALLOC_COUNT")]
+    MallocCount = SQLITE_STATUS_MALLOC_COUNT,
+}
+
+impl Status {
+    /// The `SQLITE_STATUS_*` constant for this counter.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`Status`] for a `SQLITE_STATUS_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_STATUS_MEMORY_USED => Some(Self::MemoryUsed),
+            SQLITE_STATUS_PAGECACHE_USED => Some(Self::PageCacheUsed),
+            SQLITE_STATUS_PAGECACHE_OVERFLOW => Some(Self::PageCacheOverflow),
+            SQLITE_STATUS_MALLOC_SIZE => Some(Self::MallocSize),
+            SQLITE_STATUS_PARSER_STACK => Some(Self::ParserStack),
+            SQLITE_STATUS_PAGECACHE_SIZE => Some(Self::PageCacheSize),
+            SQLITE_STATUS_MALLOC_COUNT => Some(Self::MallocCount),
+            _ => None,
+        }
+    }
+}
+
+/// A [connection status][status] counter, read with
+/// [`Connection::status`](crate::Connection::status).
+///
+/// [status]: https://sqlite.org/c3ref/c_dbstatus_options.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum DbStatus {
+    /// Lookaside memory slots currently checked out.
+    #[doc(alias = "SQLITE_DBSTATUS_LOOKASIDE_USED")]
+    LookasideUsed = SQLITE_DBSTATUS_LOOKASIDE_USED,
+    /// Bytes of heap memory used by the connection’s page caches.
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_USED")]
+    CacheUsed = SQLITE_DBSTATUS_CACHE_USED,
+    /// Bytes of heap memory used to store the schemas of the connection’s
+    /// databases.
+    #[doc(alias = "SQLITE_DBSTATUS_SCHEMA_USED")]
+    SchemaUsed = SQLITE_DBSTATUS_SCHEMA_USED,
+    /// Bytes of heap memory used by the connection’s prepared statements.
+    #[doc(alias = "SQLITE_DBSTATUS_STMT_USED")]
+    StatementUsed = SQLITE_DBSTATUS_STMT_USED,
+    /// Allocations satisfied from lookaside memory. (Only the highwater value
+    /// is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_LOOKASIDE_HIT")]
+    LookasideHit = SQLITE_DBSTATUS_LOOKASIDE_HIT,
+    /// Allocations which were too large for lookaside memory. (Only the
+    /// highwater value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_LOOKASIDE_MISS_SIZE")]
+    LookasideMissSize = SQLITE_DBSTATUS_LOOKASIDE_MISS_SIZE,
+    /// Allocations which missed lookaside memory because it was full. (Only
+    /// the highwater value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_LOOKASIDE_MISS_FULL")]
+    LookasideMissFull = SQLITE_DBSTATUS_LOOKASIDE_MISS_FULL,
+    /// Page cache hits. (Only the current value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_HIT")]
+    CacheHit = SQLITE_DBSTATUS_CACHE_HIT,
+    /// Page cache misses. (Only the current value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_MISS")]
+    CacheMiss = SQLITE_DBSTATUS_CACHE_MISS,
+    /// Dirty pages written to disk. (Only the current value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_WRITE")]
+    CacheWrite = SQLITE_DBSTATUS_CACHE_WRITE,
+    /// `1` if there are outstanding deferred foreign key violations, or `0`
+    /// if not. (Only the current value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_DEFERRED_FKS")]
+    DeferredForeignKeys = SQLITE_DBSTATUS_DEFERRED_FKS,
+    /// Like [`CacheUsed`](Self::CacheUsed), but page caches shared with other
+    /// connections are divided evenly between them.
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_USED_SHARED")]
+    CacheUsedShared = SQLITE_DBSTATUS_CACHE_USED_SHARED,
+    /// Dirty pages written to disk in the middle of a transaction, because
+    /// the page cache was full. (Only the current value is meaningful.)
+    #[doc(alias = "SQLITE_DBSTATUS_CACHE_SPILL")]
+    CacheSpill = SQLITE_DBSTATUS_CACHE_SPILL,
+}
+
+impl DbStatus {
+    /// The `SQLITE_DBSTATUS_*` constant for this counter.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`DbStatus`] for a `SQLITE_DBSTATUS_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_DBSTATUS_LOOKASIDE_USED => Some(Self::LookasideUsed),
+            SQLITE_DBSTATUS_CACHE_USED => Some(Self::CacheUsed),
+            SQLITE_DBSTATUS_SCHEMA_USED => Some(Self::SchemaUsed),
+            SQLITE_DBSTATUS_STMT_USED => Some(Self::StatementUsed),
+            SQLITE_DBSTATUS_LOOKASIDE_HIT => Some(Self::LookasideHit),
+            SQLITE_DBSTATUS_LOOKASIDE_MISS_SIZE => Some(Self::LookasideMissSize),
+            SQLITE_DBSTATUS_LOOKASIDE_MISS_FULL => Some(Self::LookasideMissFull),
+            SQLITE_DBSTATUS_CACHE_HIT => Some(Self::CacheHit),
+            SQLITE_DBSTATUS_CACHE_MISS => Some(Self::CacheMiss),
+            SQLITE_DBSTATUS_CACHE_WRITE => Some(Self::CacheWrite),
+            SQLITE_DBSTATUS_DEFERRED_FKS => Some(Self::DeferredForeignKeys),
+            SQLITE_DBSTATUS_CACHE_USED_SHARED => Some(Self::CacheUsedShared),
+            SQLITE_DBSTATUS_CACHE_SPILL => Some(Self::CacheSpill),
+            _ => None,
+        }
+    }
+}
+
+/// A [prepared statement status][status] counter, read with
+/// [`Statement::counter`](crate::Statement::counter).
+///
+/// [status]: https://sqlite.org/c3ref/c_stmtstatus_counter.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum StmtCounter {
+    /// Forward steps through a table as part of a full table scan. A large
+    /// count may indicate a missing index.
+    #[doc(alias = "SQLITE_STMTSTATUS_FULLSCAN_STEP")]
+    FullscanStep = SQLITE_STMTSTATUS_FULLSCAN_STEP,
+    /// Sort operations. A non-zero count may indicate an index which could
+    /// be used to avoid sorting.
+    #[doc(alias = "SQLITE_STMTSTATUS_SORT")]
+    Sort = SQLITE_STMTSTATUS_SORT,
+    /// Rows inserted into [automatic indexes][autoindex], which SQLite builds
+    /// when a permanent index would help.
+    ///
+    /// [autoindex]: https://sqlite.org/optoverview.html#autoindex
+    #[doc(alias = "SQLITE_STMTSTATUS_AUTOINDEX")]
+    AutoIndex = SQLITE_STMTSTATUS_AUTOINDEX,
+    /// Virtual machine operations run by the statement.
+    #[doc(alias = "SQLITE_STMTSTATUS_VM_STEP")]
+    VmStep = SQLITE_STMTSTATUS_VM_STEP,
+    /// Times the statement was automatically re-prepared, because of a
+    /// schema change or a changed binding which could affect the query plan.
+    #[doc(alias = "SQLITE_STMTSTATUS_REPREPARE")]
+    Reprepare = SQLITE_STMTSTATUS_REPREPARE,
+    /// Times the statement has been run to completion or reset.
+    #[doc(alias = "SQLITE_STMTSTATUS_RUN")]
+    Run = SQLITE_STMTSTATUS_RUN,
+    /// Approximate bytes of heap memory used by the statement. (This counter
+    /// can't be reset.)
+    #[doc(alias = "SQLITE_STMTSTATUS_MEMUSED")]
+    MemUsed = SQLITE_STMTSTATUS_MEMUSED,
+}
+
+impl StmtCounter {
+    /// The `SQLITE_STMTSTATUS_*` constant for this counter.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`StmtCounter`] for a `SQLITE_STMTSTATUS_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_STMTSTATUS_FULLSCAN_STEP => Some(Self::FullscanStep),
+            SQLITE_STMTSTATUS_SORT => Some(Self::Sort),
+            SQLITE_STMTSTATUS_AUTOINDEX => Some(Self::AutoIndex),
+            SQLITE_STMTSTATUS_VM_STEP => Some(Self::VmStep),
+            SQLITE_STMTSTATUS_REPREPARE => Some(Self::Reprepare),
+            SQLITE_STMTSTATUS_RUN => Some(Self::Run),
+            SQLITE_STMTSTATUS_MEMUSED => Some(Self::MemUsed),
+            _ => None,
+        }
+    }
+}
+
+/// Read a global [runtime status][status] counter, returning its current and
+/// highwater values. If `reset` is `true`, the highwater value is reset.
+///
+/// Memory counters are only tracked if SQLite was built with memory status
+/// enabled (the default; see [`SQLITE_DEFAULT_MEMSTATUS`][memstatus]).
+///
+/// [status]: https://sqlite.org/c3ref/status.html
+/// [memstatus]: https://sqlite.org/compile.html#default_memstatus
+#[doc(alias = "sqlite3_status64")]
+pub fn status(metric: Status, reset: bool) -> Result<(i64, i64)> {
+    let mut current = 0;
+    let mut highwater = 0;
+
+    let result =
+        unsafe { sqlite3_status64(metric.raw(), &mut current, &mut highwater, reset as i32) };
+
+    match Error::from_code(result) {
+        None => Ok((current, highwater)),
+        Some(err) => Err(err),
+    }
+}
@@ -0,0 +1,121 @@
+use core::{mem, ops::Deref};
+
+use crate::{connection::Connection, error::Result};
+
+impl Connection {
+    /// Begin a (deferred) [transaction][], returning a [`Transaction`] guard
+    /// which rolls it back if dropped before being
+    /// [committed](Transaction::commit).
+    ///
+    /// The connection is borrowed mutably, so that nothing else can end the
+    /// transaction (or begin another) while the guard is alive. Fails if a
+    /// transaction is already open on this connection.
+    ///
+    /// [transaction]: https://sqlite.org/lang_transaction.html
+    pub fn transaction(&mut self) -> Result<Transaction<'_>> {
+        self.execute("BEGIN;", ())?;
+
+        Ok(Transaction {
+            connection: self,
+            drop_behavior: DropBehavior::default(),
+        })
+    }
+}
+
+/// An open [transaction][] on a [`Connection`], begun with
+/// [`Connection::transaction`].
+///
+/// A `Transaction` dereferences to its `Connection`, so statements can be run
+/// on it directly. When dropped without being [committed](Self::commit) or
+/// [rolled back](Self::rollback), the transaction is ended according to its
+/// [drop behavior](Self::set_drop_behavior).
+///
+/// [transaction]: https://sqlite.org/lang_transaction.html
+#[must_use = "a Transaction will be rolled back if dropped"]
+pub struct Transaction<'c> {
+    connection: &'c mut Connection,
+    drop_behavior: DropBehavior,
+}
+
+impl<'c> Transaction<'c> {
+    /// Commit the transaction.
+    ///
+    /// If `COMMIT` fails (e.g., because of a deferred foreign key violation),
+    /// SQLite leaves the transaction open, and it's ended according to the
+    /// guard's [drop behavior](Self::drop_behavior).
+    pub fn commit(self) -> Result<()> {
+        self.finish("COMMIT;")
+    }
+
+    /// Roll back the transaction.
+    pub fn rollback(self) -> Result<()> {
+        self.finish("ROLLBACK;")
+    }
+
+    /// What happens to the transaction if this guard is dropped without being
+    /// committed or rolled back.
+    pub fn drop_behavior(&self) -> DropBehavior {
+        self.drop_behavior
+    }
+
+    /// Change what happens to the transaction if this guard is dropped
+    /// without being committed or rolled back.
+    pub fn set_drop_behavior(&mut self, behavior: DropBehavior) {
+        self.drop_behavior = behavior;
+    }
+
+    /// The [`Connection`] the transaction is open on.
+    pub fn connection(&self) -> &Connection {
+        self.connection
+    }
+
+    fn finish(self, sql: &str) -> Result<()> {
+        self.connection.execute(sql, ())?;
+        mem::forget(self);
+        Ok(())
+    }
+}
+
+impl Deref for Transaction<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Self::Target {
+        self.connection
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        // The transaction may have already been ended by SQL run on the
+        // connection (e.g., a `COMMIT`), or rolled back by SQLite after an
+        // error.
+        if self.connection.in_autocommit() {
+            return;
+        }
+
+        let sql = match self.drop_behavior {
+            DropBehavior::Rollback => "ROLLBACK;",
+            DropBehavior::Commit => "COMMIT;",
+            DropBehavior::Ignore => return,
+        };
+
+        // Errors can't be reported from `drop`; call `commit` or `rollback`
+        // to handle them.
+        let _ = self.connection.execute(sql, ());
+    }
+}
+
+/// What a [`Transaction`] does if it's dropped while still open.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub enum DropBehavior {
+    /// Run `ROLLBACK`.
+    #[default]
+    Rollback,
+    /// Run `COMMIT`. If the commit fails, the transaction is left open.
+    Commit,
+    /// Do nothing, leaving the transaction open on the connection.
+    ///
+    /// SQLite will roll the transaction back when the connection is closed,
+    /// or it can be ended by running `COMMIT` or `ROLLBACK` directly.
+    Ignore,
+}
@@ -0,0 +1,59 @@
+use super::Type;
+
+/// The [type affinity][] of a table column: the type SQLite prefers to
+/// convert values to when they're stored in the column.
+///
+/// For example, storing the text `'42'` in a column with `INTEGER` affinity
+/// stores the integer `42`; comparing the column to `'42'` applies the same
+/// conversion to the text. (Columns of `STRICT` tables are never converted
+/// this way.)
+///
+/// [type affinity]: https://sqlite.org/datatype3.html#type_affinity
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Affinity {
+    /// Numbers are converted to `TEXT` before they're stored.
+    Text,
+    /// Text which looks like a number is stored as an `INTEGER` or `REAL`,
+    /// and a `REAL` which is exactly an integer is stored as an `INTEGER`.
+    Numeric,
+    /// Stores values the same as [`Numeric`](Self::Numeric); the two only
+    /// differ in a `CAST` expression.
+    Integer,
+    /// Like [`Numeric`](Self::Numeric), but integers are stored as `REAL`.
+    Real,
+    /// Values are stored as given, without conversion. (SQLite calls this
+    /// “`BLOB`” affinity; it's also known as “none”.)
+    Blob,
+}
+
+impl Type {
+    /// Determine the [`Affinity`] of a column from its declared type (e.g.,
+    /// `"VARCHAR(255)"`), following [SQLite’s rules][rules]:
+    ///
+    /// 1. If the type contains `INT`, the affinity is [`Integer`](Affinity::Integer).
+    /// 2. If it contains `CHAR`, `CLOB`, or `TEXT`, the affinity is [`Text`](Affinity::Text).
+    /// 3. If it contains `BLOB`, or is empty, the affinity is [`Blob`](Affinity::Blob).
+    /// 4. If it contains `REAL`, `FLOA`, or `DOUB`, the affinity is [`Real`](Affinity::Real).
+    /// 5. Otherwise, the affinity is [`Numeric`](Affinity::Numeric).
+    ///
+    /// Matching ignores case, and the first matching rule wins: so
+    /// `"FLOATING POINT"` has integer affinity, since it contains `INT`.
+    ///
+    /// [rules]: https://sqlite.org/datatype3.html#determination_of_column_affinity
+    pub fn affinity_of(declared_type: &str) -> Affinity {
+        let declared_type = declared_type.to_ascii_uppercase();
+        let contains = |patterns: &[&str]| patterns.iter().any(|p| declared_type.contains(p));
+
+        if contains(&["INT"]) {
+            Affinity::Integer
+        } else if contains(&["CHAR", "CLOB", "TEXT"]) {
+            Affinity::Text
+        } else if contains(&["BLOB"]) || declared_type.trim().is_empty() {
+            Affinity::Blob
+        } else if contains(&["REAL", "FLOA", "DOUB"]) {
+            Affinity::Real
+        } else {
+            Affinity::Numeric
+        }
+    }
+}
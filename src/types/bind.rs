@@ -1,8 +1,8 @@
-use core::{ffi::c_int, num::NonZero};
+use core::{ffi::c_int, num::NonZero, ops::RangeBounds};
 
 use crate::{
     error::{Error, ErrorReason, ParameterError, Result},
-    iter::BindIndexes,
+    iter::{BindIndexes, index_range},
 };
 
 /// A SQLite [prepared statement](crate::Statement) parameter index, used when
@@ -37,6 +37,20 @@ impl BindIndex {
         Self(unsafe { NonZero::new_unchecked(value) })
     }
 
+    /// Iterate over the parameter indexes in `range`, skipping any values
+    /// less than `1`.
+    ///
+    /// ```rust
+    /// # use squire::BindIndex;
+    /// let indexes: Vec<i32> = BindIndex::range(1..=3).map(i32::from).collect();
+    /// assert_eq!(vec![1, 2, 3], indexes);
+    /// ```
+    pub fn range(
+        range: impl RangeBounds<c_int>,
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        index_range(range, 1).map(|value| unsafe { Self::new_unchecked(value) })
+    }
+
     /// Access the underlying parameter index value as a C [`int`](c_int).
     #[inline]
     pub const fn value(&self) -> c_int {
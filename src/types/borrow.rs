@@ -39,6 +39,12 @@ impl<'a, T: ?Sized> Borrowed<'a, T> {
 impl<'a> Borrowed<'a, str> {
     #[inline]
     pub(crate) unsafe fn from_raw_str(data: *const u8, len: i32) -> Self {
+        // SQLite returns a null pointer for empty (and NULL) values, which
+        // `slice::from_raw_parts` does not accept.
+        if data.is_null() {
+            return Self("");
+        }
+
         let bytes = unsafe { slice::from_raw_parts::<'a, u8>(data, len as usize) };
         let text = unsafe { core::str::from_utf8_unchecked(bytes) };
 
@@ -49,10 +55,30 @@ impl<'a> Borrowed<'a, str> {
 impl<'a> Borrowed<'a, [u8]> {
     #[inline]
     pub(crate) unsafe fn from_raw_bytes(data: *const c_void, len: i32) -> Self {
+        // SQLite returns a null pointer for zero-length (and NULL) blobs, which
+        // `slice::from_raw_parts` does not accept.
+        if data.is_null() {
+            return Self(&[]);
+        }
+
         let bytes = unsafe { slice::from_raw_parts::<'a, u8>(data as *const u8, len as usize) };
 
         Self(bytes)
     }
+
+    /// Borrow `len` bytes of text from SQLite, plus the NUL terminator SQLite
+    /// always writes after them.
+    #[inline]
+    pub(crate) unsafe fn from_raw_bytes_with_nul(data: *const u8, len: i32) -> Self {
+        // SQLite returns a null pointer for NULL values (and if out of memory).
+        if data.is_null() {
+            return Self(b"\0");
+        }
+
+        let bytes = unsafe { slice::from_raw_parts::<'a, u8>(data, len as usize + 1) };
+
+        Self(bytes)
+    }
 }
 
 impl<'a, T: ?Sized> Deref for Borrowed<'a, T> {
@@ -0,0 +1,183 @@
+use core::{
+    ffi::{c_char, c_int, c_void},
+    mem, ptr,
+};
+
+use sqlite::{
+    SQLITE_CARRAY_DOUBLE, SQLITE_CARRAY_INT32, SQLITE_CARRAY_INT64, SQLITE_CARRAY_TEXT,
+    SQLITE_STATIC, sqlite3_carray_bind, sqlite3_destructor_type, sqlite3_malloc64,
+};
+#[cfg(feature = "functions")]
+use sqlite::{SQLITE_MISUSE, sqlite3_result_error_code};
+
+#[cfg(feature = "functions")]
+use crate::ffi::ContextRef;
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCategory, ErrorCode, Result},
+    ffi::{self, Statement},
+    types::BindIndex,
+};
+
+/// Binds a slice as an array for SQLite’s [`carray()`][carray] table-valued
+/// function, for use in `IN` clauses.
+///
+/// ```rust
+/// # use squire::{Carray, Connection};
+/// # fn main() -> squire::Result<()> {
+/// # let connection = Connection::open(c":memory:")?;
+/// # connection.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);", ())?;
+/// let ids = [1i64, 2, 3];
+///
+/// let mut query = connection.prepare(
+///     "SELECT name FROM users WHERE id IN (SELECT value FROM carray(?));",
+/// )?;
+/// let names: Vec<String> = query.query(Carray::new(&ids))?.all()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Slices of [`i32`], [`i64`], [`f64`], and `&str` can be bound. Numbers are
+/// read by SQLite directly from the slice, without copying; strings are copied
+/// when the parameter is bound, and must not contain NUL characters.
+///
+/// The `carray()` function is only available if SQLite was compiled with
+/// [`SQLITE_ENABLE_CARRAY`][enable] (e.g., with Squire’s `carray` feature and
+/// a bundled build), which registers it on every connection.
+///
+/// [carray]: https://sqlite.org/carray.html
+/// [enable]: https://sqlite.org/compile.html#enable_carray
+#[cfg_attr(docsrs, doc(cfg(feature = "carray")))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Carray<'a, T: CarrayElement>(&'a [T]);
+
+impl<'a, T: CarrayElement> Carray<'a, T> {
+    /// Bind `values` as a `carray()` array.
+    pub const fn new(values: &'a [T]) -> Self {
+        Self(values)
+    }
+}
+
+impl<'b, 'a: 'b, T: CarrayElement> Bind<'b> for Carray<'a, T> {
+    type Value = Self;
+
+    #[inline]
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self)
+    }
+}
+
+/// [Binds](ffi::Bind) an array via [`sqlite3_carray_bind`].
+impl<'b, 'a: 'b, T: CarrayElement> ffi::Bind<'b> for Carray<'a, T> {
+    unsafe fn bind_parameter<'c>(self, statement: &Statement<'c>, index: BindIndex) -> Result<()>
+    where
+        'c: 'b,
+    {
+        let len = c_int::try_from(self.0.len()).map_err(
+            #[cold]
+            |_| Error::from(ErrorCategory::TooBig),
+        )?;
+        let (data, destructor) = T::carray_data(self.0)?;
+
+        let result = unsafe {
+            sqlite3_carray_bind(
+                statement.as_ptr(),
+                index.value(),
+                data,
+                len,
+                T::CARRAY_TYPE,
+                destructor,
+            )
+        };
+
+        match Error::from_connection(statement, result) {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// An array can't be returned from a function; this sets a
+    /// [misuse](ErrorCategory::Misuse) error instead.
+    #[cfg(feature = "functions")]
+    unsafe fn bind_return<'c>(self, context: &ContextRef<'c>)
+    where
+        'b: 'c,
+    {
+        unsafe { sqlite3_result_error_code(context.as_ptr(), SQLITE_MISUSE) };
+    }
+}
+
+/// A type which can be an element of a [`Carray`].
+///
+/// This trait is sealed, and can't be implemented outside of Squire.
+pub trait CarrayElement: sealed::Sealed {}
+
+impl CarrayElement for i32 {}
+impl CarrayElement for i64 {}
+impl CarrayElement for f64 {}
+impl CarrayElement for &str {}
+
+mod sealed {
+    use super::*;
+
+    pub trait Sealed: Sized {
+        const CARRAY_TYPE: c_int;
+
+        /// The array pointer to bind, and its destructor.
+        fn carray_data(values: &[Self]) -> Result<(*mut c_void, sqlite3_destructor_type)>;
+    }
+
+    macro_rules! number {
+        ($($t:ty => $flag:ident),+) => {
+            $(
+                impl Sealed for $t {
+                    const CARRAY_TYPE: c_int = $flag;
+
+                    fn carray_data(values: &[Self]) -> Result<(*mut c_void, sqlite3_destructor_type)> {
+                        Ok((values.as_ptr().cast_mut().cast(), SQLITE_STATIC))
+                    }
+                }
+            )+
+        };
+    }
+
+    number!(i32 => SQLITE_CARRAY_INT32, i64 => SQLITE_CARRAY_INT64, f64 => SQLITE_CARRAY_DOUBLE);
+
+    impl Sealed for &str {
+        const CARRAY_TYPE: c_int = SQLITE_CARRAY_TEXT;
+
+        /// Copy the strings into a single allocation: an array of `char *`,
+        /// followed by the NUL-terminated strings it points to.
+        fn carray_data(values: &[Self]) -> Result<(*mut c_void, sqlite3_destructor_type)> {
+            let header = values.len() * mem::size_of::<*const c_char>();
+            let mut size = header;
+            for value in values {
+                if value.contains('\0') {
+                    return Err(Error::with_detail(
+                        ErrorCode::SQUIRE_PARAMETER_BIND,
+                        "carray text must not contain NUL characters",
+                    ));
+                }
+                size += value.len() + 1;
+            }
+
+            let data = unsafe { sqlite3_malloc64(size.max(1) as u64) };
+            if data.is_null() {
+                return Err(Error::from(ErrorCategory::OutOfMemory));
+            }
+
+            let pointers = data.cast::<*const c_char>();
+            let mut text = unsafe { data.cast::<u8>().add(header) };
+            for (i, value) in values.iter().enumerate() {
+                unsafe {
+                    pointers.add(i).write(text.cast());
+                    ptr::copy_nonoverlapping(value.as_ptr(), text, value.len());
+                    text.add(value.len()).write(0);
+                    text = text.add(value.len() + 1);
+                }
+            }
+
+            Ok((data, sqlite3_destructor_type::free()))
+        }
+    }
+}
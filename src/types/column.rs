@@ -1,6 +1,9 @@
-use core::ffi::c_int;
+use core::{ffi::c_int, ops::RangeBounds};
 
-use crate::error::{Error, ErrorCategory, Result};
+use crate::{
+    error::{Error, ErrorCategory, Result},
+    iter::index_range,
+};
 
 /// A SQLite column index, used for [reading values][] out of queried rows.
 ///
@@ -23,6 +26,19 @@ impl ColumnIndex {
         Self(value)
     }
 
+    /// Iterate over the column indexes in `range`, skipping any negative values.
+    ///
+    /// ```rust
+    /// # use squire::ColumnIndex;
+    /// let indexes: Vec<ColumnIndex> = ColumnIndex::range(0..3).collect();
+    /// assert_eq!(vec![ColumnIndex::new(0), ColumnIndex::new(1), ColumnIndex::new(2)], indexes);
+    /// ```
+    pub fn range(
+        range: impl RangeBounds<c_int>,
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        index_range(range, 0).map(Self)
+    }
+
     /// Access the underlying SQLite column index as a C [`int`](c_int).
     #[inline]
     pub const fn value(&self) -> c_int {
@@ -0,0 +1,86 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCode},
+    fetch::Fetch,
+    types::Borrowed,
+};
+
+/// A [`Decimal`] stored in SQLite as an integer count of `10^-SCALE` units.
+///
+/// For example, a `ScaledDecimal<2>` stores `12.34` as the integer `1234`.
+/// This allows SQLite to compare and sum values numerically, at the cost of a
+/// fixed precision.
+///
+/// Binding a value with more than `SCALE` fractional digits, or whose scaled
+/// value doesn't fit in an [`i64`], returns a
+/// [range error](crate::ParameterError::Range).
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct ScaledDecimal<const SCALE: u32>(pub Decimal);
+
+/// [`Decimal`] values are bound as TEXT, in [normalized](Decimal::normalize)
+/// form (without trailing zeros), so that equal values are stored identically.
+///
+/// Use [`ScaledDecimal`] to store decimals as integers instead.
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl Bind<'_> for Decimal {
+    type Value = String;
+
+    fn into_bind_value(self) -> crate::Result<Self::Value> {
+        Ok(self.normalize().to_string())
+    }
+}
+
+/// [`Decimal`] values are parsed exactly; a column with more digits than a
+/// `Decimal` can represent fails with an
+/// [`IntegrationError::Decimal`](crate::IntegrationError::Decimal).
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl<'r> Fetch<'r> for Decimal {
+    type Value = Borrowed<'r, str>;
+
+    fn from_value(value: Self::Value) -> crate::Result<Self> {
+        Decimal::from_str_exact(&value).map_err(Error::from_fetch)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl<const SCALE: u32> Bind<'_> for ScaledDecimal<SCALE> {
+    type Value = i64;
+
+    fn into_bind_value(self) -> crate::Result<Self::Value> {
+        const { assert!(SCALE <= Decimal::MAX_SCALE) };
+
+        let mut scaled = self.0;
+        scaled.rescale(SCALE);
+
+        if scaled != self.0 {
+            return Err(Error::with_detail(
+                ErrorCode::SQUIRE_PARAMETER_RANGE,
+                format!("{} has more than {SCALE} fractional digits", self.0),
+            ));
+        }
+
+        i64::try_from(scaled.mantissa()).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RANGE,
+                    "ScaledDecimal cannot fit in i64 parameter",
+                )
+            },
+        )
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "decimal")))]
+impl<const SCALE: u32> Fetch<'_> for ScaledDecimal<SCALE> {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> crate::Result<Self> {
+        Decimal::try_new(value, SCALE)
+            .map(Self)
+            .map_err(Error::from_fetch)
+    }
+}
@@ -1,4 +1,4 @@
-use jiff::{SignedDuration, Span, Timestamp, Zoned, civil};
+use jiff::{SignedDuration, Span, Timestamp, Zoned, civil, tz::TimeZone};
 
 use crate::{bind::Bind, error::Error, fetch::Fetch, types::Borrowed};
 
@@ -110,6 +110,55 @@ impl Fetch<'_> for Timestamp {
     }
 }
 
+/// A [`Timestamp`] stored in SQLite as UTC text, in the `YYYY-MM-DD HH:MM:SS`
+/// format written by [`CURRENT_TIMESTAMP`][current] and SQLite’s
+/// [date and time functions][functions].
+///
+/// A bare `Timestamp` is stored as an integer count of nanoseconds. Use
+/// `SqlText` for columns with a `DEFAULT CURRENT_TIMESTAMP`, or which are
+/// compared against `datetime('now')`.
+///
+/// Fractional seconds are only written if they're nonzero. When fetched, the
+/// `T`-separated [RFC 3339][rfc] form (e.g., `2024-06-15T14:30:00Z`) is also
+/// accepted; text without a UTC offset is read as UTC.
+///
+/// [current]: https://sqlite.org/lang_createtable.html#the_default_clause
+/// [functions]: https://sqlite.org/lang_datefunc.html
+/// [rfc]: https://datatracker.ietf.org/doc/html/rfc3339
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct SqlText<T = Timestamp>(pub T);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl Bind<'_> for SqlText<Timestamp> {
+    type Value = String;
+
+    fn into_bind_value(self) -> crate::Result<Self::Value> {
+        Ok(self
+            .0
+            .to_zoned(TimeZone::UTC)
+            .strftime("%Y-%m-%d %H:%M:%S%.f")
+            .to_string())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl<'b> Fetch<'b> for SqlText<Timestamp> {
+    type Value = Borrowed<'b, str>;
+
+    fn from_value(value: Self::Value) -> crate::Result<Self> {
+        if let Ok(timestamp) = value.parse::<Timestamp>() {
+            return Ok(Self(timestamp));
+        }
+
+        value
+            .parse::<civil::DateTime>()
+            .and_then(|datetime| datetime.to_zoned(TimeZone::UTC))
+            .map(|zoned| Self(zoned.timestamp()))
+            .map_err(Error::from_fetch)
+    }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
 impl Bind<'_> for Zoned {
     type Value = String;
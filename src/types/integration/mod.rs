@@ -1,11 +1,29 @@
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "decimal")]
+mod decimal;
+
 #[cfg(feature = "jiff")]
 mod jiff;
 
+#[cfg(feature = "std")]
+mod net;
+
+#[cfg(feature = "std-time")]
+mod time;
+
 #[cfg(feature = "url")]
 mod url;
 
 #[cfg(feature = "uuid")]
 mod uuid;
+
+#[cfg(feature = "decimal")]
+pub use decimal::ScaledDecimal;
+#[cfg(feature = "jiff")]
+pub use jiff::SqlText;
+#[cfg(feature = "std")]
+pub use net::PackedIp;
+#[cfg(feature = "std-time")]
+pub use time::{Micros, Millis, Secs, UnixMillis, UnixSeconds};
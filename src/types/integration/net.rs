@@ -0,0 +1,127 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCode, Result},
+    fetch::Fetch,
+    types::Borrowed,
+};
+
+/// An IP address stored in SQLite as a compact BLOB: 4 bytes for an IPv4
+/// address, or 16 bytes for IPv6.
+///
+/// Packed addresses of the same family sort numerically. A bare [`IpAddr`],
+/// [`Ipv4Addr`], or [`Ipv6Addr`] is instead stored as text (e.g.,
+/// `"192.0.2.1"`).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct PackedIp<T = IpAddr>(pub T);
+
+/// Implement [`Bind`] and [`Fetch`] for a [`std::net`] type via its canonical
+/// text representation.
+macro_rules! text {
+    ($($t:ty => $name:literal),+ $(,)?) => {
+        $(
+            impl Bind<'_> for $t {
+                type Value = String;
+
+                fn into_bind_value(self) -> Result<Self::Value> {
+                    Ok(self.to_string())
+                }
+            }
+
+            impl<'b> Fetch<'b> for $t {
+                type Value = Borrowed<'b, str>;
+
+                fn from_value(value: Self::Value) -> Result<Self> {
+                    value.parse().map_err(
+                        #[cold]
+                        |err| {
+                            Error::with_detail(
+                                ErrorCode::SQUIRE_FETCH_PARSE,
+                                format!("invalid {}: {err}", $name),
+                            )
+                        },
+                    )
+                }
+            }
+        )+
+    };
+}
+
+text!(
+    IpAddr => "IP address",
+    Ipv4Addr => "IPv4 address",
+    Ipv6Addr => "IPv6 address",
+    SocketAddr => "socket address",
+);
+
+impl Bind<'_> for PackedIp<Ipv4Addr> {
+    type Value = [u8; 4];
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self.0.octets())
+    }
+}
+
+impl<'b> Fetch<'b> for PackedIp<Ipv4Addr> {
+    type Value = Borrowed<'b, [u8]>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        match <[u8; 4]>::try_from(&*value) {
+            Ok(octets) => Ok(Self(Ipv4Addr::from(octets))),
+            Err(_) => Err(packed_length_error("4", value.len())),
+        }
+    }
+}
+
+impl Bind<'_> for PackedIp<Ipv6Addr> {
+    type Value = [u8; 16];
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self.0.octets())
+    }
+}
+
+impl<'b> Fetch<'b> for PackedIp<Ipv6Addr> {
+    type Value = Borrowed<'b, [u8]>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        match <[u8; 16]>::try_from(&*value) {
+            Ok(octets) => Ok(Self(Ipv6Addr::from(octets))),
+            Err(_) => Err(packed_length_error("16", value.len())),
+        }
+    }
+}
+
+impl Bind<'_> for PackedIp<IpAddr> {
+    type Value = Vec<u8>;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(match self.0 {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        })
+    }
+}
+
+impl<'b> Fetch<'b> for PackedIp<IpAddr> {
+    type Value = Borrowed<'b, [u8]>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        if let Ok(octets) = <[u8; 4]>::try_from(&*value) {
+            Ok(Self(IpAddr::from(octets)))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(&*value) {
+            Ok(Self(IpAddr::from(octets)))
+        } else {
+            Err(packed_length_error("4 or 16", value.len()))
+        }
+    }
+}
+
+#[cold]
+fn packed_length_error(expected: &str, len: usize) -> Error {
+    Error::with_detail(
+        ErrorCode::SQUIRE_FETCH_PARSE,
+        format!("invalid packed IP address: expected {expected} bytes, got {len}"),
+    )
+}
@@ -0,0 +1,219 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCode, Result},
+    fetch::Fetch,
+};
+
+/// A [`SystemTime`] stored in SQLite as an integer count of seconds since the
+/// [Unix epoch](UNIX_EPOCH).
+///
+/// When fetched, fractional seconds are truncated toward the past.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct UnixSeconds<T = SystemTime>(pub T);
+
+/// A [`SystemTime`] stored in SQLite as an integer count of milliseconds since
+/// the [Unix epoch](UNIX_EPOCH).
+///
+/// This is the same representation used to bind and fetch a bare `SystemTime`.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct UnixMillis<T = SystemTime>(pub T);
+
+/// A [`Duration`] stored in SQLite as an integer count of whole seconds.
+///
+/// When bound, fractional seconds are truncated.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Secs(pub Duration);
+
+/// A [`Duration`] stored in SQLite as an integer count of whole milliseconds.
+///
+/// When bound, fractional milliseconds are truncated.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Millis(pub Duration);
+
+/// A [`Duration`] stored in SQLite as an integer count of whole microseconds.
+///
+/// When bound, fractional microseconds are truncated.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Micros(pub Duration);
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+
+/// [`SystemTime`] values are bound as an integer count of milliseconds since
+/// the [Unix epoch](UNIX_EPOCH). Use [`UnixSeconds`] to store seconds instead.
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Bind<'_> for SystemTime {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        to_unix(self, NANOS_PER_MILLI)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Fetch<'_> for SystemTime {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        from_unix(value, Duration::from_millis)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Bind<'_> for UnixSeconds<SystemTime> {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        to_unix(self.0, NANOS_PER_SECOND)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Fetch<'_> for UnixSeconds<SystemTime> {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        from_unix(value, Duration::from_secs).map(Self)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Bind<'_> for UnixMillis<SystemTime> {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        self.0.into_bind_value()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Fetch<'_> for UnixMillis<SystemTime> {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        SystemTime::from_value(value).map(Self)
+    }
+}
+
+/// [`Duration`] values are bound as an integer count of nanoseconds.
+///
+/// Durations longer than [`i64::MAX`] nanoseconds (about 292 years) can't be
+/// bound, and return a [range error](crate::ParameterError::Range).
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Bind<'_> for Duration {
+    type Value = i64;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        i64::try_from(self.as_nanos()).map_err(
+            #[cold]
+            |_| {
+                Error::with_detail(
+                    ErrorCode::SQUIRE_PARAMETER_RANGE,
+                    "Duration cannot fit in i64 nanoseconds",
+                )
+            },
+        )
+    }
+}
+
+/// Negative column values can't be fetched as a [`Duration`], and return a
+/// [range error](crate::FetchError::Range).
+#[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+impl Fetch<'_> for Duration {
+    type Value = i64;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        match u64::try_from(value) {
+            Ok(nanos) => Ok(Duration::from_nanos(nanos)),
+            Err(_) => Err(Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_RANGE,
+                "negative value cannot be a Duration",
+            )),
+        }
+    }
+}
+
+/// Implement [`Bind`] and [`Fetch`] for a [`Duration`] wrapper which stores
+/// a count of some unit.
+macro_rules! duration_unit {
+    ($t:ident, $as_unit:ident, $from_unit:ident, $unit:literal) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+        impl Bind<'_> for $t {
+            type Value = i64;
+
+            fn into_bind_value(self) -> Result<Self::Value> {
+                i64::try_from(self.0.$as_unit()).map_err(
+                    #[cold]
+                    |_| {
+                        Error::with_detail(
+                            ErrorCode::SQUIRE_PARAMETER_RANGE,
+                            concat!("Duration cannot fit in i64 ", $unit),
+                        )
+                    },
+                )
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "std-time")))]
+        impl Fetch<'_> for $t {
+            type Value = i64;
+
+            fn from_value(value: Self::Value) -> Result<Self> {
+                match u64::try_from(value) {
+                    Ok(count) => Ok(Self(Duration::$from_unit(count))),
+                    Err(_) => Err(Error::with_detail(
+                        ErrorCode::SQUIRE_FETCH_RANGE,
+                        "negative value cannot be a Duration",
+                    )),
+                }
+            }
+        }
+    };
+}
+
+duration_unit!(Secs, as_secs, from_secs, "seconds");
+duration_unit!(Millis, as_millis, from_millis, "milliseconds");
+duration_unit!(Micros, as_micros, from_micros, "microseconds");
+
+fn to_unix(time: SystemTime, nanos_per_unit: i128) -> Result<i64> {
+    let nanos = match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_nanos() as i128,
+        Err(before) => -(before.duration().as_nanos() as i128),
+    };
+
+    i64::try_from(nanos.div_euclid(nanos_per_unit)).map_err(
+        #[cold]
+        |_| {
+            Error::with_detail(
+                ErrorCode::SQUIRE_PARAMETER_RANGE,
+                "SystemTime cannot fit in i64 parameter",
+            )
+        },
+    )
+}
+
+fn from_unix(value: i64, unit: fn(u64) -> Duration) -> Result<SystemTime> {
+    let offset = unit(value.unsigned_abs());
+    let time = if value >= 0 {
+        UNIX_EPOCH.checked_add(offset)
+    } else {
+        UNIX_EPOCH.checked_sub(offset)
+    };
+
+    time.ok_or_else(
+        #[cold]
+        || {
+            Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_RANGE,
+                "value out of SystemTime range",
+            )
+        },
+    )
+}
@@ -45,6 +45,43 @@ where
     }
 }
 
+/// A dynamic [JSON value](json::Value) is bound as TEXT.
+///
+/// Use [`Json`] to bind a value of a concrete [`Serialize`] type.
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+impl<'b> Bind<'b> for &json::Value {
+    type Value = String;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        json::to_string(self).map_err(Error::from_bind)
+    }
+}
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+impl<'b> Bind<'b> for json::Value {
+    type Value = String;
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        (&self).into_bind_value()
+    }
+}
+
+/// A dynamic [JSON value](json::Value) is parsed from a TEXT column; invalid
+/// JSON fails with an [`IntegrationError::Json`](crate::IntegrationError::Json).
+///
+/// Use [`Json`] to fetch a value of a concrete [`Deserialize`] type.
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+impl<'r> Fetch<'r> for json::Value {
+    type Value = Borrowed<'r, str>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        json::from_str(value.into_inner()).map_err(Error::from_fetch)
+    }
+}
+
 /// A value which is stored in SQLite [serialized](Serialize) as [JSONB][jsonb].
 ///
 /// # ⚠️ Warning
@@ -0,0 +1,117 @@
+use core::fmt;
+
+use sqlite::{
+    SQLITE_LIMIT_ATTACHED, SQLITE_LIMIT_COLUMN, SQLITE_LIMIT_COMPOUND_SELECT,
+    SQLITE_LIMIT_EXPR_DEPTH, SQLITE_LIMIT_FUNCTION_ARG, SQLITE_LIMIT_LENGTH,
+    SQLITE_LIMIT_LIKE_PATTERN_LENGTH, SQLITE_LIMIT_SQL_LENGTH, SQLITE_LIMIT_TRIGGER_DEPTH,
+    SQLITE_LIMIT_VARIABLE_NUMBER, SQLITE_LIMIT_VDBE_OP, SQLITE_LIMIT_WORKER_THREADS,
+};
+
+/// A [run-time limit][limit] on the size of a SQLite construct, which can be
+/// [lowered](crate::Connection::set_limit) for a [`Connection`](crate::Connection).
+///
+/// [limit]: https://sqlite.org/c3ref/c_limit_attached.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum Limit {
+    /// The maximum size of any string, blob, or table row, in bytes.
+    #[doc(alias = "SQLITE_LIMIT_LENGTH")]
+    Length = SQLITE_LIMIT_LENGTH,
+    /// The maximum length of a SQL statement, in bytes.
+    #[doc(alias = "SQLITE_LIMIT_SQL_LENGTH")]
+    SqlLength = SQLITE_LIMIT_SQL_LENGTH,
+    /// The maximum number of columns in a table, index, or view, or in the
+    /// result set of a `SELECT`.
+    #[doc(alias = "SQLITE_LIMIT_COLUMN")]
+    Column = SQLITE_LIMIT_COLUMN,
+    /// The maximum depth of the parse tree of any expression.
+    #[doc(alias = "SQLITE_LIMIT_EXPR_DEPTH")]
+    ExprDepth = SQLITE_LIMIT_EXPR_DEPTH,
+    /// The maximum number of terms in a compound `SELECT`.
+    #[doc(alias = "SQLITE_LIMIT_COMPOUND_SELECT")]
+    CompoundSelect = SQLITE_LIMIT_COMPOUND_SELECT,
+    /// The maximum number of virtual machine instructions in a prepared
+    /// statement.
+    #[doc(alias = "SQLITE_LIMIT_VDBE_OP")]
+    VdbeOp = SQLITE_LIMIT_VDBE_OP,
+    /// The maximum number of arguments to a function.
+    #[doc(alias = "SQLITE_LIMIT_FUNCTION_ARG")]
+    FunctionArg = SQLITE_LIMIT_FUNCTION_ARG,
+    /// The maximum number of [attached](https://sqlite.org/lang_attach.html)
+    /// databases.
+    #[doc(alias = "SQLITE_LIMIT_ATTACHED")]
+    Attached = SQLITE_LIMIT_ATTACHED,
+    /// The maximum length of the pattern argument to `LIKE` or `GLOB`.
+    #[doc(alias = "SQLITE_LIMIT_LIKE_PATTERN_LENGTH")]
+    LikePatternLength = SQLITE_LIMIT_LIKE_PATTERN_LENGTH,
+    /// The maximum index of a statement [parameter](crate::BindIndex).
+    #[doc(alias = "SQLITE_LIMIT_VARIABLE_NUMBER")]
+    VariableNumber = SQLITE_LIMIT_VARIABLE_NUMBER,
+    /// The maximum depth of recursion for triggers.
+    #[doc(alias = "SQLITE_LIMIT_TRIGGER_DEPTH")]
+    TriggerDepth = SQLITE_LIMIT_TRIGGER_DEPTH,
+    /// The maximum number of auxiliary worker threads a prepared statement
+    /// may start.
+    #[doc(alias = "SQLITE_LIMIT_WORKER_THREADS")]
+    WorkerThreads = SQLITE_LIMIT_WORKER_THREADS,
+}
+
+impl Limit {
+    const ALL: [Self; 12] = [
+        Self::Length,
+        Self::SqlLength,
+        Self::Column,
+        Self::ExprDepth,
+        Self::CompoundSelect,
+        Self::VdbeOp,
+        Self::FunctionArg,
+        Self::Attached,
+        Self::LikePatternLength,
+        Self::VariableNumber,
+        Self::TriggerDepth,
+        Self::WorkerThreads,
+    ];
+
+    /// The `SQLITE_LIMIT_*` constant for this limit.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`Limit`] for a `SQLITE_LIMIT_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        if value >= 0 && (value as usize) < Self::ALL.len() {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The name of the `SQLITE_LIMIT_*` constant for this limit.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Length => "SQLITE_LIMIT_LENGTH",
+            Self::SqlLength => "SQLITE_LIMIT_SQL_LENGTH",
+            Self::Column => "SQLITE_LIMIT_COLUMN",
+            Self::ExprDepth => "SQLITE_LIMIT_EXPR_DEPTH",
+            Self::CompoundSelect => "SQLITE_LIMIT_COMPOUND_SELECT",
+            Self::VdbeOp => "SQLITE_LIMIT_VDBE_OP",
+            Self::FunctionArg => "SQLITE_LIMIT_FUNCTION_ARG",
+            Self::Attached => "SQLITE_LIMIT_ATTACHED",
+            Self::LikePatternLength => "SQLITE_LIMIT_LIKE_PATTERN_LENGTH",
+            Self::VariableNumber => "SQLITE_LIMIT_VARIABLE_NUMBER",
+            Self::TriggerDepth => "SQLITE_LIMIT_TRIGGER_DEPTH",
+            Self::WorkerThreads => "SQLITE_LIMIT_WORKER_THREADS",
+        }
+    }
+
+    /// Find the [`Limit`] whose `SQLITE_LIMIT_*` constant is named `name`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|limit| limit.as_str() == name)
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,23 +1,51 @@
+mod affinity;
 mod bind;
 mod borrow;
+#[cfg(sqlite_has_carray)]
+mod carray;
 mod column;
 #[cfg(feature = "functions")]
 mod func;
 mod integration;
 #[cfg(all(any(feature = "json", feature = "jsonb"), feature = "serde"))]
 mod json;
+mod limit;
+mod null;
 mod row_id;
+#[cfg(sqlite_has_serialize)]
+mod serialize;
+mod strict_bool;
 mod text;
+mod txn_state;
 mod value;
+mod wide;
 
+pub use affinity::Affinity;
 pub use bind::BindIndex;
 pub use borrow::Borrowed;
+#[cfg(sqlite_has_carray)]
+pub use carray::{Carray, CarrayElement};
 pub use column::ColumnIndex;
 #[cfg(feature = "functions")]
 pub use func::FunctionOptions;
+#[cfg(feature = "std")]
+pub use integration::PackedIp;
+#[cfg(feature = "decimal")]
+pub use integration::ScaledDecimal;
+#[cfg(feature = "jiff")]
+pub use integration::SqlText;
+#[cfg(feature = "std-time")]
+pub use integration::{Micros, Millis, Secs, UnixMillis, UnixSeconds};
+pub use limit::Limit;
+pub use null::Null;
 pub use row_id::RowId;
+#[cfg(sqlite_has_serialize)]
+pub use serialize::DeserializeFlags;
+pub use strict_bool::StrictBool;
 pub use text::Encoding;
-pub use value::Type;
+pub use txn_state::TxnState;
+pub use value::{Type, Value};
+pub use wide::Wide;
 
 #[cfg(all(feature = "json", feature = "serde"))]
 pub use json::Json;
@@ -0,0 +1,14 @@
+/// Binds SQL `NULL`, without the type annotation a bare [`None`] needs.
+///
+/// ```rust
+/// # use squire::{Connection, Null};
+/// # fn main() -> squire::Result<()> {
+/// # let connection = Connection::open(c":memory:")?;
+/// # connection.execute("CREATE TABLE users (username TEXT, email TEXT);", ())?;
+/// let mut add_user = connection.prepare("INSERT INTO users (username, email) VALUES (?, ?);")?;
+/// add_user.execute(("bob", Null))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy, Debug)]
+pub struct Null;
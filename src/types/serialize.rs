@@ -0,0 +1,51 @@
+use sqlite::{SQLITE_DESERIALIZE_READONLY, SQLITE_DESERIALIZE_RESIZEABLE};
+
+/// Options for [deserializing](crate::Connection::deserialize) a database.
+///
+/// (See the [SQLite reference][deserialize] for details.)
+///
+/// [deserialize]: https://sqlite.org/c3ref/deserialize.html
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+#[repr(transparent)]
+pub struct DeserializeFlags(i32);
+
+impl DeserializeFlags {
+    const READ_ONLY: i32 = SQLITE_DESERIALIZE_READONLY;
+    const RESIZABLE: i32 = SQLITE_DESERIALIZE_RESIZEABLE;
+
+    /// Deserialize a writable database whose size is fixed to the size of the
+    /// deserialized data.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Use `flags`, a combination of `SQLITE_DESERIALIZE_*` constants.
+    pub const fn from_raw(flags: i32) -> Self {
+        Self(flags)
+    }
+
+    /// The `SQLITE_DESERIALIZE_*` constants set in these flags.
+    pub const fn raw(self) -> i32 {
+        self.0
+    }
+
+    /// Treat the deserialized database as read-only.
+    #[doc(alias = "SQLITE_DESERIALIZE_READONLY")]
+    pub const fn read_only(self, value: bool) -> Self {
+        self.set(Self::READ_ONLY, value)
+    }
+
+    /// Allow SQLite to grow the deserialized database as it is written to.
+    #[doc(alias = "SQLITE_DESERIALIZE_RESIZEABLE")]
+    pub const fn resizable(self, value: bool) -> Self {
+        self.set(Self::RESIZABLE, value)
+    }
+
+    const fn set(self, bit: i32, value: bool) -> Self {
+        if value {
+            Self(self.0 | bit)
+        } else {
+            Self(self.0 & !bit)
+        }
+    }
+}
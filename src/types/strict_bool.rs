@@ -0,0 +1,30 @@
+/// A [`bool`] which must be stored as exactly `0` or `1`.
+///
+/// A bare `bool` is fetched leniently: any nonzero integer is `true`.
+/// Fetching a `StrictBool` from any integer other than `0` or `1` instead
+/// returns a [range error](crate::FetchError::Range), to catch columns which
+/// hold something other than a boolean.
+///
+/// Both are bound as `1` (for `true`) or `0` (for `false`).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct StrictBool(pub bool);
+
+impl StrictBool {
+    /// Unwraps the `StrictBool` wrapper, returning the inner [`bool`].
+    pub const fn into_inner(self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for StrictBool {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StrictBool> for bool {
+    fn from(value: StrictBool) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,38 @@
+use sqlite::{SQLITE_TXN_NONE, SQLITE_TXN_READ, SQLITE_TXN_WRITE};
+
+/// The [transaction state][state] of a database, as reported by
+/// [`Connection::txn_state`](crate::Connection::txn_state).
+///
+/// [state]: https://sqlite.org/c3ref/txn_state.html
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+#[repr(i32)]
+pub enum TxnState {
+    /// No transaction is open.
+    #[default]
+    #[doc(alias = "SQLITE_TXN_NONE")]
+    None = SQLITE_TXN_NONE,
+    /// A read transaction is open; the database has been read, but not
+    /// changed.
+    #[doc(alias = "SQLITE_TXN_READ")]
+    Read = SQLITE_TXN_READ,
+    /// A write transaction is open; the database may have been changed.
+    #[doc(alias = "SQLITE_TXN_WRITE")]
+    Write = SQLITE_TXN_WRITE,
+}
+
+impl TxnState {
+    /// The `SQLITE_TXN_*` constant for this state.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`TxnState`] for a `SQLITE_TXN_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_TXN_NONE => Some(Self::None),
+            SQLITE_TXN_READ => Some(Self::Read),
+            SQLITE_TXN_WRITE => Some(Self::Write),
+            _ => None,
+        }
+    }
+}
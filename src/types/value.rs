@@ -1,3 +1,5 @@
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+
 #[cfg(feature = "value")]
 use sqlite::sqlite3_value_type;
 use sqlite::{
@@ -5,9 +7,14 @@ use sqlite::{
 };
 
 use super::ColumnIndex;
-use crate::ffi::Statement;
 #[cfg(feature = "value")]
 use crate::ffi::ValueRef;
+use crate::{
+    error::{Error, ErrorCode, Result},
+    fetch::check_fetched,
+    ffi::{self, Fetch as _, Statement},
+    types::Borrowed,
+};
 
 /// The datatype of a SQLite column value.
 ///
@@ -80,3 +87,79 @@ impl Type {
         Self::from_code(code)
     }
 }
+
+/// An owned SQLite value of any [type](Type).
+///
+/// `Value` is useful when the columns of a query aren’t known ahead of time.
+/// A whole row can be fetched as a `Vec<Value>` with
+/// [`Row::to_values`](crate::Row::to_values), or by querying for
+/// `Vec<Value>` [`Columns`](crate::Columns).
+#[derive(PartialEq, Clone, Debug)]
+pub enum Value {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl Value {
+    /// The [`Type`] of this value.
+    pub const fn value_type(&self) -> Type {
+        match self {
+            Self::Null => Type::Null,
+            Self::Integer(_) => Type::Integer,
+            Self::Real(_) => Type::Float,
+            Self::Text(_) => Type::Text,
+            Self::Blob(_) => Type::Blob,
+        }
+    }
+
+    /// `true` if this value is [`NULL`](Self::Null); `false` otherwise.
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Fetch a column value, keeping its stored [`Type`].
+    ///
+    /// `TEXT` values which aren't valid UTF-8 return a
+    /// [parse error](crate::FetchError::Parse).
+    ///
+    /// # Safety
+    ///
+    /// The column index must be valid for the statement.
+    pub(crate) unsafe fn fetch_column<'r, 'c>(
+        statement: &'r Statement<'c>,
+        column: ColumnIndex,
+    ) -> Result<Self>
+    where
+        'c: 'r,
+    {
+        let value = match unsafe { Type::fetch_column(statement, column) } {
+            Type::Null => Self::Null,
+            Type::Integer => Self::Integer(unsafe { i64::fetch_column(statement, column) }),
+            Type::Float => Self::Real(unsafe { f64::fetch_column(statement, column) }),
+            Type::Text => {
+                let text = unsafe { ffi::fetch_column_text(statement, column) };
+                check_fetched(statement, column, text.len())?;
+                let text = str::from_utf8(text.into_inner()).map_err(
+                    #[cold]
+                    |err| {
+                        Error::with_detail(
+                            ErrorCode::SQUIRE_FETCH_PARSE,
+                            format!("invalid UTF-8 in column {}: {err}", column.value()),
+                        )
+                    },
+                )?;
+                Self::Text(text.to_owned())
+            }
+            Type::Blob => {
+                let blob = unsafe { Borrowed::<[u8]>::fetch_column(statement, column) };
+                check_fetched(statement, column, blob.len())?;
+                Self::Blob(blob.to_vec())
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::format;
+
+use crate::{
+    bind::Bind,
+    error::{Error, ErrorCode, Result},
+    fetch::Fetch,
+    types::Borrowed,
+};
+
+/// Binds and fetches a 128-bit integer as a 16-byte BLOB, to store values
+/// that don't fit in SQLite’s 64-bit `INTEGER`.
+///
+/// A bare [`i128`] or [`u128`] is bound as an `INTEGER`, and fails with a
+/// [range error](crate::ParameterError::Range) if it overflows an `i64`.
+/// `Wide` stores the full range instead.
+///
+/// Values are stored big-endian (with the sign bit of an `i128` flipped), so
+/// that SQLite sorts the BLOBs in the same order as the integers they hold.
+/// Fetching a `Wide` from anything other than a 16-byte BLOB returns a
+/// [range error](crate::FetchError::Range).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy, Debug)]
+pub struct Wide<T>(pub T);
+
+impl Bind<'_> for Wide<u128> {
+    type Value = [u8; 16];
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(self.0.to_be_bytes())
+    }
+}
+
+impl<'r> Fetch<'r> for Wide<u128> {
+    type Value = Borrowed<'r, [u8]>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        from_blob(value.into_inner()).map(|bytes| Self(u128::from_be_bytes(bytes)))
+    }
+}
+
+impl Bind<'_> for Wide<i128> {
+    type Value = [u8; 16];
+
+    fn into_bind_value(self) -> Result<Self::Value> {
+        Ok(((self.0 as u128) ^ SIGN).to_be_bytes())
+    }
+}
+
+impl<'r> Fetch<'r> for Wide<i128> {
+    type Value = Borrowed<'r, [u8]>;
+
+    fn from_value(value: Self::Value) -> Result<Self> {
+        from_blob(value.into_inner()).map(|bytes| Self((u128::from_be_bytes(bytes) ^ SIGN) as i128))
+    }
+}
+
+const SIGN: u128 = 1 << 127;
+
+fn from_blob(bytes: &[u8]) -> Result<[u8; 16]> {
+    <[u8; 16]>::try_from(bytes).map_err(
+        #[cold]
+        |_| {
+            Error::with_detail(
+                ErrorCode::SQUIRE_FETCH_RANGE,
+                format!("expected a 16-byte blob, got {} bytes", bytes.len()),
+            )
+        },
+    )
+}
@@ -0,0 +1,90 @@
+use core::ffi::CStr;
+
+use sqlite::{
+    SQLITE_MUTEX_STATIC_MAIN, sqlite3_mutex_alloc, sqlite3_mutex_enter, sqlite3_mutex_leave,
+    sqlite3_vfs, sqlite3_vfs_find,
+};
+
+use crate::error::{Error, ErrorCode, Result};
+
+mod shim;
+
+pub use shim::{LockLevel, Vfs, VfsFile, register_vfs};
+
+/// List the names of every registered [virtual filesystem][vfs], starting
+/// with the [default](default_vfs).
+///
+/// ```rust
+/// let names = squire::vfs_names();
+/// assert_eq!(squire::default_vfs(), names.first().copied());
+/// ```
+///
+/// VFS names which aren't valid UTF-8 are skipped. The list is read while
+/// holding SQLite's `SQLITE_MUTEX_STATIC_MAIN` mutex, which also guards
+/// [registering](register_vfs) and unregistering VFSes.
+///
+/// [vfs]: https://sqlite.org/vfs.html
+#[doc(alias = "sqlite3_vfs_find")]
+pub fn vfs_names() -> Vec<&'static str> {
+    let mut names = Vec::new();
+
+    // `sqlite3_vfs_find` takes the (non-recursive) main mutex itself, so the
+    // head of the list is found before locking it for the rest of the walk.
+    let mut vfs = unsafe { sqlite3_vfs_find(core::ptr::null()) };
+    let mutex = unsafe { sqlite3_mutex_alloc(SQLITE_MUTEX_STATIC_MAIN) };
+    unsafe { sqlite3_mutex_enter(mutex) };
+
+    while let Some(current) = unsafe { vfs.as_ref() } {
+        if let Some(name) = unsafe { vfs_name(current) } {
+            names.push(name);
+        }
+        vfs = current.pNext;
+    }
+
+    unsafe { sqlite3_mutex_leave(mutex) };
+    names
+}
+
+/// The name of the default [virtual filesystem][vfs], used by connections
+/// which don't [select](crate::ConnectionBuilder::vfs) one.
+///
+/// Returns `None` if no VFS is registered, or if its name isn't valid UTF-8.
+///
+/// [vfs]: https://sqlite.org/vfs.html
+#[doc(alias = "sqlite3_vfs_find")]
+pub fn default_vfs() -> Option<&'static str> {
+    let vfs = unsafe { sqlite3_vfs_find(core::ptr::null()).as_ref() }?;
+    unsafe { vfs_name(vfs) }
+}
+
+/// Check that a VFS named `name` is registered, so that opening a connection
+/// with it fails early with a [can't open](crate::ErrorCategory::CantOpen)
+/// error instead of a generic one.
+pub(crate) fn check_vfs(name: Option<&CStr>) -> Result<()> {
+    let Some(name) = name else {
+        return Ok(());
+    };
+
+    if unsafe { sqlite3_vfs_find(name.as_ptr()) }.is_null() {
+        Err(Error::with_detail(
+            ErrorCode::CANTOPEN,
+            format!("no such VFS: {}", name.to_string_lossy()),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Read the name of a registered VFS.
+///
+/// # Safety
+///
+/// `vfs` must be registered with SQLite, and its `zName` must outlive the
+/// program (as SQLite requires of every registered VFS).
+unsafe fn vfs_name(vfs: &sqlite3_vfs) -> Option<&'static str> {
+    if vfs.zName.is_null() {
+        return None;
+    }
+
+    unsafe { CStr::from_ptr(vfs.zName) }.to_str().ok()
+}
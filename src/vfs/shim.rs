@@ -0,0 +1,669 @@
+use core::{
+    ffi::{CStr, c_char, c_double, c_int, c_void},
+    mem, ptr, slice,
+};
+use std::{
+    ffi::CString,
+    panic::{AssertUnwindSafe, catch_unwind},
+    thread,
+    time::Duration,
+};
+
+use sqlite::{
+    SQLITE_ACCESS_READWRITE, SQLITE_CANTOPEN, SQLITE_ERROR, SQLITE_IOERR_ACCESS,
+    SQLITE_IOERR_CHECKRESERVEDLOCK, SQLITE_IOERR_CLOSE, SQLITE_IOERR_DELETE, SQLITE_IOERR_FSTAT,
+    SQLITE_IOERR_FSYNC, SQLITE_IOERR_LOCK, SQLITE_IOERR_READ, SQLITE_IOERR_SHORT_READ,
+    SQLITE_IOERR_TRUNCATE, SQLITE_IOERR_UNLOCK, SQLITE_IOERR_WRITE, SQLITE_LOCK_EXCLUSIVE,
+    SQLITE_LOCK_NONE, SQLITE_LOCK_PENDING, SQLITE_LOCK_RESERVED, SQLITE_LOCK_SHARED, SQLITE_MISUSE,
+    SQLITE_NOTFOUND, SQLITE_OK, sqlite3_file, sqlite3_int64, sqlite3_io_methods,
+    sqlite3_syscall_ptr, sqlite3_vfs, sqlite3_vfs_find, sqlite3_vfs_register,
+};
+
+use crate::error::{Error, ErrorCategory, ErrorCode, Result};
+
+/// A [virtual filesystem][vfs] implemented in Rust, which can be
+/// [registered](register_vfs) with SQLite.
+///
+/// SQLite asks the VFS to [open](Self::open) the database file, and any
+/// journal, WAL, or temporary files it needs. A read-only VFS only has to
+/// open the database itself, and report that no other files
+/// [exist](Self::access).
+///
+/// Randomness, sleeping, the current time, and extension loading are
+/// delegated to the VFS which was the default when this one was registered.
+/// A panic in [`open`](Self::open) or [`full_pathname`](Self::full_pathname)
+/// fails with a [can't open](ErrorCategory::CantOpen) error, and in any other
+/// method with an I/O error.
+///
+/// [vfs]: https://sqlite.org/vfs.html
+pub trait Vfs: Send + Sync + 'static {
+    /// The [file](VfsFile) type opened by this VFS.
+    type File: VfsFile;
+
+    /// Open the file `name`, or a temporary file if `name` is `None`.
+    ///
+    /// `flags` are the [`SQLITE_OPEN_*`][flags] flags SQLite is opening the
+    /// file with, which also indicate what the file will be used for (e.g.,
+    /// `SQLITE_OPEN_MAIN_DB` or `SQLITE_OPEN_MAIN_JOURNAL`).
+    ///
+    /// [flags]: https://sqlite.org/c3ref/c_open_autoproxy.html
+    fn open(&self, name: Option<&str>, flags: i32) -> Result<Self::File>;
+
+    /// Delete the file `name`.
+    ///
+    /// The default implementation fails with a
+    /// [read-only](ErrorCategory::ReadOnly) error.
+    fn delete(&self, name: &str) -> Result<()> {
+        let _ = name;
+        Err(ErrorCategory::ReadOnly.into())
+    }
+
+    /// Check whether the file `name` exists, or if `write` is `true`, whether
+    /// it exists and can be written to.
+    fn access(&self, name: &str, write: bool) -> Result<bool>;
+
+    /// Resolve `name` to the canonical path which will be passed to
+    /// [`open`](Self::open).
+    ///
+    /// The default implementation returns `name` unchanged.
+    fn full_pathname(&self, name: &str) -> Result<String> {
+        Ok(name.to_owned())
+    }
+}
+
+/// A file opened by a [`Vfs`].
+///
+/// The methods which modify the file fail with a
+/// [read-only](ErrorCategory::ReadOnly) error by default, and locking is a
+/// no-op, which suits a read-only database that no other process writes to.
+///
+/// SQLite allocates the memory for each open file, and only aligns it to 8
+/// bytes, so a `VfsFile` type may not require a larger alignment. A panic
+/// in any method is reported to SQLite as an I/O error.
+pub trait VfsFile: Send + 'static {
+    /// Read into `buf`, starting at `offset` bytes into the file, returning
+    /// the number of bytes read.
+    ///
+    /// Returning fewer than `buf.len()` bytes indicates that the end of the
+    /// file was reached; the rest of `buf` is filled with zeroes.
+    fn read(&mut self, buf: &mut [u8], offset: u64) -> Result<usize>;
+
+    /// Write `buf` to the file, starting at `offset` bytes into the file.
+    fn write(&mut self, buf: &[u8], offset: u64) -> Result<()> {
+        let _ = (buf, offset);
+        Err(ErrorCategory::ReadOnly.into())
+    }
+
+    /// Truncate the file to `size` bytes.
+    fn truncate(&mut self, size: u64) -> Result<()> {
+        let _ = size;
+        Err(ErrorCategory::ReadOnly.into())
+    }
+
+    /// Flush written data to durable storage.
+    fn sync(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// The size of the file, in bytes.
+    fn size(&self) -> Result<u64>;
+
+    /// Raise the file's lock to `level`.
+    fn lock(&mut self, level: LockLevel) -> Result<()> {
+        let _ = level;
+        Ok(())
+    }
+
+    /// Lower the file's lock to `level`.
+    fn unlock(&mut self, level: LockLevel) -> Result<()> {
+        let _ = level;
+        Ok(())
+    }
+
+    /// Check whether any connection holds a
+    /// [`Reserved`](LockLevel::Reserved) or higher lock on the file.
+    fn has_reserved_lock(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// The file's [sector size][sector], in bytes.
+    ///
+    /// [sector]: https://sqlite.org/c3ref/io_methods.html
+    fn sector_size(&self) -> i32 {
+        4096
+    }
+
+    /// The [`SQLITE_IOCAP_*`][iocap] characteristics of the file's storage.
+    ///
+    /// [iocap]: https://sqlite.org/c3ref/c_iocap_atomic.html
+    fn device_characteristics(&self) -> i32 {
+        0
+    }
+}
+
+/// A [file lock][locking] level, passed to [`VfsFile::lock`] and
+/// [`VfsFile::unlock`].
+///
+/// [locking]: https://sqlite.org/lockingv3.html
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+#[repr(i32)]
+pub enum LockLevel {
+    #[doc(alias = "SQLITE_LOCK_NONE")]
+    None = SQLITE_LOCK_NONE,
+    #[doc(alias = "SQLITE_LOCK_SHARED")]
+    Shared = SQLITE_LOCK_SHARED,
+    #[doc(alias = "SQLITE_LOCK_RESERVED")]
+    Reserved = SQLITE_LOCK_RESERVED,
+    #[doc(alias = "SQLITE_LOCK_PENDING")]
+    Pending = SQLITE_LOCK_PENDING,
+    #[doc(alias = "SQLITE_LOCK_EXCLUSIVE")]
+    Exclusive = SQLITE_LOCK_EXCLUSIVE,
+}
+
+impl LockLevel {
+    /// The `SQLITE_LOCK_*` constant for this level.
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Find the [`LockLevel`] for a `SQLITE_LOCK_*` constant.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            SQLITE_LOCK_NONE => Some(Self::None),
+            SQLITE_LOCK_SHARED => Some(Self::Shared),
+            SQLITE_LOCK_RESERVED => Some(Self::Reserved),
+            SQLITE_LOCK_PENDING => Some(Self::Pending),
+            SQLITE_LOCK_EXCLUSIVE => Some(Self::Exclusive),
+            _ => None,
+        }
+    }
+}
+
+/// Register `vfs` with SQLite as `name`, optionally making it the
+/// [default](super::default_vfs).
+///
+/// Connections can then [select](crate::ConnectionBuilder::vfs) the VFS by
+/// name. A registered VFS lives for the rest of the program; fails if a VFS
+/// named `name` is already registered.
+#[doc(alias = "sqlite3_vfs_register")]
+pub fn register_vfs<V: Vfs>(name: &str, vfs: V, make_default: bool) -> Result<()> {
+    let name = CString::new(name)?;
+
+    if !unsafe { sqlite3_vfs_find(name.as_ptr()) }.is_null() {
+        return Err(Error::with_detail(
+            ErrorCode::ERROR,
+            format!("VFS already registered: {}", name.to_string_lossy()),
+        ));
+    }
+
+    // SQLite only aligns the `sqlite3_file` it allocates to 8 bytes.
+    const {
+        assert!(
+            mem::align_of::<FileHandle<V::File>>() <= 8,
+            "VfsFile types must not need more than 8-byte alignment",
+        );
+    }
+
+    let fallback = unsafe { sqlite3_vfs_find(ptr::null()) };
+    let Some(fallback_ref) = (unsafe { fallback.as_ref() }) else {
+        return Err(Error::with_detail(
+            ErrorCode::ERROR,
+            "no default VFS is registered",
+        ));
+    };
+
+    let registration = Box::into_raw(Box::new(Registration {
+        base: sqlite3_vfs {
+            iVersion: 2,
+            szOsFile: mem::size_of::<FileHandle<V::File>>() as c_int,
+            mxPathname: fallback_ref.mxPathname,
+            pNext: ptr::null_mut(),
+            zName: name.as_ptr(),
+            pAppData: ptr::null_mut(),
+            xOpen: Some(x_open::<V>),
+            xDelete: Some(x_delete::<V>),
+            xAccess: Some(x_access::<V>),
+            xFullPathname: Some(x_full_pathname::<V>),
+            xDlOpen: Some(x_dl_open::<V>),
+            xDlError: Some(x_dl_error::<V>),
+            xDlSym: Some(x_dl_sym::<V>),
+            xDlClose: Some(x_dl_close::<V>),
+            xRandomness: Some(x_randomness::<V>),
+            xSleep: Some(x_sleep::<V>),
+            xCurrentTime: Some(x_current_time::<V>),
+            xGetLastError: Some(x_get_last_error::<V>),
+            xCurrentTimeInt64: Some(x_current_time_int64::<V>),
+            xSetSystemCall: None,
+            xGetSystemCall: None,
+            xNextSystemCall: None,
+        },
+        io_methods: io_methods::<V::File>(),
+        fallback,
+        vfs,
+        name,
+    }));
+
+    unsafe {
+        (*registration).base.pAppData = registration.cast();
+    }
+
+    let result =
+        unsafe { sqlite3_vfs_register(&raw mut (*registration).base, make_default as c_int) };
+    if result != SQLITE_OK {
+        drop(unsafe { Box::from_raw(registration) });
+        return Err(Error::from_code(result).unwrap_or_else(
+            #[cold]
+            || Error::from(ErrorCategory::Unknown),
+        ));
+    }
+
+    Ok(())
+}
+
+/// A registered [`Vfs`], which SQLite points to with
+/// [`pAppData`](sqlite3_vfs::pAppData).
+struct Registration<V: Vfs> {
+    base: sqlite3_vfs,
+    io_methods: sqlite3_io_methods,
+    fallback: *mut sqlite3_vfs,
+    vfs: V,
+    // Kept alive for `base.zName`.
+    #[allow(dead_code)]
+    name: CString,
+}
+
+/// The [`sqlite3_file`] allocated by SQLite for each file a [`Vfs`] opens.
+#[repr(C)]
+struct FileHandle<F> {
+    base: sqlite3_file,
+    file: F,
+}
+
+fn io_methods<F: VfsFile>() -> sqlite3_io_methods {
+    sqlite3_io_methods {
+        iVersion: 1,
+        xClose: Some(x_close::<F>),
+        xRead: Some(x_read::<F>),
+        xWrite: Some(x_write::<F>),
+        xTruncate: Some(x_truncate::<F>),
+        xSync: Some(x_sync::<F>),
+        xFileSize: Some(x_file_size::<F>),
+        xLock: Some(x_lock::<F>),
+        xUnlock: Some(x_unlock::<F>),
+        xCheckReservedLock: Some(x_check_reserved_lock::<F>),
+        xFileControl: Some(x_file_control),
+        xSectorSize: Some(x_sector_size::<F>),
+        xDeviceCharacteristics: Some(x_device_characteristics::<F>),
+        xShmMap: None,
+        xShmLock: None,
+        xShmBarrier: None,
+        xShmUnmap: None,
+        xFetch: None,
+        xUnfetch: None,
+    }
+}
+
+unsafe fn registration<'a, V: Vfs>(vfs: *mut sqlite3_vfs) -> &'a Registration<V> {
+    unsafe { &*(*vfs).pAppData.cast::<Registration<V>>() }
+}
+
+unsafe fn file<'a, F: VfsFile>(file: *mut sqlite3_file) -> &'a mut F {
+    unsafe { &mut (*file.cast::<FileHandle<F>>()).file }
+}
+
+/// Read a path passed by SQLite, or `None` if it's not valid UTF-8.
+unsafe fn path<'a>(name: *const c_char) -> Option<&'a str> {
+    unsafe { CStr::from_ptr(name) }.to_str().ok()
+}
+
+/// The result code to return to SQLite for `err`; errors which aren't from
+/// SQLite are reported as `default`.
+fn error_code(err: &Error, default: c_int) -> c_int {
+    if err.is_sqlite() {
+        err.code().raw()
+    } else {
+        default
+    }
+}
+
+/// Call into a [`Vfs`] or [`VfsFile`], returning `default` if it panics
+/// instead of unwinding into SQLite.
+fn guard(default: c_int, f: impl FnOnce() -> c_int) -> c_int {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(default)
+}
+
+fn result_code(result: Result<()>, default: c_int) -> c_int {
+    match result {
+        Ok(()) => SQLITE_OK,
+        Err(err) => error_code(&err, default),
+    }
+}
+
+unsafe extern "C" fn x_open<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    handle: *mut sqlite3_file,
+    flags: c_int,
+    out_flags: *mut c_int,
+) -> c_int {
+    guard(SQLITE_CANTOPEN, || {
+        let registration = unsafe { registration::<V>(vfs) };
+        let handle = handle.cast::<FileHandle<V::File>>();
+        // If `pMethods` is set, SQLite will call `xClose` even if `xOpen` fails.
+        unsafe { (*handle).base.pMethods = ptr::null() };
+
+        let name = if name.is_null() {
+            None
+        } else {
+            match unsafe { path(name) } {
+                Some(name) => Some(name),
+                None => return SQLITE_CANTOPEN,
+            }
+        };
+
+        match registration.vfs.open(name, flags) {
+            Ok(file) => {
+                unsafe {
+                    (&raw mut (*handle).file).write(file);
+                    (*handle).base.pMethods = &registration.io_methods;
+                }
+                if !out_flags.is_null() {
+                    unsafe { *out_flags = flags };
+                }
+                SQLITE_OK
+            }
+            Err(err) => error_code(&err, SQLITE_CANTOPEN),
+        }
+    })
+}
+
+unsafe extern "C" fn x_delete<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    _sync_dir: c_int,
+) -> c_int {
+    guard(SQLITE_IOERR_DELETE, || {
+        let registration = unsafe { registration::<V>(vfs) };
+        let Some(name) = (unsafe { path(name) }) else {
+            return SQLITE_IOERR_DELETE;
+        };
+
+        result_code(registration.vfs.delete(name), SQLITE_IOERR_DELETE)
+    })
+}
+
+unsafe extern "C" fn x_access<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    flags: c_int,
+    out: *mut c_int,
+) -> c_int {
+    guard(SQLITE_IOERR_ACCESS, || {
+        let registration = unsafe { registration::<V>(vfs) };
+        let Some(name) = (unsafe { path(name) }) else {
+            unsafe { *out = 0 };
+            return SQLITE_OK;
+        };
+
+        match registration
+            .vfs
+            .access(name, flags == SQLITE_ACCESS_READWRITE)
+        {
+            Ok(result) => {
+                unsafe { *out = result as c_int };
+                SQLITE_OK
+            }
+            Err(err) => error_code(&err, SQLITE_IOERR_ACCESS),
+        }
+    })
+}
+
+unsafe extern "C" fn x_full_pathname<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    len: c_int,
+    out: *mut c_char,
+) -> c_int {
+    guard(SQLITE_CANTOPEN, || {
+        let registration = unsafe { registration::<V>(vfs) };
+        let Some(name) = (unsafe { path(name) }) else {
+            return SQLITE_CANTOPEN;
+        };
+
+        let path = match registration.vfs.full_pathname(name) {
+            Ok(path) => path,
+            Err(err) => return error_code(&err, SQLITE_CANTOPEN),
+        };
+
+        let path = path.as_bytes();
+        if path.len() >= len as usize || path.contains(&0) {
+            return SQLITE_CANTOPEN;
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(path.as_ptr(), out.cast::<u8>(), path.len());
+            *out.add(path.len()) = 0;
+        }
+
+        SQLITE_OK
+    })
+}
+
+unsafe extern "C" fn x_dl_open<V: Vfs>(vfs: *mut sqlite3_vfs, name: *const c_char) -> *mut c_void {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xDlOpen } {
+        Some(f) => unsafe { f(fallback, name) },
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn x_dl_error<V: Vfs>(vfs: *mut sqlite3_vfs, len: c_int, out: *mut c_char) {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    if let Some(f) = unsafe { (*fallback).xDlError } {
+        unsafe { f(fallback, len, out) };
+    }
+}
+
+unsafe extern "C" fn x_dl_sym<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    library: *mut c_void,
+    symbol: *const c_char,
+) -> sqlite3_syscall_ptr {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xDlSym } {
+        Some(f) => unsafe { f(fallback, library, symbol) },
+        None => None,
+    }
+}
+
+unsafe extern "C" fn x_dl_close<V: Vfs>(vfs: *mut sqlite3_vfs, library: *mut c_void) {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    if let Some(f) = unsafe { (*fallback).xDlClose } {
+        unsafe { f(fallback, library) };
+    }
+}
+
+unsafe extern "C" fn x_randomness<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    len: c_int,
+    out: *mut c_char,
+) -> c_int {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xRandomness } {
+        Some(f) => unsafe { f(fallback, len, out) },
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn x_sleep<V: Vfs>(vfs: *mut sqlite3_vfs, microseconds: c_int) -> c_int {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xSleep } {
+        Some(f) => unsafe { f(fallback, microseconds) },
+        None => {
+            thread::sleep(Duration::from_micros(microseconds.max(0) as u64));
+            microseconds
+        }
+    }
+}
+
+unsafe extern "C" fn x_current_time<V: Vfs>(vfs: *mut sqlite3_vfs, out: *mut c_double) -> c_int {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xCurrentTime } {
+        Some(f) => unsafe { f(fallback, out) },
+        None => SQLITE_ERROR,
+    }
+}
+
+unsafe extern "C" fn x_get_last_error<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    len: c_int,
+    out: *mut c_char,
+) -> c_int {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { (*fallback).xGetLastError } {
+        Some(f) => unsafe { f(fallback, len, out) },
+        None => 0,
+    }
+}
+
+unsafe extern "C" fn x_current_time_int64<V: Vfs>(
+    vfs: *mut sqlite3_vfs,
+    out: *mut sqlite3_int64,
+) -> c_int {
+    let fallback = unsafe { registration::<V>(vfs) }.fallback;
+    match unsafe { ((*fallback).iVersion >= 2, (*fallback).xCurrentTimeInt64) } {
+        (true, Some(f)) => unsafe { f(fallback, out) },
+        _ => SQLITE_ERROR,
+    }
+}
+
+unsafe extern "C" fn x_close<F: VfsFile>(handle: *mut sqlite3_file) -> c_int {
+    let handle = handle.cast::<FileHandle<F>>();
+    let result = guard(SQLITE_IOERR_CLOSE, || {
+        unsafe { ptr::drop_in_place(&raw mut (*handle).file) };
+        SQLITE_OK
+    });
+    unsafe { (*handle).base.pMethods = ptr::null() };
+
+    result
+}
+
+unsafe extern "C" fn x_read<F: VfsFile>(
+    handle: *mut sqlite3_file,
+    buf: *mut c_void,
+    len: c_int,
+    offset: sqlite3_int64,
+) -> c_int {
+    guard(SQLITE_IOERR_READ, || {
+        let file = unsafe { file::<F>(handle) };
+        let buf = unsafe { slice::from_raw_parts_mut(buf.cast::<u8>(), len as usize) };
+
+        match file.read(buf, offset as u64) {
+            Ok(read) if read >= buf.len() => SQLITE_OK,
+            Ok(read) => {
+                buf[read..].fill(0);
+                SQLITE_IOERR_SHORT_READ
+            }
+            Err(err) => error_code(&err, SQLITE_IOERR_READ),
+        }
+    })
+}
+
+unsafe extern "C" fn x_write<F: VfsFile>(
+    handle: *mut sqlite3_file,
+    buf: *const c_void,
+    len: c_int,
+    offset: sqlite3_int64,
+) -> c_int {
+    guard(SQLITE_IOERR_WRITE, || {
+        let file = unsafe { file::<F>(handle) };
+        let buf = unsafe { slice::from_raw_parts(buf.cast::<u8>(), len as usize) };
+
+        result_code(file.write(buf, offset as u64), SQLITE_IOERR_WRITE)
+    })
+}
+
+unsafe extern "C" fn x_truncate<F: VfsFile>(
+    handle: *mut sqlite3_file,
+    size: sqlite3_int64,
+) -> c_int {
+    guard(SQLITE_IOERR_TRUNCATE, || {
+        let file = unsafe { file::<F>(handle) };
+        result_code(file.truncate(size as u64), SQLITE_IOERR_TRUNCATE)
+    })
+}
+
+unsafe extern "C" fn x_sync<F: VfsFile>(handle: *mut sqlite3_file, _flags: c_int) -> c_int {
+    guard(SQLITE_IOERR_FSYNC, || {
+        let file = unsafe { file::<F>(handle) };
+        result_code(file.sync(), SQLITE_IOERR_FSYNC)
+    })
+}
+
+unsafe extern "C" fn x_file_size<F: VfsFile>(
+    handle: *mut sqlite3_file,
+    out: *mut sqlite3_int64,
+) -> c_int {
+    guard(SQLITE_IOERR_FSTAT, || {
+        let file = unsafe { file::<F>(handle) };
+        match file.size() {
+            Ok(size) => {
+                unsafe { *out = size as sqlite3_int64 };
+                SQLITE_OK
+            }
+            Err(err) => error_code(&err, SQLITE_IOERR_FSTAT),
+        }
+    })
+}
+
+unsafe extern "C" fn x_lock<F: VfsFile>(handle: *mut sqlite3_file, level: c_int) -> c_int {
+    guard(SQLITE_IOERR_LOCK, || {
+        let file = unsafe { file::<F>(handle) };
+        match LockLevel::from_raw(level) {
+            Some(level) => result_code(file.lock(level), SQLITE_IOERR_LOCK),
+            None => SQLITE_MISUSE,
+        }
+    })
+}
+
+unsafe extern "C" fn x_unlock<F: VfsFile>(handle: *mut sqlite3_file, level: c_int) -> c_int {
+    guard(SQLITE_IOERR_UNLOCK, || {
+        let file = unsafe { file::<F>(handle) };
+        match LockLevel::from_raw(level) {
+            Some(level) => result_code(file.unlock(level), SQLITE_IOERR_UNLOCK),
+            None => SQLITE_MISUSE,
+        }
+    })
+}
+
+unsafe extern "C" fn x_check_reserved_lock<F: VfsFile>(
+    handle: *mut sqlite3_file,
+    out: *mut c_int,
+) -> c_int {
+    guard(SQLITE_IOERR_CHECKRESERVEDLOCK, || {
+        let file = unsafe { file::<F>(handle) };
+        match file.has_reserved_lock() {
+            Ok(result) => {
+                unsafe { *out = result as c_int };
+                SQLITE_OK
+            }
+            Err(err) => error_code(&err, SQLITE_IOERR_CHECKRESERVEDLOCK),
+        }
+    })
+}
+
+unsafe extern "C" fn x_file_control(
+    _handle: *mut sqlite3_file,
+    _op: c_int,
+    _arg: *mut c_void,
+) -> c_int {
+    SQLITE_NOTFOUND
+}
+
+unsafe extern "C" fn x_sector_size<F: VfsFile>(handle: *mut sqlite3_file) -> c_int {
+    guard(0, || unsafe { file::<F>(handle) }.sector_size())
+}
+
+unsafe extern "C" fn x_device_characteristics<F: VfsFile>(handle: *mut sqlite3_file) -> c_int {
+    guard(0, || unsafe { file::<F>(handle) }.device_characteristics())
+}
@@ -4,9 +4,11 @@
 )]
 #![allow(clippy::approx_constant)]
 
-use std::error::Error;
+use std::{error::Error, ops::ControlFlow};
 
-use squire::{Columns, Connection, Memory};
+use squire::{
+    Borrowed, Columns, Connection, ErrorCategory, ErrorReason, FetchError, Memory, Parameters,
+};
 
 type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
 
@@ -93,6 +95,91 @@ fn fetch_borrowed_struct() -> Result {
     Ok(())
 }
 
+#[test]
+fn stream_rows() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b, c) VALUES ('second', 7, 1.5), ('third', 9, 2.5);",
+        (),
+    )?;
+
+    let mut query = connection.prepare("SELECT a, b, c FROM example ORDER BY id;")?;
+
+    let mut seen = Vec::new();
+    let mut rows = query.query(())?.rows::<Row>()?;
+    rows.try_for_each(|row| {
+        seen.push(row.a);
+        Ok(if row.b == 7 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        })
+    })?;
+    assert_eq!(vec!["hello 🌎!", "second"], seen);
+    drop(rows);
+
+    let mut rows = query.query(())?.rows::<Row>()?;
+    let total = rows.fold(0, |total, row| Ok(total + row.a.len() as i64 + row.b))?;
+    assert_eq!(
+        ("hello 🌎!".len() + "second".len() + "third".len()) as i64 + 42 + 7 + 9,
+        total
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stream_borrowed_rows() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b, c) VALUES ('second', 7, 1.5), ('third', 9, 2.5);",
+        (),
+    )?;
+
+    let mut query = connection.prepare("SELECT a, b, c FROM example ORDER BY id;")?;
+
+    let mut seen = Vec::new();
+    query.query(())?.try_for_each(|row| {
+        let row: BorrowedRow = row.columns()?;
+        seen.push(row.a.len());
+        Ok(if row.b == 7 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        })
+    })?;
+    assert_eq!(vec!["hello 🌎!".len(), "second".len()], seen);
+
+    let total = query.query(())?.fold(0, |total, row| {
+        let row: BorrowedRow = row.columns()?;
+        Ok(total + row.a.len() as i64 + row.b)
+    })?;
+    assert_eq!(
+        ("hello 🌎!".len() + "second".len() + "third".len()) as i64 + 42 + 7 + 9,
+        total
+    );
+
+    let mut lengths = Vec::new();
+    let mut rows = query.query(())?.rows::<(Borrowed<str>, i64)>()?;
+    rows.try_for_each(|(a, b)| {
+        lengths.push((a.len(), b));
+        Ok(ControlFlow::Continue(()))
+    })?;
+    assert_eq!(vec![("hello 🌎!".len(), 42), (6, 7), (5, 9)], lengths);
+    drop(rows);
+
+    let mut rows = query.query(())?.rows::<BorrowedRow>()?;
+    let total = rows.fold(0, |total, row| {
+        if row.b == 9 {
+            return Err(ErrorCategory::Aborted.into());
+        }
+        Ok(total + row.a.len() as i64)
+    });
+    assert_eq!(Some(ErrorCategory::Aborted), total.unwrap_err().category());
+
+    Ok(())
+}
+
 #[derive(Columns)]
 struct RowTuple(String, i64, f64);
 
@@ -131,3 +218,336 @@ fn fetch_sequential() -> Result {
 
     Ok(())
 }
+
+#[derive(Columns, PartialEq, Debug)]
+enum Priority {
+    Low = 1,
+    High = 10,
+}
+
+#[derive(Columns, PartialEq, Debug)]
+enum Status {
+    #[squire(rename = "in-progress")]
+    InProgress,
+    Done,
+}
+
+#[derive(Columns)]
+struct Task {
+    priority: Priority,
+    status: Status,
+}
+
+#[test]
+fn fetch_enum() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 10 AS priority, 'in-progress' AS status;")?;
+    let task: Task = query.query(())?.one()?;
+    assert_eq!(Priority::High, task.priority);
+    assert_eq!(Status::InProgress, task.status);
+
+    let mut query = connection.prepare("SELECT 1, 'Done';")?;
+    let (priority, status): (Priority, Status) = query.query(())?.one()?;
+    assert_eq!(Priority::Low, priority);
+    assert_eq!(Status::Done, status);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_enum_unknown() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 2;")?;
+    let error = query.query(())?.one::<Priority>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), error.reason());
+
+    let mut query = connection.prepare("SELECT 'InProgress';")?;
+    assert!(query.query(())?.one::<Status>().is_err());
+
+    Ok(())
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct Permissions(u8);
+
+impl From<Permissions> for i64 {
+    fn from(permissions: Permissions) -> i64 {
+        permissions.0.into()
+    }
+}
+
+impl TryFrom<i64> for Permissions {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(bits: i64) -> std::result::Result<Self, Self::Error> {
+        u8::try_from(bits).map(Self)
+    }
+}
+
+#[derive(Columns, Parameters, PartialEq, Clone, Debug)]
+struct Grant {
+    name: String,
+    #[squire(bind_with = squire::bits::to_i64, fetch_with = squire::bits::from_i64)]
+    permissions: Permissions,
+}
+
+#[test]
+fn fetch_bits() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE grants (name TEXT, permissions INTEGER) STRICT;",
+        (),
+    )?;
+
+    let grant = Grant {
+        name: "admin".to_owned(),
+        permissions: Permissions(0b1011),
+    };
+    connection.execute(
+        "INSERT INTO grants (name, permissions) VALUES (:name, :permissions);",
+        grant.clone(),
+    )?;
+
+    let mut query = connection.prepare("SELECT name, permissions FROM grants;")?;
+    assert_eq!(grant, query.query(())?.one::<Grant>()?);
+
+    let mut query = connection.prepare("SELECT 'root' AS name, 256 AS permissions;")?;
+    let error = query.query(())?.one::<Grant>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), error.reason());
+
+    Ok(())
+}
+
+fn shout(name: String) -> String {
+    name.to_uppercase()
+}
+
+fn positive(count: i64) -> std::result::Result<i64, squire::Error> {
+    if count > 0 {
+        Ok(count)
+    } else {
+        Err(ErrorCategory::Aborted.into())
+    }
+}
+
+#[derive(Columns, PartialEq, Debug)]
+struct Shouted {
+    #[squire(fetch_with = shout)]
+    name: String,
+    #[squire(fetch_with = positive, result)]
+    count: i64,
+}
+
+#[test]
+fn fetch_with_field_type() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 'hello' AS name, 3 AS count;")?;
+    assert_eq!(
+        Shouted {
+            name: "HELLO".to_owned(),
+            count: 3,
+        },
+        query.query(())?.one::<Shouted>()?
+    );
+
+    let mut query = connection.prepare("SELECT 'hello' AS name, 0 AS count;")?;
+    assert!(query.query(())?.one::<Shouted>().is_err());
+
+    Ok(())
+}
+
+#[derive(Columns, PartialEq, Debug)]
+struct User {
+    id: i64,
+    name: String,
+}
+
+#[derive(Columns, PartialEq, Debug)]
+struct Org {
+    id: i64,
+    name: String,
+}
+
+#[derive(Columns)]
+struct Membership {
+    #[squire(flatten)]
+    user: User,
+    #[squire(flatten)]
+    org: Org,
+    role: String,
+}
+
+#[test]
+fn fetch_flattened() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection
+        .prepare("SELECT 'admin' AS role, 1 AS id, 'ada' AS name, 2 AS id, 'acme' AS name;")?;
+    let membership: Membership = query.query(())?.one()?;
+
+    assert_eq!(
+        User {
+            id: 1,
+            name: "ada".to_owned()
+        },
+        membership.user
+    );
+    assert_eq!(
+        Org {
+            id: 2,
+            name: "acme".to_owned()
+        },
+        membership.org
+    );
+    assert_eq!("admin", membership.role);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_flattened_missing() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 'admin' AS role, 1 AS id, 'ada' AS name;")?;
+    assert!(query.query(())?.one::<Membership>().is_err());
+
+    Ok(())
+}
+
+#[derive(Columns)]
+struct Invite {
+    name: String,
+    #[squire(flatten)]
+    from: User,
+    #[squire(flatten)]
+    to: User,
+}
+
+#[test]
+fn fetch_flattened_repeated_names() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection
+        .prepare("SELECT 'welcome' AS name, 1 AS id, 'ada' AS name, 2 AS id, 'grace' AS name;")?;
+    let invite: Invite = query.query(())?.one()?;
+
+    assert_eq!("welcome", invite.name);
+    assert_eq!(
+        User {
+            id: 1,
+            name: "ada".to_owned()
+        },
+        invite.from
+    );
+    assert_eq!(
+        User {
+            id: 2,
+            name: "grace".to_owned()
+        },
+        invite.to
+    );
+
+    Ok(())
+}
+
+#[derive(Columns)]
+struct Defaults {
+    #[squire(default)]
+    count: i64,
+    #[squire(default = "unnamed".to_owned())]
+    name: String,
+    #[squire(default = -1)]
+    present: i64,
+}
+
+#[test]
+fn fetch_default_when_null() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT NULL AS count, NULL AS name, 5 AS present;")?;
+    let row: Defaults = query.query(())?.one()?;
+
+    assert_eq!(0, row.count);
+    assert_eq!("unnamed", row.name);
+    assert_eq!(5, row.present);
+
+    Ok(())
+}
+
+#[derive(Columns, Debug)]
+struct Inserted {
+    id: i64,
+    created_at: String,
+}
+
+#[test]
+fn insert_returning() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT, created_at TEXT DEFAULT '2025-01-01 00:00:00');",
+        (),
+    )?;
+
+    let mut insert =
+        connection.prepare("INSERT INTO events (name) VALUES (?) RETURNING id, created_at;")?;
+    let first: Inserted = insert.query(("launch",))?.insert_returning()?;
+    let second: Inserted = insert.query(("landing",))?.insert_returning()?;
+
+    assert_eq!((1, 2), (first.id, second.id));
+    assert_eq!("2025-01-01 00:00:00", first.created_at);
+
+    let mut insert = connection.prepare(
+        "INSERT INTO events (id, name) VALUES (?, ?) ON CONFLICT DO NOTHING RETURNING id, created_at;",
+    )?;
+    let err = insert
+        .query((1, "duplicate"))?
+        .insert_returning::<Inserted>()
+        .unwrap_err();
+    assert_eq!(
+        Some(ErrorReason::Row(squire::RowError::NotReturned)),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_returning)]
+fn execute_many_returning() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, name TEXT UNIQUE);",
+        (),
+    )?;
+
+    let mut insert =
+        connection.prepare("INSERT INTO events (name) VALUES (?) RETURNING id, name;")?;
+    let inserted: Vec<(i64, String)> =
+        insert.execute_many_returning([("launch",), ("orbit",), ("landing",)])?;
+    assert_eq!(
+        vec![
+            (1, "launch".to_owned()),
+            (2, "orbit".to_owned()),
+            (3, "landing".to_owned()),
+        ],
+        inserted
+    );
+
+    let mut insert = connection.prepare(
+        "INSERT INTO events (name) VALUES (?), (? || ' again') ON CONFLICT DO NOTHING RETURNING id;",
+    )?;
+    let ids: Vec<i64> =
+        insert.execute_many_returning([("launch", "launch"), ("debrief", "debrief")])?;
+    assert_eq!(vec![4, 5, 6], ids);
+
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM events;")?
+        .query(())?
+        .one()?;
+    assert_eq!(6, count);
+
+    Ok(())
+}
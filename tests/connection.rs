@@ -0,0 +1,1406 @@
+use std::error::Error;
+
+use squire::{Connection, Limit};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn connection() -> Result<Connection> {
+    #[cfg(sqlite_has_memory_database)]
+    let connection = Connection::open(squire::Memory)?;
+    #[cfg(not(sqlite_has_memory_database))]
+    let connection = Connection::open(c"")?;
+
+    Ok(connection)
+}
+
+#[test]
+fn limits() -> Result {
+    let connection = connection()?;
+
+    let original = connection.limit(Limit::ExprDepth);
+    assert!(original > 10);
+
+    assert_eq!(original, connection.set_limit(Limit::ExprDepth, 10));
+    assert_eq!(10, connection.limit(Limit::ExprDepth));
+    assert_eq!(10, connection.set_limit(Limit::ExprDepth, -1));
+
+    let result = connection.execute(
+        "SELECT 1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + 1))))))))));",
+        (),
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn limit_names() {
+    for raw in 0..12 {
+        let limit = Limit::from_raw(raw).expect("limit");
+        assert_eq!(raw, limit.raw());
+        assert_eq!(Some(limit), Limit::from_name(limit.as_str()));
+    }
+
+    assert_eq!(None, Limit::from_raw(12));
+}
+
+#[test]
+#[cfg(sqlite_has_authorization_callback)]
+fn authorizer() -> Result {
+    use std::sync::{Arc, Mutex};
+
+    use squire::{AuthAction, AuthResult, ErrorCategory};
+
+    let connection = connection()?;
+    connection.execute("CREATE TABLE users (name TEXT, password TEXT);", ())?;
+    connection.execute("INSERT INTO users VALUES ('alice', 'hunter2');", ())?;
+
+    let tables = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&tables);
+    connection.set_authorizer(move |action| match action {
+        AuthAction::Read { table, column } => {
+            seen.lock().unwrap().extend(table.map(str::to_owned));
+            if column == Some("password") {
+                AuthResult::Ignore
+            } else {
+                AuthResult::Allow
+            }
+        }
+        AuthAction::Select => AuthResult::Allow,
+        AuthAction::Delete { .. } => panic!("no deleting"),
+        _ => AuthResult::Deny,
+    });
+
+    let (name, password): (String, Option<String>) = connection
+        .prepare("SELECT name, password FROM users;")?
+        .query(())?
+        .one()?;
+    assert_eq!("alice", name);
+    assert_eq!(None, password);
+    assert_eq!(vec!["users", "users"], *tables.lock().unwrap());
+
+    let err = connection
+        .prepare("INSERT INTO users VALUES ('bob', '');")
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Authorization), err.category());
+
+    let err = connection.prepare("DELETE FROM users;").unwrap_err();
+    assert_eq!(Some(ErrorCategory::Authorization), err.category());
+
+    connection.clear_authorizer();
+    assert_eq!(1, connection.execute("DELETE FROM users;", ())?);
+
+    Ok(())
+}
+
+#[cfg(sqlite_has_memory_database)]
+#[test]
+fn statement_cache() -> Result {
+    let connection = Connection::builder(squire::Memory)
+        .statement_cache_capacity(1)
+        .open()?;
+
+    let first = {
+        let mut statement = connection.prepare_cached("SELECT ?1;")?;
+        let value: i64 = statement.query(1)?.one()?;
+        assert_eq!(1, value);
+        format!("{:?}", *statement)
+    };
+
+    let second = {
+        let mut statement = connection.prepare_cached("SELECT ?1;")?;
+        let value: Option<i64> = statement.binding().ready().one()?;
+        assert_eq!(None, value, "bindings are cleared when returned");
+        format!("{:?}", *statement)
+    };
+    assert_eq!(first, second);
+
+    {
+        let outer = connection.prepare_cached("SELECT ?1;")?;
+        let inner = connection.prepare_cached("SELECT ?1;")?;
+        assert_ne!(format!("{:?}", *outer), format!("{:?}", *inner));
+    }
+
+    let mut other = connection.prepare_cached("SELECT 2;")?;
+    let value: i64 = other.query(())?.one()?;
+    assert_eq!(2, value);
+    drop(other);
+
+    connection.close()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "multi-thread")]
+#[test]
+fn thread_safety() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Connection>();
+
+    #[cfg(feature = "serialized")]
+    {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Connection>();
+        assert_send::<squire::Statement<'static>>();
+    }
+}
+
+#[test]
+fn annotate_error() -> Result {
+    let connection = connection()?;
+
+    let sql = "SELECT 1\n  FORM example;";
+    let err = connection.prepare(sql).unwrap_err();
+
+    if let Some(location) = err.source_location() {
+        assert_eq!(16, location.offset());
+        assert_eq!(
+            format!("{err}\n --> line 2, column 8\n  |\n2 |   FORM example;\n  |        ^"),
+            err.annotate(sql)
+        );
+    } else {
+        assert_eq!(err.to_string(), err.annotate(sql));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn constraint_target() -> Result {
+    let connection = connection()?;
+    connection.execute(
+        "CREATE TABLE members (org TEXT NOT NULL, email TEXT, CHECK (email <> ''), UNIQUE (org, email));",
+        (),
+    )?;
+    connection.execute("INSERT INTO members VALUES ('a', 'x@example.com');", ())?;
+
+    let err = connection
+        .execute("INSERT INTO members VALUES ('a', 'x@example.com');", ())
+        .unwrap_err();
+    let target = err.constraint_target().expect("a unique constraint");
+    assert_eq!("members", target.table);
+    assert_eq!(vec!["org", "email"], target.columns);
+
+    let err = connection
+        .execute("INSERT INTO members VALUES (NULL, 'y@example.com');", ())
+        .unwrap_err();
+    let target = err.constraint_target().expect("a not null constraint");
+    assert_eq!(vec!["org"], target.columns);
+
+    let err = connection
+        .execute("INSERT INTO members VALUES ('b', '');", ())
+        .unwrap_err();
+    assert_eq!(None, err.constraint_target());
+
+    let err = connection.prepare("SELECT * FROM missing;").unwrap_err();
+    assert_eq!(None, err.constraint_target());
+
+    Ok(())
+}
+
+#[test]
+fn with_retry() -> Result {
+    use std::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    };
+
+    use squire::{ErrorCategory, RetryPolicy};
+
+    static SLEPT: AtomicU64 = AtomicU64::new(0);
+
+    let connection = connection()?;
+    let policy = RetryPolicy {
+        max_attempts: 4,
+        backoff: Duration::from_millis(10),
+        jitter: false,
+        sleep: |duration| {
+            SLEPT.fetch_add(duration.as_millis() as u64, Ordering::SeqCst);
+        },
+    };
+
+    let mut attempts = 0;
+    let value = connection.with_retry(policy, |connection| {
+        attempts += 1;
+        if attempts < 3 {
+            Err(squire::Error::from(ErrorCategory::Busy))
+        } else {
+            connection.prepare("SELECT 42;")?.query(())?.one::<i32>()
+        }
+    })?;
+    assert_eq!(42, value);
+    assert_eq!(3, attempts);
+    assert_eq!(10 + 20, SLEPT.load(Ordering::SeqCst));
+
+    let mut attempts = 0;
+    let err = connection
+        .with_retry(policy, |_| -> squire::Result<()> {
+            attempts += 1;
+            Err(squire::Error::from(ErrorCategory::Locked))
+        })
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Locked), err.category());
+    assert_eq!(4, attempts);
+
+    let mut attempts = 0;
+    let err = connection
+        .with_retry(policy, |_| -> squire::Result<()> {
+            attempts += 1;
+            Err(squire::Error::from(ErrorCategory::Constraint))
+        })
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Constraint), err.category());
+    assert_eq!(1, attempts);
+
+    Ok(())
+}
+
+#[test]
+fn status() -> Result {
+    use squire::{DbStatus, Status};
+
+    let connection = connection()?;
+    connection.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+
+    let (schema, _) = connection.status(DbStatus::SchemaUsed, false)?;
+    assert!(schema > 0);
+
+    let (hits, _) = connection.status(DbStatus::CacheHit, true)?;
+    assert!(hits >= 0);
+
+    let (used, highwater) = squire::status(Status::MemoryUsed, false)?;
+    assert!(highwater >= used);
+
+    assert_eq!(Some(DbStatus::CacheMiss), DbStatus::from_raw(8));
+    assert_eq!(Some(Status::MallocCount), Status::from_raw(9));
+
+    Ok(())
+}
+
+#[test]
+fn autocommit_and_readonly() -> Result {
+    let connection = connection()?;
+    assert!(connection.in_autocommit());
+
+    connection.execute("BEGIN;", ())?;
+    assert!(!connection.in_autocommit());
+    connection.execute("COMMIT;", ())?;
+    assert!(connection.in_autocommit());
+
+    assert_eq!(Some(false), connection.is_readonly("main"));
+    assert_eq!(None, connection.is_readonly("missing"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(sqlite_has_shared_cache, sqlite_has_memory_database))]
+fn shared_cache() -> Result {
+    use squire::Uri;
+
+    let uri = format!("file:squire-shared-{}?mode=memory", std::process::id());
+    let open = || {
+        Connection::builder(Uri::new(uri.as_str()))
+            .shared_cache(true)
+            .open()
+    };
+
+    let first = open()?;
+    let second = open()?;
+
+    first.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    first.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+
+    let count: i64 = second
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(1, count);
+
+    let private = Connection::builder(Uri::new(uri.as_str()))
+        .shared_cache(false)
+        .open()?;
+    assert!(private.prepare("SELECT count(*) FROM example;").is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_temporary_database)]
+fn temporary_database() -> Result {
+    let first = Connection::open(squire::Temporary)?;
+    let second = Connection::open(squire::Temporary)?;
+
+    first.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    first.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    assert_eq!(None, first.filename("main"));
+
+    // Each connection has its own private database.
+    let tables: i64 = second
+        .prepare("SELECT count(*) FROM sqlite_schema;")?
+        .query(())?
+        .one()?;
+    assert_eq!(0, tables);
+
+    Ok(())
+}
+
+#[test]
+fn uri_builder() -> Result {
+    use squire::{ErrorCategory, Uri, UriCache, UriMode};
+
+    let dir = std::env::temp_dir().join(format!("squire uri {}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("odd #name?.sqlite3");
+
+    let uri = Uri::builder(&path)
+        .mode(UriMode::ReadWriteCreate)
+        .param("note", "a&b=c d")
+        .build()?;
+    assert!(
+        uri.to_str()?
+            .ends_with("/odd%20%23name%3F.sqlite3?mode=rwc&note=a%26b%3Dc%20d")
+    );
+
+    let connection = Connection::open(uri)?;
+    connection.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    connection.close()?;
+    assert!(path.exists());
+
+    let uri = Uri::builder(&path)
+        .mode(UriMode::ReadOnly)
+        .immutable(true)
+        .build()?;
+    let connection = Connection::open(uri)?;
+    let err = connection
+        .execute("INSERT INTO example DEFAULT VALUES;", ())
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::ReadOnly), err.category());
+    connection.close()?;
+
+    let uri = Uri::builder(format!("squire-uri-{}", std::process::id()))
+        .mode(UriMode::Memory)
+        .cache(UriCache::Shared)
+        .build()?;
+    let first = Connection::open(uri.clone())?;
+    let second = Connection::open(uri)?;
+    first.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    second.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+
+    let err = Uri::builder(&path)
+        .param("vfs", "unix#1")
+        .build()
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Misuse), err.category());
+
+    std::fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_complete)]
+fn is_complete() {
+    use squire::is_complete;
+
+    assert!(is_complete("SELECT 1;"));
+    assert!(is_complete("SELECT 1;\0"));
+    assert!(is_complete("SELECT 1; SELECT 2;"));
+    assert!(!is_complete("SELECT 1"));
+    assert!(!is_complete("SELECT 'a;"));
+    assert!(!is_complete("SELECT 1 /* ; */"));
+    assert!(is_complete("SELECT 1;\0 SELECT 2"));
+    assert!(!is_complete("SELECT 1 \0;"));
+
+    let long = format!("SELECT '{}';", "x".repeat(1000));
+    assert!(is_complete(&long));
+    assert!(!is_complete(&long[..long.len() - 1]));
+}
+
+#[test]
+fn heap_limits() {
+    use squire::{set_hard_heap_limit, set_soft_heap_limit};
+
+    let limit = 64 * 1024 * 1024;
+    let prior = set_soft_heap_limit(limit);
+    assert_eq!(limit, set_soft_heap_limit(-1));
+    assert_eq!(limit, set_soft_heap_limit(prior));
+
+    let prior = set_hard_heap_limit(-1);
+    assert_eq!(prior, set_hard_heap_limit(prior));
+}
+
+#[cfg(all(
+    sqlite_has_memory_database,
+    sqlite_has_db_config_defensive,
+    sqlite_has_db_config_enable_view,
+    sqlite_has_db_config_trusted_schema
+))]
+#[test]
+fn db_config() -> Result {
+    let connection = Connection::builder(squire::Memory)
+        .defensive(true)
+        .trusted_schema(false)
+        .enable_fkey(true)
+        .enable_trigger(false)
+        .enable_view(false)
+        .open()?;
+
+    let foreign_keys: bool = connection
+        .prepare("PRAGMA foreign_keys;")?
+        .query(())?
+        .one()?;
+    assert!(foreign_keys);
+    let trusted_schema: bool = connection
+        .prepare("PRAGMA trusted_schema;")?
+        .query(())?
+        .one()?;
+    assert!(!trusted_schema);
+
+    connection.execute("CREATE TABLE t (a INTEGER);", ())?;
+    connection.execute("CREATE TABLE log (a INTEGER);", ())?;
+    connection.execute(
+        "CREATE TRIGGER t_log AFTER INSERT ON t BEGIN INSERT INTO log VALUES (new.a); END;",
+        (),
+    )?;
+    connection.execute("INSERT INTO t VALUES (1);", ())?;
+    let logged: i64 = connection
+        .prepare("SELECT count(*) FROM log;")?
+        .query(())?
+        .one()?;
+    assert_eq!(0, logged);
+
+    connection.execute("CREATE VIEW v AS SELECT a FROM t;", ())?;
+    assert!(connection.prepare("SELECT a FROM v;").is_err());
+
+    connection.execute("PRAGMA writable_schema = ON;", ())?;
+    assert!(
+        connection
+            .execute("UPDATE sqlite_schema SET sql = '' WHERE name = 't';", ())
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg(sqlite_has_memory_database)]
+#[test]
+fn extended_result_codes() -> Result {
+    use squire::{ConstraintError, ErrorCategory, ErrorReason};
+
+    for extended in [true, false] {
+        let connection = Connection::builder(squire::Memory)
+            .extended_result_codes(extended)
+            .open()?;
+        connection.execute("CREATE TABLE t (a INTEGER UNIQUE);", ())?;
+        connection.execute("INSERT INTO t VALUES (1);", ())?;
+
+        let err = connection
+            .execute("INSERT INTO t VALUES (1);", ())
+            .unwrap_err();
+        assert_eq!(Some(ErrorCategory::Constraint), err.category());
+
+        let reason = extended.then_some(ErrorReason::Constraint(ConstraintError::Unique));
+        assert_eq!(reason, err.reason());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn checkpoint_on_close() -> Result {
+    use squire::{CheckpointMode, JournalMode};
+
+    let path = std::env::temp_dir().join(format!("squire-checkpoint-{}.db", std::process::id()));
+    let wal = path.with_extension("db-wal");
+    let filename = std::ffi::CString::new(path.to_str().unwrap())?;
+
+    let connection = Connection::builder(filename.as_c_str())
+        .checkpoint_on_close(CheckpointMode::Truncate)
+        .open()?;
+    connection.set_journal_mode(JournalMode::Wal)?;
+    connection.execute("CREATE TABLE example (value INTEGER);", ())?;
+    connection.execute("INSERT INTO example (value) VALUES (1), (2), (3);", ())?;
+    assert!(std::fs::metadata(&wal)?.len() > 0);
+
+    connection.close()?;
+    let remaining = std::fs::metadata(&wal).map_or(0, |metadata| metadata.len());
+
+    let connection = Connection::open(filename.as_c_str())?;
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    connection.close()?;
+
+    for file in [&path, &wal, &path.with_extension("db-shm")] {
+        let _ = std::fs::remove_file(file);
+    }
+
+    assert_eq!(0, remaining);
+    assert_eq!(3, count);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_authorization_callback)]
+fn checkpoint_on_close_busy() -> Result {
+    use std::sync::Arc;
+
+    use squire::{AuthResult, CheckpointMode, ErrorCategory, JournalMode};
+
+    let path =
+        std::env::temp_dir().join(format!("squire-checkpoint-busy-{}.db", std::process::id()));
+    let filename = std::ffi::CString::new(path.to_str().unwrap())?;
+
+    let connection = Connection::builder(filename.as_c_str())
+        .checkpoint_on_close(CheckpointMode::Truncate)
+        .open()?;
+    connection.set_journal_mode(JournalMode::Wal)?;
+    connection.execute("CREATE TABLE example (value INTEGER);", ())?;
+    connection.execute("INSERT INTO example (value) VALUES (1);", ())?;
+
+    let authorizer = Arc::new(());
+    let held = Arc::clone(&authorizer);
+    connection.set_authorizer(move |_| {
+        let _ = &held;
+        AuthResult::Allow
+    });
+
+    // An open read transaction keeps the truncating checkpoint busy.
+    let reader = Connection::open(filename.as_c_str())?;
+    reader.execute("BEGIN;", ())?;
+    let count: i64 = reader
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(1, count);
+
+    let err = connection.close().unwrap_err();
+    assert_eq!(Some(ErrorCategory::Busy), err.category());
+    assert_eq!(1, Arc::strong_count(&authorizer));
+
+    reader.execute("COMMIT;", ())?;
+    reader.close()?;
+    for extension in ["db", "db-wal", "db-shm"] {
+        let _ = std::fs::remove_file(path.with_extension(extension));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_json)]
+fn json_extract() -> Result {
+    let connection = Connection::open(c":memory:")?;
+    let json = r#"{"name": "alice", "tags": ["a", "b"], "age": 30}"#;
+
+    let name: String = connection.json_extract(json, "$.name")?;
+    assert_eq!("alice", name);
+    let age: i64 = connection.json_extract(json, "$.age")?;
+    assert_eq!(30, age);
+    let tag: String = connection.json_extract(json, "$.tags[1]")?;
+    assert_eq!("b", tag);
+    let missing: Option<String> = connection.json_extract(json, "$.email")?;
+    assert_eq!(None, missing);
+
+    assert!(
+        connection
+            .json_extract::<String>("{not json", "$.name")
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn vfs_names() -> Result {
+    use squire::ErrorCategory;
+
+    let names = squire::vfs_names();
+    let default = squire::default_vfs().expect("a default VFS");
+    assert_eq!(Some(&default), names.first());
+
+    let connection = Connection::builder(c":memory:").vfs(default).open()?;
+    drop(connection);
+
+    let err = Connection::builder(c":memory:")
+        .vfs(c"squire-missing")
+        .open()
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::CantOpen), err.category());
+
+    Ok(())
+}
+
+#[test]
+fn library_info() -> Result {
+    use squire::FeatureKey;
+
+    let library = squire::library();
+
+    let connection = connection()?;
+    let version: String = connection
+        .prepare("SELECT sqlite_version();")?
+        .query(())?
+        .one()?;
+    assert_eq!(version, library.version().to_string());
+
+    assert_eq!(cfg!(sqlite_has_json), library.has_feature(FeatureKey::Json));
+    assert_eq!(
+        cfg!(sqlite_has_serialize),
+        library.has_feature(FeatureKey::Serialize)
+    );
+    assert_eq!(
+        cfg!(sqlite_has_json),
+        library.features().any(|key| key == FeatureKey::Json)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn backup_with_progress() -> Result {
+    use core::ops::ControlFlow;
+
+    let source = connection()?;
+    source.execute(
+        "CREATE TABLE example (id INTEGER PRIMARY KEY, body BLOB);",
+        (),
+    )?;
+    for _ in 0..64 {
+        source.execute("INSERT INTO example (body) VALUES (zeroblob(2048));", ())?;
+    }
+
+    let mut destination = connection()?;
+    let mut steps = Vec::new();
+    source
+        .backup("main", &mut destination, "main")?
+        .run_with_progress(4, |progress| {
+            steps.push(progress);
+            ControlFlow::Continue(())
+        })?;
+
+    assert!(steps.len() > 1);
+    assert!(steps.windows(2).all(|w| w[1].remaining <= w[0].remaining));
+    let last = steps.last().unwrap();
+    assert_eq!(0, last.remaining);
+    assert_eq!(last.total, last.copied());
+
+    let count: i64 = destination
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(64, count);
+
+    let mut abandoned = connection()?;
+    let mut calls = 0;
+    source
+        .backup("main", &mut abandoned, "main")?
+        .run_with_progress(4, |_| {
+            calls += 1;
+            ControlFlow::Break(())
+        })?;
+    assert_eq!(1, calls);
+
+    let tables: i64 = abandoned
+        .prepare("SELECT count(*) FROM sqlite_schema;")?
+        .query(())?
+        .one()?;
+    assert_eq!(0, tables);
+
+    Ok(())
+}
+
+#[test]
+fn backup_busy() -> Result {
+    use core::ops::ControlFlow;
+
+    let path = std::env::temp_dir().join(format!("squire-backup-{}.db", std::process::id()));
+    let filename = std::ffi::CString::new(path.to_str().unwrap())?;
+
+    let source = Connection::open(filename.as_c_str())?;
+    source.execute("CREATE TABLE example (value INTEGER);", ())?;
+
+    let writer = Connection::open(filename.as_c_str())?;
+    writer.execute("BEGIN EXCLUSIVE;", ())?;
+
+    let mut destination = connection()?;
+    let mut calls = 0;
+    source
+        .backup("main", &mut destination, "main")?
+        .run_with_progress(-1, |_| {
+            calls += 1;
+            if calls < 3 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        })?;
+    assert_eq!(3, calls);
+
+    writer.execute("ROLLBACK;", ())?;
+    drop((source, writer));
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[test]
+fn transaction_drop_behavior() -> Result {
+    use squire::DropBehavior;
+
+    fn count(connection: &Connection) -> Result<i64> {
+        Ok(connection
+            .prepare("SELECT count(*) FROM example;")?
+            .query(())?
+            .one()?)
+    }
+
+    let mut connection = connection()?;
+    connection.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+
+    let txn = connection.transaction()?;
+    assert_eq!(DropBehavior::Rollback, txn.drop_behavior());
+    txn.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    drop(txn);
+    assert!(connection.in_autocommit());
+    assert_eq!(0, count(&connection)?);
+
+    let mut txn = connection.transaction()?;
+    txn.set_drop_behavior(DropBehavior::Commit);
+    txn.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    drop(txn);
+    assert!(connection.in_autocommit());
+    assert_eq!(1, count(&connection)?);
+
+    let mut txn = connection.transaction()?;
+    txn.set_drop_behavior(DropBehavior::Ignore);
+    txn.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    drop(txn);
+    assert!(!connection.in_autocommit());
+    assert!(connection.transaction().is_err());
+    connection.execute("ROLLBACK;", ())?;
+    assert_eq!(1, count(&connection)?);
+
+    let txn = connection.transaction()?;
+    txn.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    assert_eq!(2, count(txn.connection())?);
+    txn.commit()?;
+    assert_eq!(2, count(&connection)?);
+
+    Ok(())
+}
+
+#[test]
+fn transaction_failed_commit() -> Result {
+    let mut connection = connection()?;
+    connection.execute("PRAGMA foreign_keys = ON;", ())?;
+    connection.execute("CREATE TABLE parent (id INTEGER PRIMARY KEY);", ())?;
+    connection.execute(
+        "CREATE TABLE child (
+            parent_id INTEGER REFERENCES parent (id) DEFERRABLE INITIALLY DEFERRED
+        );",
+        (),
+    )?;
+
+    let txn = connection.transaction()?;
+    txn.execute("INSERT INTO child VALUES (1);", ())?;
+    let err = txn.commit().unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Constraint), err.category());
+
+    // The failed COMMIT left the transaction open; the guard rolled it back.
+    assert!(connection.in_autocommit());
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM child;")?
+        .query(())?
+        .one()?;
+    assert_eq!(0, count);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(sqlite_has_serialize, sqlite_has_memory_database))]
+fn register_vfs() -> Result {
+    use std::sync::Arc;
+
+    use squire::{ErrorCategory, Vfs, VfsFile};
+
+    struct Bundled(Arc<Vec<u8>>);
+    struct BundledFile(Arc<Vec<u8>>);
+
+    impl Vfs for Bundled {
+        type File = BundledFile;
+
+        fn open(&self, name: Option<&str>, _flags: i32) -> squire::Result<BundledFile> {
+            match name {
+                Some("bundled.db") => Ok(BundledFile(Arc::clone(&self.0))),
+                Some("panic.db") => panic!("cannot open panic.db"),
+                _ => Err(ErrorCategory::CantOpen.into()),
+            }
+        }
+
+        fn access(&self, name: &str, write: bool) -> squire::Result<bool> {
+            Ok(name == "bundled.db" && !write)
+        }
+    }
+
+    impl VfsFile for BundledFile {
+        fn read(&mut self, buf: &mut [u8], offset: u64) -> squire::Result<usize> {
+            let data = self.0.get(offset as usize..).unwrap_or_default();
+            let len = buf.len().min(data.len());
+            buf[..len].copy_from_slice(&data[..len]);
+            Ok(len)
+        }
+
+        fn size(&self) -> squire::Result<u64> {
+            Ok(self.0.len() as u64)
+        }
+    }
+
+    let source = connection()?;
+    source.execute("CREATE TABLE example (value TEXT);", ())?;
+    source.execute("INSERT INTO example VALUES ('hello'), ('world');", ())?;
+    let data = Arc::new(source.serialize("main")?);
+
+    squire::register_vfs("squire-bundled", Bundled(Arc::clone(&data)), false)?;
+    assert!(squire::vfs_names().contains(&"squire-bundled"));
+    assert_ne!(Some("squire-bundled"), squire::default_vfs());
+    assert!(squire::register_vfs("squire-bundled", Bundled(Arc::clone(&data)), false).is_err());
+
+    let connection = Connection::builder(c"bundled.db")
+        .vfs(c"squire-bundled")
+        .read_only()
+        .open()?;
+    let values: Vec<String> = connection
+        .prepare("SELECT value FROM example ORDER BY rowid;")?
+        .query(())?
+        .all()?;
+    assert_eq!(vec!["hello", "world"], values);
+
+    let err = connection
+        .execute("INSERT INTO example VALUES ('again');", ())
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::ReadOnly), err.category());
+
+    let err = Connection::builder(c"missing.db")
+        .vfs(c"squire-bundled")
+        .read_only()
+        .open()
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::CantOpen), err.category());
+
+    let err = Connection::builder(c"panic.db")
+        .vfs(c"squire-bundled")
+        .read_only()
+        .open()
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::CantOpen), err.category());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_column_metadata)]
+fn column_metadata() -> Result {
+    use squire::ErrorCategory;
+
+    let connection = connection()?;
+    connection.execute(
+        "CREATE TABLE example (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL COLLATE NOCASE, extra)",
+        (),
+    )?;
+
+    let id = connection.column_metadata(None, "example", "id")?;
+    assert_eq!(id.declared_type(), Some("INTEGER"));
+    assert_eq!(id.collation(), "BINARY");
+    assert!(id.primary_key());
+    assert!(id.auto_increment());
+    assert!(!id.not_null());
+
+    let name = connection.column_metadata(Some("main"), "example", "name")?;
+    assert_eq!(name.declared_type(), Some("TEXT"));
+    assert_eq!(name.collation(), "NOCASE");
+    assert!(name.not_null());
+    assert!(!name.primary_key());
+
+    let extra = connection.column_metadata(None, "example", "extra")?;
+    assert_eq!(extra.declared_type(), None);
+
+    let err = connection
+        .column_metadata(None, "example", "missing")
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Unknown), err.category());
+    assert!(err.to_string().contains("example.missing"), "{err}");
+
+    let err = connection
+        .column_metadata(None, "missing", "id")
+        .unwrap_err();
+    assert_eq!(Some(ErrorCategory::Unknown), err.category());
+
+    Ok(())
+}
+
+#[test]
+fn open_timeout() -> Result {
+    use std::time::{Duration, Instant};
+
+    use squire::{BusyError, ErrorReason};
+
+    let path = std::env::temp_dir().join(format!("squire-open-timeout-{}.db", std::process::id()));
+    let filename = std::ffi::CString::new(path.to_str().unwrap())?;
+
+    let writer = Connection::open(filename.as_c_str())?;
+    writer.execute("CREATE TABLE example (value INTEGER);", ())?;
+    writer.execute("BEGIN EXCLUSIVE;", ())?;
+
+    let start = Instant::now();
+    let err = Connection::builder(filename.as_c_str())
+        .open_timeout(Duration::from_millis(50))
+        .open()
+        .unwrap_err();
+    let elapsed = start.elapsed();
+
+    writer.execute("COMMIT;", ())?;
+    let reader = Connection::builder(filename.as_c_str())
+        .open_timeout(Duration::MAX)
+        .open();
+
+    let opened = reader.is_ok();
+    drop(writer);
+    drop(reader);
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(Some(ErrorReason::Busy(BusyError::Timeout)), err.reason());
+    assert!(elapsed >= Duration::from_millis(50), "{elapsed:?}");
+    assert!(opened);
+
+    Ok(())
+}
+
+#[test]
+fn raw_connection() -> Result {
+    let connection = connection()?;
+    connection.execute("CREATE TABLE example (value INTEGER);", ())?;
+
+    let shared = unsafe { Connection::from_raw(connection.as_raw(), false) };
+    assert_eq!(connection.as_raw(), shared.as_raw());
+    shared.execute("INSERT INTO example VALUES (1);", ())?;
+    let count: i64 = shared
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(1, count);
+    shared.close()?;
+
+    // Closing the borrowed connection left the original open.
+    connection.execute("INSERT INTO example VALUES (2);", ())?;
+
+    let ptr = connection.into_raw();
+    let connection = unsafe { Connection::from_raw(ptr, true) };
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(2, count);
+    connection.close()?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "functions")]
+fn aggregate_function() -> Result {
+    use squire::{Aggregate, Fetch, FunctionOptions, Value, ffi::ValueRef};
+
+    /// Joins its text arguments with commas, or returns NULL for no rows.
+    struct Join;
+
+    impl Aggregate for Join {
+        type State = Option<String>;
+
+        fn step<'a>(
+            &self,
+            joined: &mut Option<String>,
+            arguments: &'a [ValueRef<'a>],
+        ) -> squire::Result<()> {
+            let Some(text) = Option::<String>::fetch_value(&arguments[0])? else {
+                return Ok(());
+            };
+            match joined {
+                Some(joined) => {
+                    joined.push(',');
+                    joined.push_str(&text);
+                }
+                None => *joined = Some(text),
+            }
+            Ok(())
+        }
+
+        fn finish(&self, joined: Option<String>) -> squire::Result<Value> {
+            Ok(joined.map_or(Value::Null, Value::Text))
+        }
+    }
+
+    let connection = connection()?;
+    connection.create_aggregate_function("join_names", 1, FunctionOptions::default(), Join)?;
+    connection.execute("CREATE TABLE names (grp TEXT, name TEXT);", ())?;
+    connection.execute(
+        "INSERT INTO names VALUES ('a', 'x'), ('a', 'y'), ('b', 'z');",
+        (),
+    )?;
+
+    let groups: Vec<(String, String)> = connection
+        .prepare("SELECT grp, join_names(name) FROM names GROUP BY grp ORDER BY grp;")?
+        .query(())?
+        .all()?;
+    assert_eq!(
+        vec![
+            ("a".to_owned(), "x,y".to_owned()),
+            ("b".to_owned(), "z".to_owned())
+        ],
+        groups
+    );
+
+    let empty: Option<String> = connection
+        .prepare("SELECT join_names(name) FROM names WHERE grp = 'c';")?
+        .query(())?
+        .one()?;
+    assert_eq!(None, empty);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "functions", sqlite_has_window_function))]
+fn window_function() -> Result {
+    use squire::{Aggregate, Fetch, FunctionOptions, Value, WindowFunction, ffi::ValueRef};
+
+    /// Sums `u8` arguments, so that larger values fail to fetch.
+    struct ByteSum;
+
+    impl Aggregate for ByteSum {
+        type State = u64;
+
+        fn step<'a>(&self, sum: &mut u64, arguments: &'a [ValueRef<'a>]) -> squire::Result<()> {
+            *sum += u64::from(u8::fetch_value(&arguments[0])?);
+            Ok(())
+        }
+
+        fn finish(&self, sum: u64) -> squire::Result<Value> {
+            Ok(Value::Integer(sum as i64))
+        }
+    }
+
+    impl WindowFunction for ByteSum {
+        fn inverse<'a>(&self, sum: &mut u64, arguments: &'a [ValueRef<'a>]) -> squire::Result<()> {
+            *sum -= u64::from(u8::fetch_value(&arguments[0])?);
+            Ok(())
+        }
+
+        fn value(&self, sum: &u64) -> squire::Result<Value> {
+            Ok(Value::Integer(*sum as i64))
+        }
+    }
+
+    let connection = connection()?;
+    connection.create_window_function("byte_sum", 1, FunctionOptions::default(), ByteSum)?;
+    connection.execute("CREATE TABLE bytes (grp TEXT, value INTEGER);", ())?;
+    connection.execute(
+        "INSERT INTO bytes VALUES ('a', 1), ('a', 2), ('a', 3), ('b', 10), ('b', 20);",
+        (),
+    )?;
+
+    let windows: Vec<i64> = connection
+        .prepare(
+            "SELECT byte_sum(value) OVER (ORDER BY value ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM bytes;",
+        )?
+        .query(())?
+        .all()?;
+    assert_eq!(vec![1, 3, 5, 13, 30], windows);
+
+    let groups: Vec<(String, i64)> = connection
+        .prepare("SELECT grp, byte_sum(value) FROM bytes GROUP BY grp ORDER BY grp;")?
+        .query(())?
+        .all()?;
+    assert_eq!(vec![("a".to_owned(), 6), ("b".to_owned(), 30)], groups);
+
+    let empty: i64 = connection
+        .prepare("SELECT byte_sum(value) FROM bytes WHERE grp = 'c';")?
+        .query(())?
+        .one()?;
+    assert_eq!(0, empty);
+
+    connection.execute("INSERT INTO bytes VALUES ('c', 1000);", ())?;
+    let err = connection
+        .prepare("SELECT byte_sum(value) FROM bytes;")?
+        .query(())?
+        .one::<i64>()
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Unknown), err.category());
+    assert!(err.to_string().contains("out of range"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn filename_and_txn_state() -> Result {
+    use squire::TxnState;
+
+    let dir = std::env::temp_dir().join(format!("squire-filename-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("main.sqlite3");
+
+    let connection = Connection::open(path.as_path())?;
+    assert_eq!(
+        Some(path.canonicalize()?.as_path()),
+        connection.filename("main")
+    );
+    assert_eq!(None, connection.filename("temp"));
+    assert_eq!(None, connection.filename("missing"));
+
+    connection.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    assert_eq!(TxnState::None, connection.txn_state(None));
+
+    connection.execute("BEGIN;", ())?;
+    connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one::<i64>()?;
+    assert_eq!(TxnState::Read, connection.txn_state(Some("main")));
+
+    connection.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+    assert_eq!(TxnState::Write, connection.txn_state(None));
+    assert_eq!(TxnState::None, connection.txn_state(Some("missing")));
+    connection.execute("COMMIT;", ())?;
+
+    connection.close()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_session)]
+fn session() -> Result {
+    use squire::{ConflictResolution, ConflictType};
+
+    let source = connection()?;
+    let replica = connection()?;
+    for connection in [&source, &replica] {
+        connection.execute(
+            "CREATE TABLE example (id INTEGER PRIMARY KEY, value TEXT);",
+            (),
+        )?;
+    }
+    replica.execute(
+        "INSERT INTO example (id, value) VALUES (2, 'existing');",
+        (),
+    )?;
+
+    let mut session = source.session("main")?;
+    session.attach(Some("example"))?;
+    assert!(session.is_empty());
+
+    source.execute(
+        "INSERT INTO example (id, value) VALUES (1, 'a'), (2, 'b');",
+        (),
+    )?;
+    assert!(!session.is_empty());
+    let changeset = session.changeset()?;
+    drop(session);
+
+    let mut conflicts = Vec::new();
+    replica.apply_changeset(&changeset, |kind, table| {
+        conflicts.push((kind, table.to_owned()));
+        ConflictResolution::Replace
+    })?;
+    assert_eq!(
+        vec![(ConflictType::Conflict, "example".to_owned())],
+        conflicts
+    );
+
+    let rows: Vec<(i64, String)> = replica
+        .prepare("SELECT id, value FROM example ORDER BY id;")?
+        .query(())?
+        .all()?;
+    assert_eq!(vec![(1, "a".to_owned()), (2, "b".to_owned())], rows);
+
+    let err = replica
+        .apply_changeset(&changeset, |_, _| ConflictResolution::Abort)
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Aborted), err.category());
+
+    let err = replica
+        .apply_changeset(&changeset, |_, _| panic!("conflict handler panicked"))
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Aborted), err.category());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_snapshot)]
+fn snapshot() -> Result {
+    use squire::JournalMode;
+
+    let dir = std::env::temp_dir().join(format!("squire-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("main.sqlite3");
+
+    let writer = Connection::open(path.as_path())?;
+    writer.set_journal_mode(JournalMode::Wal)?;
+    writer.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+    writer.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+
+    let reader = Connection::open(path.as_path())?;
+    let count = |connection: &Connection| -> squire::Result<i64> {
+        connection
+            .prepare("SELECT count(*) FROM example;")?
+            .query(())?
+            .one()
+    };
+
+    reader.execute("BEGIN;", ())?;
+    assert_eq!(1, count(&reader)?);
+    let before = reader.snapshot("main")?;
+    reader.execute("COMMIT;", ())?;
+
+    writer.execute("INSERT INTO example DEFAULT VALUES;", ())?;
+
+    reader.execute("BEGIN;", ())?;
+    reader.open_snapshot("main", &before)?;
+    assert_eq!(1, count(&reader)?);
+    reader.execute("COMMIT;", ())?;
+
+    reader.execute("BEGIN;", ())?;
+    assert_eq!(2, count(&reader)?);
+    let after = reader.snapshot("main")?;
+    reader.execute("COMMIT;", ())?;
+
+    assert!(before < after);
+    assert_ne!(before, after);
+
+    drop((before, after));
+    reader.close()?;
+    writer.close()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(sqlite_has_pre_update_hook, feature = "value"))]
+fn preupdate_hook() -> Result {
+    use std::sync::{Arc, Mutex};
+
+    use squire::{ColumnIndex, ErrorCategory, PreUpdateOp};
+
+    type Change = (
+        PreUpdateOp,
+        String,
+        Option<i64>,
+        Option<String>,
+        Option<String>,
+    );
+
+    let connection = connection()?;
+    connection.execute(
+        "CREATE TABLE example (id INTEGER PRIMARY KEY, value TEXT);",
+        (),
+    )?;
+
+    let changes = Arc::new(Mutex::new(Vec::<Change>::new()));
+    let log = Arc::clone(&changes);
+    connection.set_preupdate_hook(move |update| {
+        let value = ColumnIndex::new(1);
+        let old = update.fetch_old::<String>(value).ok();
+        let new = update.fetch_new::<String>(value).ok();
+
+        if update.op() == PreUpdateOp::Insert {
+            let err = update.old_value(value).unwrap_err();
+            assert_eq!(Some(ErrorCategory::Misuse), err.category());
+        }
+        assert_eq!(2, update.count());
+        assert_eq!(0, update.depth());
+        assert_eq!(Some("main"), update.database());
+
+        log.lock().unwrap().push((
+            update.op(),
+            update.table().unwrap().to_owned(),
+            update.old_rowid(),
+            old,
+            new,
+        ));
+    });
+
+    connection.execute("INSERT INTO example (value) VALUES ('a');", ())?;
+    connection.execute("UPDATE example SET value = 'b';", ())?;
+    connection.execute("DELETE FROM example;", ())?;
+
+    connection.clear_preupdate_hook();
+    connection.execute("INSERT INTO example (value) VALUES ('c');", ())?;
+
+    let example = || "example".to_owned();
+    assert_eq!(
+        vec![
+            (
+                PreUpdateOp::Insert,
+                example(),
+                None,
+                None,
+                Some("a".to_owned())
+            ),
+            (
+                PreUpdateOp::Update,
+                example(),
+                Some(1),
+                Some("a".to_owned()),
+                Some("b".to_owned())
+            ),
+            (
+                PreUpdateOp::Delete,
+                example(),
+                Some(1),
+                Some("b".to_owned()),
+                None
+            ),
+        ],
+        *changes.lock().unwrap()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(sqlite_has_pre_update_hook, feature = "value"))]
+fn preupdate_hook_panic_and_close() -> Result {
+    use std::sync::Arc;
+
+    let connection = connection()?;
+    connection.execute("CREATE TABLE example (id INTEGER PRIMARY KEY);", ())?;
+
+    let token = Arc::new(());
+    let held = Arc::clone(&token);
+    connection.set_preupdate_hook(move |_| {
+        let _held = &held;
+        panic!("hook failed");
+    });
+
+    // A panicking hook doesn't stop the change
+    connection.execute("INSERT INTO example (id) VALUES (1);", ())?;
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(1, count);
+
+    // Closing the connection drops the hook
+    assert_eq!(2, Arc::strong_count(&token));
+    connection.close()?;
+    assert_eq!(1, Arc::strong_count(&token));
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "decimal")]
+
+use std::{error::Error, str::FromStr};
+
+use rust_decimal::Decimal;
+use squire::{Connection, Memory, ScaledDecimal};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn connection() -> Result<Connection> {
+    Ok(Connection::open(Memory)?)
+}
+
+#[test]
+fn decimal_round_trip() -> Result {
+    let conn = connection()?;
+
+    let value = Decimal::from_str("12345678901234567890.123456789")?;
+
+    let mut stmt = conn.prepare("SELECT ?, typeof(?1)")?;
+    let (fetched, kind): (Decimal, String) = stmt.query(value)?.one()?;
+
+    assert_eq!(value, fetched);
+    assert_eq!("text", kind);
+    Ok(())
+}
+
+#[test]
+fn decimal_column_representation() -> Result {
+    let conn = connection()?;
+
+    let value = Decimal::from_str("-1.2500")?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    let text: String = stmt.query(value)?.one()?;
+
+    assert_eq!("-1.25", text);
+    Ok(())
+}
+
+#[test]
+fn decimal_from_integer() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT 42")?;
+    let fetched: Decimal = stmt.query(())?.one()?;
+
+    assert_eq!(Decimal::from(42), fetched);
+    Ok(())
+}
+
+#[test]
+fn decimal_fetch_error() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    let result = stmt.query("not a decimal")?.one::<Decimal>();
+
+    let err = result.unwrap_err();
+    assert!(err.is_integration());
+    assert!(err.as_integration().is_some_and(|e| e.is_decimal()));
+    Ok(())
+}
+
+#[test]
+fn scaled_round_trip() -> Result {
+    let conn = connection()?;
+
+    let value = ScaledDecimal::<2>(Decimal::from_str("12.3")?);
+
+    let mut stmt = conn.prepare("SELECT ?1, ?1")?;
+    let (raw, fetched): (i64, ScaledDecimal<2>) = stmt.query(value)?.one()?;
+
+    assert_eq!(1230, raw);
+    assert_eq!(value, fetched);
+    Ok(())
+}
+
+#[test]
+fn scaled_precision_loss() -> Result {
+    let conn = connection()?;
+
+    let value = ScaledDecimal::<2>(Decimal::from_str("0.125")?);
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    assert!(stmt.query(value).is_err());
+    Ok(())
+}
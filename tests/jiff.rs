@@ -193,3 +193,92 @@ fn zoned_fetch_error() -> Result {
 
     Ok(())
 }
+
+// SqlText tests
+
+#[test]
+fn sql_text_current_timestamp() -> Result {
+    use squire::SqlText;
+
+    let conn = connection()?;
+    conn.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, created_at TEXT DEFAULT CURRENT_TIMESTAMP)",
+        (),
+    )?;
+    conn.execute("INSERT INTO events DEFAULT VALUES", ())?;
+
+    let before = Timestamp::now();
+    let mut stmt = conn.prepare("SELECT created_at FROM events")?;
+    let (SqlText(created_at),): (SqlText,) = stmt.query(())?.rows()?.next()?.ok_or("no row")?;
+    assert!((before - created_at).get_seconds().abs() <= 2);
+
+    let mut stmt = conn.prepare("SELECT created_at = ? FROM events")?;
+    let (equal,): (bool,) = stmt
+        .query(SqlText(created_at))?
+        .rows()?
+        .next()?
+        .ok_or("no row")?;
+    assert!(equal);
+
+    Ok(())
+}
+
+#[test]
+fn sql_text_round_trip() -> Result {
+    use squire::SqlText;
+
+    let conn = connection()?;
+
+    let timestamp: Timestamp = "2024-06-15T14:30:00.25Z".parse()?;
+
+    let mut stmt = conn.prepare("SELECT ?1, ?1, datetime(?1)")?;
+    let (text, SqlText(fetched), SqlText(truncated)): (String, SqlText, SqlText) = stmt
+        .query(SqlText(timestamp))?
+        .rows()?
+        .next()?
+        .ok_or("no row")?;
+
+    assert_eq!(text, "2024-06-15 14:30:00.25");
+    assert_eq!(fetched, timestamp);
+    assert_eq!(truncated, "2024-06-15T14:30:00Z".parse()?);
+    Ok(())
+}
+
+#[test]
+fn sql_text_rfc3339() -> Result {
+    use squire::SqlText;
+
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    for text in [
+        "2024-06-15T14:30:00Z",
+        "2024-06-15T10:30:00-04:00",
+        "2024-06-15T14:30:00",
+    ] {
+        let (SqlText(fetched),): (SqlText,) = stmt.query(text)?.rows()?.next()?.ok_or("no row")?;
+        assert_eq!(
+            fetched,
+            "2024-06-15T14:30:00Z".parse::<Timestamp>()?,
+            "{text}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn sql_text_fetch_error() -> Result {
+    use squire::SqlText;
+
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    let mut rows = stmt.query("yesterday")?.rows::<(SqlText,)>()?;
+    let err = rows.next().unwrap_err();
+
+    assert!(err.is_integration());
+    assert!(err.as_integration().unwrap().is_jiff());
+
+    Ok(())
+}
@@ -132,6 +132,26 @@ mod jsonb_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn jsonb_path() -> Result {
+        use squire::ColumnIndex;
+
+        let connection = Connection::open(Memory)?;
+        let mut query = connection.prepare(
+            r#"SELECT jsonb('{"user": {"name": "alice", "roles": ["admin", {"level": 3}]}}');"#,
+        )?;
+        let mut execution = query.query(())?;
+        let row = execution.row()?.ok_or("no row")?;
+        let column = ColumnIndex::INITIAL;
+
+        let name: String = row.json_path(column, "/user/name")?;
+        assert_eq!("alice", name);
+        assert_eq!(3, row.json_path::<u8>(column, "/user/roles/1/level")?);
+        assert_eq!(None, row.json_path::<Option<u8>>(column, "/user/age")?);
+
+        Ok(())
+    }
 }
 
 #[test]
@@ -190,3 +210,64 @@ fn json_nested_structure() -> Result {
 
     Ok(())
 }
+
+#[test]
+fn json_value() -> Result {
+    let connection = setup("TEXT")?;
+
+    let metadata = serde_json::json!({ "tags": ["a", "b"], "count": 2 });
+
+    let mut insert =
+        connection.prepare("INSERT INTO records (metadata, settings) VALUES (?, 'not json');")?;
+    let id = insert.insert(&metadata)?;
+
+    let mut query = connection.prepare("SELECT metadata FROM records WHERE id = ?;")?;
+    let fetched: serde_json::Value = query.query(id)?.one()?;
+    assert_eq!(metadata, fetched);
+
+    let mut query = connection.prepare("SELECT settings FROM records WHERE id = ?;")?;
+    let err = query.query(id)?.one::<serde_json::Value>().unwrap_err();
+    assert!(err.as_integration().is_some_and(|err| err.is_json()));
+
+    Ok(())
+}
+
+#[test]
+fn json_path() -> Result {
+    use squire::{ColumnIndex, ErrorReason, FetchError};
+
+    let connection = Connection::open(Memory)?;
+    let mut query = connection.prepare(
+        r#"SELECT '{"user": {"name": "alice", "roles": ["admin", {"level": 3}]}, "a/b": 1, "m~n": 2}', NULL, '{"broken": '"#,
+    )?;
+    let mut execution = query.query(())?;
+    let row = execution.row()?.ok_or("no row")?;
+    let column = ColumnIndex::INITIAL;
+
+    let name: &str = row.json_path(column, "/user/name")?;
+    assert_eq!("alice", name);
+    assert_eq!(3, row.json_path::<u8>(column, "/user/roles/1/level")?);
+    assert_eq!(1, row.json_path::<i64>(column, "/a~1b")?);
+    assert_eq!(2, row.json_path::<i64>(column, "/m~0n")?);
+
+    let roles: serde_json::Value = row.json_path(column, "/user/roles")?;
+    assert_eq!(serde_json::json!(["admin", {"level": 3}]), roles);
+
+    assert_eq!(None, row.json_path::<Option<u8>>(column, "/user/age")?);
+    assert_eq!(
+        None,
+        row.json_path::<Option<&str>>(column, "/user/roles/5")?
+    );
+    assert_eq!(None, row.json_path::<Option<&str>>(column.next(), "/user")?);
+
+    let err = row.json_path::<u8>(column, "/user/age").unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), err.reason());
+
+    assert!(
+        row.json_path::<Option<u8>>(ColumnIndex::new(2), "/x")
+            .is_err()
+    );
+    assert!(row.json_path::<u8>(column, "user").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+#![cfg(sqlite_has_memory_database)]
+
+use std::{
+    error::Error,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use squire::{Connection, ErrorReason, FetchError, Memory, PackedIp};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn connection() -> Result<Connection> {
+    Ok(Connection::open(Memory)?)
+}
+
+#[test]
+fn text_round_trip() -> Result {
+    let conn = connection()?;
+
+    let v4: IpAddr = Ipv4Addr::new(192, 0, 2, 1).into();
+    let v6: Ipv6Addr = "2001:db8::1".parse()?;
+    let socket: SocketAddr = "[2001:db8::1]:8080".parse()?;
+
+    let mut stmt = conn.prepare("SELECT ?1, ?2, ?3, typeof(?1), ?1 || ''")?;
+    let (a, b, c, kind, text): (IpAddr, Ipv6Addr, SocketAddr, String, String) =
+        stmt.query((v4, v6, socket))?.one()?;
+
+    assert_eq!(v4, a);
+    assert_eq!(v6, b);
+    assert_eq!(socket, c);
+    assert_eq!("text", kind);
+    assert_eq!("192.0.2.1", text);
+    Ok(())
+}
+
+#[test]
+fn packed_round_trip() -> Result {
+    let conn = connection()?;
+
+    let v4 = Ipv4Addr::new(10, 0, 0, 1);
+    let v6: Ipv6Addr = "::ffff:1.2.3.4".parse()?;
+
+    let mut stmt = conn.prepare("SELECT ?1, ?2, ?2, length(?1), length(?2), typeof(?1)")?;
+    let (a, b, c, len4, len6, kind): (
+        PackedIp<Ipv4Addr>,
+        PackedIp<Ipv6Addr>,
+        PackedIp,
+        i64,
+        i64,
+        String,
+    ) = stmt
+        .query((PackedIp(v4), PackedIp(IpAddr::V6(v6))))?
+        .one()?;
+
+    assert_eq!(v4, a.0);
+    assert_eq!(v6, b.0);
+    assert_eq!(IpAddr::V6(v6), c.0);
+    assert_eq!((4, 16), (len4, len6));
+    assert_eq!("blob", kind);
+    Ok(())
+}
+
+#[test]
+fn parse_errors() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT 'not an address'")?;
+    let err = stmt.query(())?.one::<IpAddr>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), err.reason());
+
+    let mut stmt = conn.prepare("SELECT x'0102'")?;
+    let err = stmt.query(())?.one::<PackedIp>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), err.reason());
+
+    let err = stmt.query(())?.one::<PackedIp<Ipv4Addr>>().unwrap_err();
+    assert!(err.to_string().contains("expected 4 bytes, got 2"));
+    Ok(())
+}
@@ -49,3 +49,120 @@ fn round_trip() -> Result {
 
     Ok(())
 }
+
+#[derive(Parameters, Clone, Copy)]
+enum Priority {
+    Low = 1,
+    High = 10,
+}
+
+#[derive(Parameters, Clone, Copy)]
+enum Status {
+    #[squire(rename = "in-progress")]
+    InProgress,
+    Done,
+}
+
+#[test]
+fn bind_enum() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?, ?, ?, ?;")?;
+    let values: (i64, i64, String, String) = query
+        .query((
+            Priority::Low,
+            Priority::High,
+            Status::InProgress,
+            Status::Done,
+        ))?
+        .one()?;
+
+    assert_eq!((1, 10, "in-progress".to_owned(), "Done".to_owned()), values);
+
+    Ok(())
+}
+
+#[derive(Parameters)]
+struct User<'a> {
+    id: i64,
+    username: &'a str,
+    email: &'a str,
+}
+
+#[derive(Parameters)]
+struct Insert<'a> {
+    #[squire(flatten)]
+    user: User<'a>,
+    created: i64,
+}
+
+#[test]
+fn bind_flattened() -> Result {
+    let connection = Connection::open(Memory)?;
+    connection.execute(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, username TEXT NOT NULL, email TEXT NOT NULL, created INTEGER NOT NULL) STRICT;",
+        (),
+    )?;
+
+    let mut insert = connection
+        .prepare("INSERT INTO users (id, username, email, created) VALUES (:id, :username, :email, :created);")?;
+    insert.insert(Insert {
+        user: User {
+            id: 7,
+            username: "ada",
+            email: "ada@example.com",
+        },
+        created: 1_700_000_000,
+    })?;
+
+    let mut query = connection.prepare("SELECT id, username, email, created FROM users;")?;
+    let row: (i64, String, String, i64) = query.query(())?.one()?;
+
+    assert_eq!(
+        (
+            7,
+            "ada".to_owned(),
+            "ada@example.com".to_owned(),
+            1_700_000_000
+        ),
+        row
+    );
+
+    Ok(())
+}
+
+#[test]
+fn checked_query() -> Result {
+    let connection = setup()?;
+
+    let inserted = squire::query!(
+        connection,
+        "INSERT INTO example (a, b, c) VALUES (?, ?, ?), (?1, ?2 * 2, ?3);",
+        "hello",
+        2,
+        0.5,
+    )
+    .execute()?;
+    assert_eq!(2, inserted);
+
+    let rows: Vec<(String, i64)> = squire::query!(
+        connection,
+        "SELECT a, b FROM example WHERE a = :a AND b >= :min OR a = :a ORDER BY b -- ?",
+        "hello",
+        0,
+    )
+    .all()?;
+    assert_eq!(vec![("hello".to_owned(), 2), ("hello".to_owned(), 4)], rows);
+
+    let missing: Option<i64> = squire::query!(
+        connection,
+        "SELECT b FROM example WHERE a = '?' /* ? */ AND \"b\" = 5",
+    )
+    .optional()?;
+    assert_eq!(None, missing);
+
+    let sum: i64 = squire::query!(connection, "SELECT ?2 + 1", 1, 2).one()?;
+    assert_eq!(3, sum);
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+#![cfg(sqlite_has_memory_database)]
+
+use std::error::Error;
+
+use squire::{Connection, JournalMode, Memory, Synchronous};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn connection() -> Result<Connection> {
+    Ok(Connection::open(Memory)?)
+}
+
+#[test]
+fn journal_mode() -> Result {
+    let connection = connection()?;
+
+    assert_eq!(JournalMode::Memory, connection.journal_mode()?);
+    assert_eq!(
+        JournalMode::Off,
+        connection.set_journal_mode(JournalMode::Off)?
+    );
+
+    // In-memory databases cannot use a write-ahead log
+    let err = connection.set_journal_mode(JournalMode::Wal).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("journal_mode could not be changed to wal (it is off)"),
+        "{err}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn foreign_keys() -> Result {
+    let connection = connection()?;
+
+    connection.set_foreign_keys(true)?;
+    assert!(connection.foreign_keys()?);
+
+    connection.set_foreign_keys(false)?;
+    assert!(!connection.foreign_keys()?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_deprecated)]
+fn case_sensitive_like() -> Result {
+    let connection = connection()?;
+    let mut query = connection.prepare("SELECT 'Hello' LIKE 'hello%';")?;
+
+    let default: bool = query.query(())?.one()?;
+    assert_eq!(!cfg!(sqlite_has_case_sensitive_like), default);
+
+    connection.set_case_sensitive_like(true)?;
+    let mut query = connection.prepare("SELECT 'Hello' LIKE 'hello%';")?;
+    assert!(!query.query(())?.one::<bool>()?);
+
+    connection.set_case_sensitive_like(false)?;
+    let mut query = connection.prepare("SELECT 'Hello' LIKE 'hello%';")?;
+    assert!(query.query(())?.one::<bool>()?);
+
+    Ok(())
+}
+
+#[test]
+fn synchronous() -> Result {
+    let connection = connection()?;
+
+    connection.set_synchronous(Synchronous::Normal)?;
+    assert_eq!(Synchronous::Normal, connection.synchronous()?);
+
+    Ok(())
+}
+
+#[test]
+fn user_version() -> Result {
+    let connection = connection()?;
+
+    assert_eq!(0, connection.user_version()?);
+    connection.set_user_version(42)?;
+    assert_eq!(42, connection.user_version()?);
+
+    Ok(())
+}
+
+#[test]
+fn page_size() -> Result {
+    let connection = connection()?;
+
+    assert!(connection.page_size()?.is_power_of_two());
+
+    Ok(())
+}
+
+#[test]
+fn cache_size() -> Result {
+    use squire::CacheSize;
+
+    let connection = connection()?;
+
+    connection.set_cache_size(CacheSize::Kibibytes(4096))?;
+    assert_eq!(CacheSize::Kibibytes(4096), connection.cache_size()?);
+    connection.set_cache_size(CacheSize::Pages(500))?;
+    assert_eq!(CacheSize::Pages(500), connection.cache_size()?);
+
+    let connection = Connection::builder(Memory)
+        .cache_size(CacheSize::Pages(100))
+        .open()?;
+    assert_eq!(CacheSize::Pages(100), connection.cache_size()?);
+
+    assert_eq!(-2000, CacheSize::Kibibytes(2000).value());
+    assert_eq!(None, CacheSize::from_value(i64::MIN));
+
+    Ok(())
+}
+
+#[test]
+fn mmap_size() -> Result {
+    let dir = std::env::temp_dir().join(format!("squire-mmap-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("main.sqlite3");
+
+    let connection = Connection::builder(path.as_path())
+        .mmap_size(1 << 20)
+        .open()?;
+    let size = connection.mmap_size()?;
+    assert!(size <= 1 << 20);
+
+    assert_eq!(0, connection.set_mmap_size(0)?);
+    assert!(connection.set_mmap_size(-1).is_err());
+
+    connection.close()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
@@ -36,3 +36,1340 @@ fn round_trip() -> Result {
 
     Ok(())
 }
+
+#[test]
+fn byte_array_round_trip() -> Result {
+    let connection = setup()?;
+
+    let hash = [0xAB_u8; 32];
+
+    let mut query = connection.prepare("SELECT ?, ?;")?;
+    let (owned, borrowed): ([u8; 32], [u8; 32]) = query.query((hash, &hash))?.one()?;
+
+    assert_eq!(hash, owned);
+    assert_eq!(hash, borrowed);
+
+    Ok(())
+}
+
+#[test]
+fn byte_array_empty() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?, typeof(?1), zeroblob(0), '';")?;
+    let (empty, kind, zero, text): ([u8; 0], String, Vec<u8>, String) =
+        query.query([0u8; 0])?.one()?;
+
+    assert_eq!([0u8; 0], empty);
+    assert_eq!("blob", kind);
+    assert!(zero.is_empty());
+    assert!(text.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn byte_array_wrong_length() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?;")?;
+    let result = query.query([1u8, 2, 3])?.one::<[u8; 4]>();
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn column_type() -> Result {
+    use squire::{Borrowed, ColumnIndex, Type};
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 1, 1.5, 'text', x'00', NULL;")?;
+    let mut execution = query.query(())?;
+    let mut row = execution.row()?.ok_or("no row")?;
+
+    let types: Vec<Type> = (0..5)
+        .map(|i| row.column_type(ColumnIndex::new(i)))
+        .collect();
+    assert_eq!(
+        vec![
+            Type::Integer,
+            Type::Float,
+            Type::Text,
+            Type::Blob,
+            Type::Null
+        ],
+        types,
+    );
+
+    let text: Option<Borrowed<'_, str>> = row.fetch(ColumnIndex::new(2))?;
+    assert_eq!(Some("text"), text.as_deref());
+
+    Ok(())
+}
+
+#[test]
+fn nullable_borrowed_text() -> Result {
+    use squire::Borrowed;
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?, ?;")?;
+    let mut rows = query
+        .query((Some("present"), None::<&str>))?
+        .rows::<(Option<Borrowed<'_, str>>, Option<Borrowed<'_, str>>)>()?;
+    let (present, absent) = rows.next()?.ok_or("no row")?;
+
+    assert_eq!(Some("present"), present.as_deref());
+    assert_eq!(None, absent.as_deref());
+
+    Ok(())
+}
+
+#[test]
+fn column_metadata() -> Result {
+    use squire::ColumnIndex;
+
+    let connection = setup()?;
+
+    let query = connection.prepare("SELECT a AS alias, b + 1 FROM example;")?;
+    let columns = query.columns();
+
+    #[cfg(sqlite_has_column_declared_type)]
+    assert_eq!(Some("TEXT"), columns.declared_type(ColumnIndex::new(0)));
+    assert_eq!(None, columns.declared_type(ColumnIndex::new(1)));
+
+    #[cfg(sqlite_has_column_metadata)]
+    {
+        let origin = columns.origin(ColumnIndex::new(0)).ok_or("no origin")?;
+        assert_eq!("main", origin.database());
+        assert_eq!("example", origin.table());
+        assert_eq!("a", origin.column());
+    }
+    assert_eq!(None, columns.origin(ColumnIndex::new(1)));
+
+    Ok(())
+}
+
+#[test]
+fn column_bytes() -> Result {
+    use squire::ColumnIndex;
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 'héllo', zeroblob(1024), 12345, '', NULL;")?;
+    let mut execution = query.query(())?;
+    let row = execution.row()?.ok_or("no row")?;
+
+    let sizes: Vec<usize> = (0..6)
+        .map(|i| row.column_bytes(ColumnIndex::new(i)))
+        .collect();
+    assert_eq!(vec![6, 1024, 5, 0, 0, 0], sizes);
+
+    let blob: Vec<u8> = row.get(ColumnIndex::new(1))?;
+    assert_eq!(1024, blob.len());
+
+    let empty: String = row.get(ColumnIndex::new(3))?;
+    assert_eq!("", empty);
+
+    Ok(())
+}
+
+#[test]
+fn manual_columns() -> Result {
+    use squire::{ColumnIndex, ColumnIndexes, Columns, Fetch, Statement};
+
+    #[derive(PartialEq, Debug)]
+    struct Example {
+        a: String,
+        b: i64,
+    }
+
+    impl ColumnIndexes for Example {
+        type Indexes = [ColumnIndex; 2];
+
+        fn resolve<'c>(statement: &Statement<'c>) -> Option<Self::Indexes> {
+            squire::resolve_named(statement, &["a", "b"])
+        }
+    }
+
+    impl<'r> Columns<'r> for Example {
+        fn fetch<'c>(statement: &'r Statement<'c>, [a, b]: Self::Indexes) -> squire::Result<Self>
+        where
+            'c: 'r,
+        {
+            Ok(Self {
+                a: Fetch::fetch_column(statement, a)?,
+                b: Fetch::fetch_column(statement, b)?,
+            })
+        }
+    }
+
+    assert_eq!(
+        [
+            ColumnIndex::new(0),
+            ColumnIndex::new(1),
+            ColumnIndex::new(2)
+        ],
+        squire::resolve_positional::<3>()
+    );
+
+    let connection = setup()?;
+    connection.execute("INSERT INTO example (a, b) VALUES ('one', 1);", ())?;
+
+    let mut query = connection.prepare("SELECT c, b, a FROM example;")?;
+    let example: Example = query.query(())?.one()?;
+    assert_eq!(
+        Example {
+            a: "one".to_owned(),
+            b: 1
+        },
+        example
+    );
+
+    let query = connection.prepare("SELECT a FROM example;")?;
+    assert_eq!(None, Example::resolve(&query));
+
+    Ok(())
+}
+
+#[test]
+fn type_affinity() -> Result {
+    use squire::{Affinity, ColumnIndex, Type};
+
+    // The examples from https://sqlite.org/datatype3.html#affinity_name_examples
+    let examples = [
+        ("INT", Affinity::Integer),
+        ("INTEGER", Affinity::Integer),
+        ("TINYINT", Affinity::Integer),
+        ("SMALLINT", Affinity::Integer),
+        ("MEDIUMINT", Affinity::Integer),
+        ("BIGINT", Affinity::Integer),
+        ("UNSIGNED BIG INT", Affinity::Integer),
+        ("INT2", Affinity::Integer),
+        ("INT8", Affinity::Integer),
+        ("CHARACTER(20)", Affinity::Text),
+        ("VARCHAR(255)", Affinity::Text),
+        ("VARYING CHARACTER(255)", Affinity::Text),
+        ("NCHAR(55)", Affinity::Text),
+        ("NATIVE CHARACTER(70)", Affinity::Text),
+        ("NVARCHAR(100)", Affinity::Text),
+        ("TEXT", Affinity::Text),
+        ("CLOB", Affinity::Text),
+        ("BLOB", Affinity::Blob),
+        ("", Affinity::Blob),
+        ("REAL", Affinity::Real),
+        ("DOUBLE", Affinity::Real),
+        ("DOUBLE PRECISION", Affinity::Real),
+        ("FLOAT", Affinity::Real),
+        ("NUMERIC", Affinity::Numeric),
+        ("DECIMAL(10,5)", Affinity::Numeric),
+        ("BOOLEAN", Affinity::Numeric),
+        ("DATE", Affinity::Numeric),
+        ("DATETIME", Affinity::Numeric),
+        ("FLOATING POINT", Affinity::Integer),
+        ("STRING", Affinity::Numeric),
+        ("varchar", Affinity::Text),
+    ];
+
+    for (declared_type, affinity) in examples {
+        assert_eq!(
+            affinity,
+            Type::affinity_of(declared_type),
+            "{declared_type}"
+        );
+    }
+
+    let connection = setup()?;
+    let query = connection.prepare("SELECT a, b, c, b + 1 FROM example;")?;
+    let columns = query.columns();
+
+    #[cfg(sqlite_has_column_declared_type)]
+    {
+        assert_eq!(Some(Affinity::Text), columns.affinity(ColumnIndex::new(0)));
+        assert_eq!(
+            Some(Affinity::Integer),
+            columns.affinity(ColumnIndex::new(1))
+        );
+        assert_eq!(Some(Affinity::Real), columns.affinity(ColumnIndex::new(2)));
+    }
+    assert_eq!(None, columns.affinity(ColumnIndex::new(3)));
+
+    Ok(())
+}
+
+#[test]
+fn optional_row() -> Result {
+    let connection = setup()?;
+
+    let mut insert = connection.prepare("INSERT INTO example (a, b) VALUES (?, ?);")?;
+    insert.insert(("one", 1))?;
+    insert.insert(("two", 2))?;
+
+    let mut query = connection.prepare("SELECT a FROM example WHERE b = ?;")?;
+    let found: Option<String> = query.query(1)?.optional()?;
+    assert_eq!(Some("one".to_owned()), found);
+
+    let missing: Option<String> = query.query(3)?.optional()?;
+    assert_eq!(None, missing);
+
+    let mut query = connection.prepare("SELECT a FROM example;")?;
+    let err = query.query(())?.optional::<String>().unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Row(squire::RowError::TooMany)),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn collect_rows() -> Result {
+    let connection = setup()?;
+
+    let mut insert = connection.prepare("INSERT INTO example (a, b) VALUES (?, ?);")?;
+    insert.insert(("one", 1))?;
+    insert.insert(("two", 2))?;
+
+    let mut query = connection.prepare("SELECT a, b FROM example ORDER BY b;")?;
+    let rows: Vec<(String, i64)> = query.query(())?.all_with_capacity(8)?;
+    assert_eq!(vec![("one".to_owned(), 1), ("two".to_owned(), 2)], rows);
+    assert!(rows.capacity() >= 8);
+
+    let rows: std::collections::VecDeque<squire::Result<(String, i64)>> =
+        query.query(())?.collect_into()?;
+    assert_eq!(2, rows.len());
+    assert!(rows.iter().all(|row| row.is_ok()));
+
+    Ok(())
+}
+
+#[test]
+fn execute_many() -> Result {
+    let connection = setup()?;
+
+    let mut insert = connection.prepare("INSERT INTO example (a, b) VALUES (?, ?);")?;
+    let changes = insert.execute_many([("one", 1), ("two", 2), ("three", 3)])?;
+    assert_eq!(3, changes);
+
+    // The same compiled statement is reused for later calls.
+    let changes = insert.execute_many(vec![("four", 4)])?;
+    assert_eq!(1, changes);
+
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(4, count);
+
+    let mut insert = connection.prepare("INSERT INTO example (id, a) VALUES (?, ?);")?;
+    let err = insert
+        .execute_many([(10, "ten"), (11, "eleven"), (10, "again"), (12, "twelve")])
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Constraint), err.category());
+    assert!(err.to_string().contains("after 2 successful rows"), "{err}");
+
+    let count: i64 = connection
+        .prepare("SELECT count(*) FROM example;")?
+        .query(())?
+        .one()?;
+    assert_eq!(6, count);
+
+    Ok(())
+}
+
+#[test]
+fn reset_and_clear_bindings() -> Result {
+    let connection = setup()?;
+    let mut statement = connection.prepare("SELECT ?1;")?;
+
+    let mut binding = statement.binding();
+    binding.set(squire::BindIndex::new(1).ok_or("index")?, 42)?;
+    let value: Option<i64> = binding.ready().one()?;
+    assert_eq!(Some(42), value);
+
+    statement.reset()?;
+    let value: Option<i64> = statement.binding().ready().one()?;
+    assert_eq!(Some(42), value);
+
+    statement.clear_bindings()?;
+    let value: Option<i64> = statement.binding().ready().one()?;
+    assert_eq!(None, value);
+
+    Ok(())
+}
+
+#[test]
+fn statement_kind() -> Result {
+    let connection = setup()?;
+
+    let select = connection.prepare("SELECT a FROM example;")?;
+    assert!(select.is_readonly());
+    assert_eq!(squire::ExplainMode::Normal, select.explain_mode());
+
+    let insert = connection.prepare("INSERT INTO example (a) VALUES ('x');")?;
+    assert!(!insert.is_readonly());
+
+    let explain = connection.prepare("EXPLAIN SELECT a FROM example;")?;
+    assert_eq!(squire::ExplainMode::Explain, explain.explain_mode());
+
+    let plan = connection.prepare("EXPLAIN QUERY PLAN SELECT a FROM example;")?;
+    assert_eq!(squire::ExplainMode::ExplainQueryPlan, plan.explain_mode());
+
+    Ok(())
+}
+
+#[test]
+fn statement_busy() -> Result {
+    let connection = setup()?;
+    connection.execute("INSERT INTO example (a) VALUES ('x'), ('y');", ())?;
+
+    let mut statement = connection.prepare("SELECT a FROM example;")?;
+    assert!(!statement.is_busy());
+
+    let mut binding = statement.binding();
+    let mut execution = binding.ready();
+    assert!(execution.row()?.is_some());
+    assert!(execution.statement().is_busy());
+    drop(execution);
+
+    assert!(!statement.is_busy());
+
+    Ok(())
+}
+
+#[test]
+fn statement_sql() -> Result {
+    let connection = setup()?;
+    let sql = "SELECT a FROM example WHERE b = ? AND a = ?;";
+    let mut statement = connection.prepare(sql)?;
+
+    assert_eq!(Some(sql), statement.sql());
+    assert_eq!(
+        Some("SELECT a FROM example WHERE b = NULL AND a = NULL;"),
+        statement.expanded_sql().as_deref()
+    );
+
+    let mut binding = statement.binding();
+    binding.set(squire::BindIndex::new(1).ok_or("index")?, 42)?;
+    binding.set(squire::BindIndex::new(2).ok_or("index")?, "it's")?;
+    let rows: Vec<String> = binding.ready().all()?;
+    assert!(rows.is_empty());
+
+    assert_eq!(
+        Some("SELECT a FROM example WHERE b = 42 AND a = 'it''s';"),
+        statement.expanded_sql().as_deref()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn complete() -> Result {
+    let connection = setup()?;
+
+    let mut insert = connection.prepare("INSERT INTO example (a, b) VALUES (?, ?), (?, ?);")?;
+    let completion = insert.query(("x", 1, "y", 2))?.complete()?;
+    assert_eq!(2, completion.changes);
+    assert_eq!(squire::RowId::new(2), completion.last_insert_rowid);
+
+    let mut update = connection.prepare("UPDATE example SET b = b + 1;")?;
+    let completion = update.query(())?.complete()?;
+    assert_eq!(2, completion.changes);
+    assert_eq!(squire::RowId::new(2), completion.last_insert_rowid);
+
+    Ok(())
+}
+
+#[test]
+fn bind_positional() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b) VALUES ('one', 1), ('two', 2), ('three', 3);",
+        (),
+    )?;
+
+    let ids: &[i64] = &[1, 3];
+    let mut query = connection.prepare("SELECT a FROM example WHERE b IN (?, ?) ORDER BY b;")?;
+    let names: Vec<String> = query.query(squire::Positional(ids))?.all()?;
+    assert_eq!(vec!["one", "three"], names);
+
+    let names: Vec<String> = query.query(squire::Positional(vec![3, 2]))?.all()?;
+    assert_eq!(vec!["two", "three"], names);
+
+    let err = query.query(squire::Positional(&ids[..1])).unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Parameter(
+            squire::ParameterError::Resolve
+        )),
+        err.reason()
+    );
+
+    let mut count = connection.prepare("SELECT count(*) FROM example;")?;
+    let empty: &[i64] = &[];
+    let total: i64 = count.query(squire::Positional(empty))?.one()?;
+    assert_eq!(3, total);
+
+    Ok(())
+}
+
+#[test]
+fn bind_map() -> Result {
+    use std::collections::{BTreeMap, HashMap};
+
+    let connection = setup()?;
+
+    let mut insert = connection.prepare("INSERT INTO example (a, b, c) VALUES (:a, @b, $c);")?;
+    let mut values = HashMap::new();
+    values.insert("a", "hash");
+    values.insert("@b", "7");
+    insert.execute(values)?;
+
+    let mut values = BTreeMap::new();
+    values.insert("a".to_owned(), "tree");
+    insert.execute(values)?;
+
+    let mut query = connection.prepare("SELECT a, b, c FROM example ORDER BY id;")?;
+    let rows: Vec<(String, Option<i64>, Option<f64>)> = query.query(())?.all()?;
+    assert_eq!(
+        vec![
+            ("hash".to_owned(), Some(7), None),
+            ("tree".to_owned(), None, None)
+        ],
+        rows
+    );
+
+    let mut values = HashMap::new();
+    values.insert("nope", 1);
+    let err = insert.execute(values).unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Parameter(
+            squire::ParameterError::Resolve
+        )),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn wide_tuples() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare(
+        "SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                17, 18, 19, 20;",
+    )?;
+
+    type Wide = (
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+    );
+
+    let row: Wide = query
+        .query((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16))?
+        .one()?;
+
+    assert_eq!(1, row.0);
+    assert_eq!(16, row.15);
+    assert_eq!(20, row.19);
+
+    Ok(())
+}
+
+#[test]
+fn bind_cow() -> Result {
+    use std::borrow::Cow;
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT ?, ?, ?, ?;")?;
+
+    let text = String::from("borrowed");
+    let bytes = vec![1u8, 2, 3];
+    let row: (String, String, Vec<u8>, Vec<u8>) = query
+        .query((
+            Cow::Borrowed(text.as_str()),
+            Cow::<str>::Owned("owned".to_owned()),
+            Cow::Borrowed(bytes.as_slice()),
+            Cow::<[u8]>::Owned(vec![4, 5]),
+        ))?
+        .one()?;
+
+    assert_eq!(
+        (
+            "borrowed".to_owned(),
+            "owned".to_owned(),
+            vec![1, 2, 3],
+            vec![4, 5]
+        ),
+        row
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reserve_blob() -> Result {
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE files (id INTEGER PRIMARY KEY, data BLOB);",
+        (),
+    )?;
+
+    let reservation = squire::Reservation::with_len(16);
+    assert_eq!(16, reservation.len());
+
+    let mut insert = connection.prepare("INSERT INTO files (data) VALUES (?);")?;
+    let id = insert.insert(reservation)?.ok_or("no row ID")?;
+
+    let mut query = connection.prepare("SELECT data FROM files WHERE id = ?;")?;
+    let data: Vec<u8> = query.query(id)?.one()?;
+    assert_eq!(vec![0; 16], data);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_utf8_text() -> Result {
+    use squire::ColumnIndex;
+    use std::borrow::Cow;
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT 'valid', CAST(x'68ff69' AS TEXT);")?;
+    let mut execution = query.query(())?;
+    let mut row = execution.row()?.ok_or("no row")?;
+
+    assert_eq!("valid", row.text(ColumnIndex::new(0))?);
+    assert_eq!("valid", row.text_lossy(ColumnIndex::new(0)));
+
+    let err = row.text(ColumnIndex::new(1)).unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Fetch(squire::FetchError::Parse)),
+        err.reason()
+    );
+    assert_eq!("h\u{FFFD}i", row.text_lossy(ColumnIndex::new(1)));
+
+    let valid: String = row.get(ColumnIndex::new(0))?;
+    assert_eq!("valid", valid);
+    for err in [
+        row.get::<String>(ColumnIndex::new(1)).unwrap_err(),
+        row.get::<&str>(ColumnIndex::new(1)).unwrap_err(),
+    ] {
+        assert_eq!(
+            Some(squire::ErrorReason::Fetch(squire::FetchError::Parse)),
+            err.reason()
+        );
+    }
+
+    let lossy: Cow<str> = row.fetch(ColumnIndex::new(1))?;
+    assert_eq!("h\u{FFFD}i", lossy);
+    drop(execution);
+
+    let mut rows = query.query(())?.rows::<(Cow<str>, Cow<str>)>()?;
+    let (valid, lossy) = rows.next()?.ok_or("no row")?;
+    assert!(matches!(valid, Cow::Borrowed("valid")));
+    assert_eq!("h\u{FFFD}i", lossy);
+
+    Ok(())
+}
+
+#[test]
+fn dynamic_values() -> Result {
+    use squire::{Type, Value};
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT NULL, 1, 2.5, 'three', x'04';")?;
+
+    let expected = vec![
+        Value::Null,
+        Value::Integer(1),
+        Value::Real(2.5),
+        Value::Text("three".to_owned()),
+        Value::Blob(vec![4]),
+    ];
+
+    let mut execution = query.query(())?;
+    let row = execution.row()?.ok_or("no row")?;
+    assert_eq!(expected, row.to_values()?);
+    drop(execution);
+
+    let rows: Vec<Vec<Value>> = query.query(())?.all()?;
+    assert_eq!(vec![expected.clone()], rows);
+    assert_eq!(Type::Float, expected[2].value_type());
+    assert!(expected[0].is_null());
+
+    let err = connection
+        .prepare("SELECT CAST(x'ff' AS TEXT);")?
+        .query(())?
+        .all::<Vec<_>, Vec<Value>>()
+        .unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Fetch(squire::FetchError::Parse)),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn write_csv() -> Result {
+    use squire::export::{BlobEncoding, CsvOptions, Quoting};
+
+    let connection = setup()?;
+    let mut query = connection.prepare(
+        "SELECT 1 AS id, 'a, \"b\"' AS name, NULL AS note, 2.5 AS score, x'fbff00' AS data
+         UNION ALL SELECT 2, 'line\nbreak', 'ok', NULL, x'';",
+    )?;
+
+    let mut csv = Vec::new();
+    let rows = query
+        .query(())?
+        .write_csv(&mut csv, CsvOptions::default())?;
+    assert_eq!(2, rows);
+    assert_eq!(
+        "id,name,note,score,data\n\
+         1,\"a, \"\"b\"\"\",,2.5,FBFF00\n\
+         2,\"line\nbreak\",ok,,\"\"\n",
+        String::from_utf8(csv)?
+    );
+
+    let options = CsvOptions::default()
+        .delimiter('\t')
+        .quoting(Quoting::NonNumeric)
+        .null("NULL")
+        .blob(BlobEncoding::Base64)
+        .header(false);
+
+    let mut csv = Vec::new();
+    query.query(())?.write_csv(&mut csv, options)?;
+    assert_eq!(
+        "1\t\"a, \"\"b\"\"\"\tNULL\t2.5\t\"+/8A\"\n\
+         2\t\"line\nbreak\"\t\"ok\"\tNULL\t\"\"\n",
+        String::from_utf8(csv)?
+    );
+
+    let mut query = connection.prepare("SELECT 1.0, 1e300, 2.5e-7, 9e999, -9e999;")?;
+    let mut csv = Vec::new();
+    query
+        .query(())?
+        .write_csv(&mut csv, CsvOptions::default().header(false))?;
+    assert_eq!("1.0,1e300,2.5e-7,Inf,-Inf\n", String::from_utf8(csv)?);
+
+    struct Closed;
+
+    impl std::io::Write for Closed {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = query
+        .query(())?
+        .write_csv(Closed, CsvOptions::default())
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Io), err.category());
+    let source = err.source().ok_or("no source")?;
+    assert_eq!(
+        Some(std::io::ErrorKind::BrokenPipe),
+        source
+            .downcast_ref::<std::io::Error>()
+            .map(|err| err.kind())
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "serde"))]
+fn write_ndjson() -> Result {
+    let connection = setup()?;
+    let mut query = connection.prepare(
+        "SELECT 1 AS id, 'a \"b\"\\' AS name, NULL AS note, 2.0 AS score, x'fbff00' AS data
+         UNION ALL SELECT 2, 'line\nbreak\u{1}', 'ok', -0.5, x'';",
+    )?;
+
+    let mut json = Vec::new();
+    let rows = query.query(())?.write_ndjson(&mut json)?;
+    assert_eq!(2, rows);
+
+    let json = String::from_utf8(json)?;
+    assert_eq!(
+        "{\"id\":1,\"name\":\"a \\\"b\\\"\\\\\",\"note\":null,\"score\":2.0,\"data\":\"+/8A\"}\n\
+         {\"id\":2,\"name\":\"line\\nbreak\\u0001\",\"note\":\"ok\",\"score\":-0.5,\"data\":\"\"}\n",
+        json
+    );
+
+    let values = json
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<Vec<serde_json::Value>, _>>()?;
+    assert_eq!(
+        serde_json::json!({"id": 2, "name": "line\nbreak\u{1}", "note": "ok", "score": -0.5, "data": ""}),
+        values[1]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(sqlite_has_carray)]
+fn carray() -> Result {
+    use squire::Carray;
+
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE fruit (id INTEGER PRIMARY KEY, name TEXT);",
+        (),
+    )?;
+    connection.execute(
+        "INSERT INTO fruit (name) VALUES ('apple'), ('banana'), ('cherry');",
+        (),
+    )?;
+
+    let ids = [1i64, 3];
+    let names: Vec<String> = connection
+        .prepare("SELECT name FROM fruit WHERE id IN (SELECT value FROM carray(?)) ORDER BY id;")?
+        .query(Carray::new(&ids))?
+        .all()?;
+    assert_eq!(vec!["apple", "cherry"], names);
+
+    let wanted = ["banana", "durian"];
+    let ids: Vec<i64> = connection
+        .prepare("SELECT id FROM fruit WHERE name IN (SELECT value FROM carray(?));")?
+        .query(Carray::new(&wanted))?
+        .all()?;
+    assert_eq!(vec![2], ids);
+
+    Ok(())
+}
+
+#[test]
+fn strict_bool() -> Result {
+    use squire::{ErrorReason, FetchError, StrictBool};
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT ?;")?;
+
+    assert_eq!(1i64, query.query(StrictBool(true))?.one::<i64>()?);
+    assert_eq!(StrictBool(false), query.query(0i64)?.one()?);
+    assert_eq!(StrictBool(true), query.query(1i64)?.one()?);
+
+    assert!(query.query(2i64)?.one::<bool>()?);
+    let err = query.query(2i64)?.one::<StrictBool>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), err.reason());
+    let err = query.query(-1i64)?.one::<StrictBool>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), err.reason());
+
+    Ok(())
+}
+
+#[test]
+fn bind_named() -> Result {
+    let connection = setup()?;
+
+    let mut statement = connection.prepare("SELECT :a || ' ' || @b;")?;
+    let mut binding = statement.binding();
+    binding.set_named(":a", "hello")?;
+    binding.set_named("b", "world")?;
+    let value: String = binding.ready().one()?;
+    assert_eq!("hello world", value);
+
+    let err = binding.set_named("c", 1).unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Parameter(
+            squire::ParameterError::Resolve
+        )),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn strict_binding() -> Result {
+    use squire::BindIndex;
+
+    let connection = setup()?;
+
+    let mut statement = connection.prepare("SELECT ?1 || ?2 || ?3;")?;
+    let mut binding = statement.binding();
+    binding.set(BindIndex::new(1).ok_or("index")?, "a")?;
+    binding.set(BindIndex::new(2).ok_or("index")?, "b")?;
+    binding.set(BindIndex::new(3).ok_or("index")?, "c")?;
+    let value: String = binding.strict()?.one()?;
+    assert_eq!("abc", value);
+
+    let mut binding = statement.binding();
+    binding.set(BindIndex::new(2).ok_or("index")?, "b")?;
+    let err = binding.strict().unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Parameter(
+            squire::ParameterError::Resolve
+        )),
+        err.reason()
+    );
+    assert!(err.to_string().contains("1, 3"), "{err}");
+
+    let mut statement = connection.prepare("SELECT ?70;")?;
+    let mut binding = statement.binding();
+    for index in BindIndex::INITIAL.iter().take(70) {
+        binding.set(index, index.value())?;
+    }
+    let value: i32 = binding.strict()?.one()?;
+    assert_eq!(70, value);
+
+    Ok(())
+}
+
+#[test]
+fn get_by_name() -> Result {
+    let connection = setup()?;
+    connection.execute("INSERT INTO example (a, b) VALUES ('one', 1);", ())?;
+
+    let mut query = connection.prepare("SELECT a, b, c FROM example;")?;
+    let mut execution = query.query(())?;
+    let row = execution.row()?.ok_or("no row")?;
+
+    let a: &str = row.get_by_name("a")?;
+    let b: i64 = row.get_by_name("b")?;
+    let c: Option<f64> = row.get(squire::ColumnIndex::new(2))?;
+    assert_eq!(("one", 1, None), (a, b, c));
+
+    let err = row.get_by_name::<i64>("d").unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Fetch(
+            squire::FetchError::UnknownColumn
+        )),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "fallible-iterator")]
+#[test]
+fn fallible_iterator() -> Result {
+    use fallible_iterator::FallibleIterator;
+
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b) VALUES ('one', 1), ('two', 2), ('three', 3);",
+        (),
+    )?;
+
+    let mut query = connection.prepare("SELECT a, b FROM example ORDER BY b;")?;
+    let names: Vec<String> = query
+        .query(())?
+        .rows::<(String, i64)>()?
+        .filter(|(_, b)| Ok(b % 2 == 1))
+        .map(|(a, _)| Ok(a))
+        .collect()?;
+    assert_eq!(vec!["one", "three"], names);
+
+    let mut query = connection.prepare("SELECT b FROM example ORDER BY b;")?;
+    let err = query
+        .query(())?
+        .rows::<i64>()?
+        .filter(|&b| {
+            if b < 3 {
+                Ok(true)
+            } else {
+                Err(squire::Error::from(squire::ErrorCategory::Range))
+            }
+        })
+        .count()
+        .unwrap_err();
+    assert_eq!(Some(squire::ErrorCategory::Range), err.category());
+
+    Ok(())
+}
+
+#[test]
+fn nonzero_integers() -> Result {
+    use std::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?, ?, ?, ?;")?;
+    let values = (
+        NonZeroI32::new(-1).ok_or("zero")?,
+        NonZeroI64::new(i64::MIN).ok_or("zero")?,
+        NonZeroU32::new(u32::MAX).ok_or("zero")?,
+        NonZeroU64::new(7).ok_or("zero")?,
+    );
+    let fetched: (NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64) = query.query(values)?.one()?;
+    assert_eq!(values, fetched);
+
+    let mut query = connection.prepare("SELECT ?;")?;
+    for value in [0, -1] {
+        let err = query.query(value)?.one::<NonZeroU64>().unwrap_err();
+        assert_eq!(
+            Some(squire::ErrorReason::Fetch(squire::FetchError::Range)),
+            err.reason()
+        );
+    }
+
+    assert!(query.query(NonZeroU64::MAX).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn real_as_f32() -> Result {
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT ?;")?;
+    let value: f32 = query.query(1.5f32)?.one()?;
+    assert_eq!(1.5, value);
+
+    let value: f32 = query.query(1e-40f64)?.one()?;
+    assert!(value.is_subnormal());
+    let value: f32 = query.query(-1e-50f64)?.one()?;
+    assert!(value == 0.0 && value.is_sign_negative());
+
+    let err = query.query(f64::MAX)?.one::<f32>().unwrap_err();
+    assert_eq!(
+        Some(squire::ErrorReason::Fetch(squire::FetchError::Range)),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn index_ranges() {
+    use squire::{BindIndex, ColumnIndex};
+
+    let columns: Vec<i32> = ColumnIndex::range(-2..3).map(i32::from).collect();
+    assert_eq!(vec![0, 1, 2], columns);
+    assert_eq!(0, ColumnIndex::range(3..3).len());
+
+    let parameters: Vec<i32> = BindIndex::range(..=3).rev().map(i32::from).collect();
+    assert_eq!(vec![3, 2, 1], parameters);
+    assert_eq!(2, BindIndex::range(0..3).len());
+}
+
+#[test]
+fn prepare_max_length() -> Result {
+    use squire::{ErrorCategory, PrepareOptions, Statement};
+
+    let connection = setup()?;
+    let options = PrepareOptions::transient().max_length(16);
+
+    let statement = Statement::prepare(&connection, "SELECT 1;", options)?;
+    drop(statement);
+
+    let err = Statement::prepare(&connection, "SELECT 1, 2, 3, 4, 5;", options).unwrap_err();
+    assert_eq!(Some(ErrorCategory::TooBig), err.category());
+
+    Ok(())
+}
+
+#[test]
+fn fetch_column() -> Result {
+    use squire::ColumnIndex;
+
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b) VALUES ('one', 1), ('two', 2), ('three', NULL);",
+        (),
+    )?;
+
+    let mut query = connection.prepare("SELECT a, b FROM example ORDER BY id;")?;
+    let a: Vec<String> = query.query(())?.column(ColumnIndex::INITIAL)?;
+    assert_eq!(vec!["one", "two", "three"], a);
+
+    let b: Vec<Option<i64>> = query.query(())?.column_named("b")?;
+    assert_eq!(vec![Some(1), Some(2), None], b);
+
+    assert!(query.query(())?.column::<i64>(ColumnIndex::new(2)).is_err());
+    assert!(query.query(())?.column_named::<i64>("c").is_err());
+
+    let mut query = connection.prepare("SELECT b FROM example WHERE b > 10;")?;
+    let none: Vec<i64> = query.query(())?.column(ColumnIndex::INITIAL)?;
+    assert!(none.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn borrowed_tuple() -> Result {
+    use core::ops::ControlFlow;
+    use squire::Borrowed;
+
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b, c) VALUES ('hello', 42, 1.5);",
+        (),
+    )?;
+
+    let mut query =
+        connection.prepare("SELECT a, b, CAST(a AS BLOB), c, upper(a), x'00ff' FROM example;")?;
+
+    {
+        let mut rows = query
+            .query(())?
+            .rows::<(Borrowed<str>, i64, Borrowed<[u8]>, f64, String, &[u8])>()?;
+        let (a, b, blob, c, upper, bytes) = rows.next()?.expect("a row");
+        assert_eq!("hello", &*a);
+        assert_eq!(42, b);
+        assert_eq!(b"hello", &*blob);
+        assert_eq!(1.5, c);
+        assert_eq!("HELLO", upper);
+        assert_eq!(&[0x00, 0xff], bytes);
+    }
+
+    let mut lengths = Vec::new();
+    query.query(())?.try_for_each(|row| {
+        let (a, b, blob): (&str, i64, Borrowed<[u8]>) = row.columns()?;
+        lengths.push(a.len() + blob.len() + b as usize);
+        Ok(ControlFlow::Continue(()))
+    })?;
+    assert_eq!(vec![52], lengths);
+
+    Ok(())
+}
+
+#[test]
+fn bind_null() -> Result {
+    use squire::{Null, Type};
+
+    let connection = setup()?;
+    connection.execute(
+        "INSERT INTO example (a, b, c) VALUES (?, ?, ?);",
+        ("bob", Null, Null),
+    )?;
+
+    let mut query = connection.prepare("SELECT b, c, typeof(?) FROM example;")?;
+    let (b, c, kind): (Option<i64>, Option<f64>, String) = query.query((Null,))?.one()?;
+    assert_eq!((None, None), (b, c));
+    assert_eq!("null", kind);
+
+    let mut execution = query.query((Null,))?;
+    let row = execution.row()?.expect("a row");
+    assert_eq!(Type::Null, row.column_type(squire::ColumnIndex::INITIAL));
+
+    Ok(())
+}
+
+#[test]
+fn borrowed_c_str() -> Result {
+    use squire::{Borrowed, ErrorReason, FetchError};
+    use std::ffi::CStr;
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT 'hello', NULL, '';")?;
+    {
+        let mut rows = query
+            .query(())?
+            .rows::<(Borrowed<CStr>, Borrowed<CStr>, Borrowed<CStr>)>()?;
+        let (text, null, empty) = rows.next()?.expect("a row");
+        assert_eq!(c"hello", &*text);
+        assert_eq!(c"", &*null);
+        assert_eq!(c"", &*empty);
+    }
+
+    let mut query = connection.prepare("SELECT 'a' || char(0) || 'b';")?;
+    let err = query
+        .query(())?
+        .try_for_each(|row| {
+            row.get::<Borrowed<CStr>>(squire::ColumnIndex::INITIAL)?;
+            Ok(core::ops::ControlFlow::Continue(()))
+        })
+        .unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), err.reason());
+
+    Ok(())
+}
+
+#[test]
+fn statement_counters() -> Result {
+    use squire::StmtCounter;
+
+    let connection = setup()?;
+    connection.execute(
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, kind TEXT NOT NULL);",
+        (),
+    )?;
+    for kind in ["open", "close", "open", "error"] {
+        connection.execute("INSERT INTO events (kind) VALUES (?);", kind)?;
+    }
+
+    // `kind` has no index, so this query scans the whole table.
+    let mut query = connection.prepare("SELECT count(*) FROM events WHERE kind = ?;")?;
+    let count: i64 = query.query("open")?.one()?;
+    assert_eq!(2, count);
+
+    assert!(query.counter(StmtCounter::FullscanStep, false) > 0);
+    assert!(query.counter(StmtCounter::VmStep, false) > 0);
+    assert_eq!(1, query.counter(StmtCounter::Run, true));
+    assert_eq!(0, query.counter(StmtCounter::Run, false));
+    assert!(query.counter(StmtCounter::MemUsed, false) > 0);
+
+    connection.execute("CREATE INDEX events_kind ON events (kind);", ())?;
+    let mut query = connection.prepare("SELECT count(*) FROM events WHERE kind = ?;")?;
+    let count: i64 = query.query("error")?.one()?;
+    assert_eq!(1, count);
+    assert_eq!(0, query.counter(StmtCounter::FullscanStep, false));
+
+    Ok(())
+}
+
+#[test]
+fn null_aggregates() -> Result {
+    use squire::{ErrorReason, RowError};
+
+    let connection = setup()?;
+
+    let mut query = connection.prepare("SELECT max(b) FROM example;")?;
+    assert_eq!(None, query.query(())?.one::<Option<i64>>()?);
+    assert_eq!((None,), query.query(())?.one::<(Option<i64>,)>()?);
+    assert_eq!(0, query.query(())?.one::<i64>()?);
+
+    let mut query = connection.prepare("SELECT max(b), count(*), sum(c) FROM example;")?;
+    let row: (Option<i64>, i64, Option<f64>) = query.query(())?.one()?;
+    assert_eq!((None, 0, None), row);
+
+    connection.execute("INSERT INTO example (a, b) VALUES ('x', 3), ('y', 5);", ())?;
+    let mut query = connection.prepare("SELECT max(b) FROM example;")?;
+    assert_eq!((Some(5),), query.query(())?.one::<(Option<i64>,)>()?);
+
+    let mut query = connection.prepare("SELECT b FROM example WHERE b > 10;")?;
+    let err = query.query(())?.one::<Option<i64>>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Row(RowError::NotReturned)), err.reason());
+
+    Ok(())
+}
+
+#[test]
+fn wide_integers() -> Result {
+    use squire::{ErrorReason, FetchError, ParameterError, Wide};
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT ?;")?;
+
+    assert_eq!(-5i128, query.query(-5i128)?.one::<i128>()?);
+    assert_eq!(7u128, query.query(7u128)?.one::<u128>()?);
+
+    let err = query.query(u128::MAX).unwrap_err();
+    assert_eq!(
+        Some(ErrorReason::Parameter(ParameterError::Range)),
+        err.reason()
+    );
+    let err = query.query(-1)?.one::<u128>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), err.reason());
+
+    for value in [i128::MIN, -1, 0, 1, i128::MAX] {
+        let Wide(fetched): Wide<i128> = query.query(Wide(value))?.one()?;
+        assert_eq!(value, fetched);
+    }
+    let Wide(fetched): Wide<u128> = query.query(Wide(u128::MAX))?.one()?;
+    assert_eq!(u128::MAX, fetched);
+
+    // BLOBs sort in the same order as the integers they hold.
+    let mut compare = connection.prepare("SELECT ?1 < ?2;")?;
+    let less: bool = compare.query((Wide(-1i128), Wide(1i128)))?.one()?;
+    assert!(less);
+
+    let err = query.query(42)?.one::<Wide<u128>>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), err.reason());
+
+    Ok(())
+}
+
+#[test]
+fn char_round_trip() -> Result {
+    use squire::{ErrorReason, FetchError};
+
+    let connection = setup()?;
+    let mut query = connection.prepare("SELECT ?, typeof(?1);")?;
+
+    for c in ['a', 'é', '🌎'] {
+        let (fetched, kind): (char, String) = query.query(c)?.one()?;
+        assert_eq!(c, fetched);
+        assert_eq!("text", kind);
+    }
+
+    let mut query = connection.prepare("SELECT ?;")?;
+    for text in ["", "ab"] {
+        let err = query.query(text)?.one::<char>().unwrap_err();
+        assert_eq!(Some(ErrorReason::Fetch(FetchError::Range)), err.reason());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn char_binds_as_text() -> Result {
+    let connection = setup()?;
+    connection.execute("CREATE TABLE flags (flag);", ())?;
+    connection.execute("INSERT INTO flags VALUES (?);", 'A')?;
+
+    // A char is stored as TEXT, not its code point, and no longer matches one.
+    let kind: String = connection
+        .prepare("SELECT typeof(flag) FROM flags;")?
+        .query(())?
+        .one()?;
+    assert_eq!("text", kind);
+
+    let matches: i64 = connection
+        .prepare("SELECT count(*) FROM flags WHERE flag = ?;")?
+        .query(u32::from('A'))?
+        .one()?;
+    assert_eq!(0, matches);
+
+    let matches: i64 = connection
+        .prepare("SELECT count(*) FROM flags WHERE flag = ?;")?
+        .query('A')?
+        .one()?;
+    assert_eq!(1, matches);
+
+    Ok(())
+}
+
+#[test]
+fn reset_returns_step_error() -> Result {
+    use squire::ErrorCategory;
+
+    let connection = setup()?;
+    connection.execute("CREATE TABLE unique_values (value INTEGER UNIQUE);", ())?;
+
+    let mut insert = connection.prepare("INSERT INTO unique_values (value) VALUES (?);")?;
+    insert.execute(1)?;
+    assert!(insert.execute(1).is_err());
+
+    let err = insert.reset().unwrap_err();
+    assert_eq!(Some(ErrorCategory::Constraint), err.category());
+
+    // Once returned, the error is cleared
+    insert.reset()?;
+    insert.execute(2)?;
+    insert.reset()?;
+
+    Ok(())
+}
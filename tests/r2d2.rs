@@ -0,0 +1,44 @@
+use std::{error::Error, thread};
+
+use squire::{Connection, SquireConnectionManager};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+#[test]
+fn pool() -> Result {
+    let dir = std::env::temp_dir().join(format!("squire-r2d2-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("pool.sqlite3");
+
+    let manager = SquireConnectionManager::new(Connection::builder(path.as_path()));
+    let pool = r2d2::Pool::builder().max_size(2).build(manager)?;
+
+    {
+        let connection = pool.get()?;
+        connection.execute(
+            "CREATE TABLE example (id INTEGER PRIMARY KEY, value INTEGER);",
+            (),
+        )?;
+        connection.execute("INSERT INTO example (value) VALUES (0), (1), (2), (3);", ())?;
+    }
+
+    let handles: Vec<_> = (0..4)
+        .map(|value| {
+            let pool = pool.clone();
+            thread::spawn(move || -> squire::Result<i64> {
+                let connection = pool.get().expect("connection");
+                let mut query = connection.prepare("SELECT value FROM example WHERE value = ?;")?;
+                query.query(value)?.one()
+            })
+        })
+        .collect();
+
+    for (value, handle) in handles.into_iter().enumerate() {
+        assert_eq!(value as i64, handle.join().expect("thread")?);
+    }
+
+    drop(pool);
+    std::fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::error::Error;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize, de::IgnoredAny};
+use squire::{Connection, ErrorReason, FetchError, Memory, ParameterError};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+#[derive(Serialize)]
+struct User<'a> {
+    username: &'a str,
+    #[serde(rename = "mail")]
+    email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    age: Option<u8>,
+    active: bool,
+    role: Role,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+enum Role {
+    Admin,
+}
+
+#[test]
+fn serialize_parameters() -> Result {
+    let connection = Connection::open(Memory)?;
+    connection.execute(
+        "CREATE TABLE users (username TEXT, email TEXT, age INTEGER, active INTEGER, role TEXT);",
+        (),
+    )?;
+
+    let mut insert = connection.prepare(
+        "INSERT INTO users (username, email, age, active, role) VALUES (:username, :mail, :age, :active, :role);",
+    )?;
+
+    let user = User {
+        username: "alice",
+        email: None,
+        age: None,
+        active: true,
+        role: Role::Admin,
+    };
+
+    let mut binding = insert.binding();
+    binding.serialize(&user)?;
+    binding.done().run()?;
+
+    let mut select = connection.prepare("SELECT username, email, age, active, role FROM users;")?;
+    let row: (String, Option<String>, Option<i64>, bool, String) = select.query(())?.one()?;
+    assert_eq!(
+        ("alice".to_owned(), None, None, true, "admin".to_owned()),
+        row
+    );
+
+    Ok(())
+}
+
+#[test]
+fn serialize_errors() -> Result {
+    let connection = Connection::open(Memory)?;
+    let mut statement = connection.prepare("SELECT :username;")?;
+
+    let mut binding = statement.binding();
+    let err = binding.serialize(&("alice",)).unwrap_err();
+    assert_eq!(
+        Some(ErrorReason::Parameter(ParameterError::Bind)),
+        err.reason()
+    );
+
+    #[derive(Serialize)]
+    struct Unknown {
+        username: &'static str,
+        missing: i64,
+    }
+
+    let err = binding
+        .serialize(&Unknown {
+            username: "alice",
+            missing: 1,
+        })
+        .unwrap_err();
+    assert_eq!(
+        Some(ErrorReason::Parameter(ParameterError::Resolve)),
+        err.reason()
+    );
+
+    #[derive(Serialize)]
+    struct Large {
+        username: u64,
+    }
+
+    let err = binding
+        .serialize(&Large { username: u64::MAX })
+        .unwrap_err();
+    assert_eq!(
+        Some(ErrorReason::Parameter(ParameterError::Range)),
+        err.reason()
+    );
+
+    Ok(())
+}
+
+#[derive(Deserialize, PartialEq, Debug)]
+struct Account<'a> {
+    id: i64,
+    username: &'a str,
+    email: Option<String>,
+    active: bool,
+    role: Role,
+    balance: f64,
+}
+
+#[test]
+fn deserialize_row() -> Result {
+    let connection = Connection::open(Memory)?;
+    let mut select = connection.prepare(
+        "SELECT 1 AS id, 'alice' AS username, NULL AS email, 1 AS active, 'admin' AS role, 2.5 AS balance, 'x' AS extra;",
+    )?;
+
+    let mut execution = select.query(())?;
+    let mut row = execution.row()?.expect("row");
+    let account: Account = row.deserialize()?;
+    assert_eq!(
+        Account {
+            id: 1,
+            username: "alice",
+            email: None,
+            active: true,
+            role: Role::Admin,
+            balance: 2.5,
+        },
+        account
+    );
+
+    drop(execution);
+
+    let mut execution = select.query(())?;
+    let mut row = execution.row()?.expect("row");
+    let map: BTreeMap<&str, IgnoredAny> = row.deserialize()?;
+    assert_eq!(
+        vec![
+            "active", "balance", "email", "extra", "id", "role", "username"
+        ],
+        map.into_keys().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_mismatch() -> Result {
+    #[derive(Deserialize, Debug)]
+    struct Small {
+        #[allow(dead_code)]
+        value: u8,
+    }
+
+    let connection = Connection::open(Memory)?;
+    let mut select = connection.prepare("SELECT 300 AS value;")?;
+    let mut execution = select.query(())?;
+    let mut row = execution.row()?.expect("row");
+
+    let err = row.deserialize::<Small>().unwrap_err();
+    assert_eq!(Some(ErrorReason::Fetch(FetchError::Parse)), err.reason());
+
+    Ok(())
+}
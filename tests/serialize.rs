@@ -0,0 +1,75 @@
+#![cfg(all(sqlite_has_serialize, sqlite_has_memory_database))]
+
+use std::error::Error;
+
+use squire::{Connection, DeserializeFlags, Memory};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn setup() -> Result<Connection> {
+    let connection = Connection::open(Memory)?;
+
+    connection.execute(
+        "CREATE TABLE example (id INTEGER PRIMARY KEY, a TEXT NOT NULL) STRICT;",
+        (),
+    )?;
+    connection.execute("INSERT INTO example (a) VALUES ('hello'), ('world');", ())?;
+
+    Ok(connection)
+}
+
+#[test]
+fn round_trip() -> Result {
+    let source = setup()?;
+    let data = source.serialize("main")?;
+    assert!(data.starts_with(b"SQLite format 3\0"));
+
+    let connection = Connection::open(Memory)?;
+    connection.deserialize("main", data, DeserializeFlags::new().resizable(true))?;
+
+    let mut query = connection.prepare("SELECT group_concat(a, ' ') FROM example;")?;
+    let value: String = query.query(())?.one()?;
+    assert_eq!("hello world", value);
+
+    connection.execute("INSERT INTO example (a) VALUES ('again');", ())?;
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_read_only() -> Result {
+    let data = setup()?.serialize("main")?;
+
+    let connection = Connection::open(Memory)?;
+    connection.deserialize("main", data, DeserializeFlags::new().read_only(true))?;
+
+    let result = connection.execute("INSERT INTO example (a) VALUES ('again');", ());
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn open_memory_from() -> Result {
+    let data = setup()?.serialize("main")?;
+
+    let connection =
+        Connection::open_memory_from(&data[..], DeserializeFlags::new().read_only(true))?;
+
+    let mut query = connection.prepare("SELECT count(*) FROM example;")?;
+    let count: i64 = query.query(())?.one()?;
+    assert_eq!(2, count);
+
+    let result = connection.execute("INSERT INTO example (a) VALUES ('again');", ());
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_unknown_schema() -> Result {
+    let connection = setup()?;
+    assert!(connection.serialize("missing").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "std-time")]
+
+use std::{
+    error::Error,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use squire::{Connection, Memory, Micros, Millis, Secs, UnixMillis, UnixSeconds};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+fn connection() -> Result<Connection> {
+    Ok(Connection::open(Memory)?)
+}
+
+#[test]
+fn system_time_round_trip() -> Result {
+    let conn = connection()?;
+
+    let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+    let mut stmt = conn.prepare("SELECT ?, typeof(?1)")?;
+    let (fetched, kind): (SystemTime, String) = stmt.query(time)?.one()?;
+
+    assert_eq!(time, fetched);
+    assert_eq!("integer", kind);
+    Ok(())
+}
+
+#[test]
+fn system_time_column_representation() -> Result {
+    let conn = connection()?;
+
+    let time = UNIX_EPOCH + Duration::new(1_700_000_000, 987_654_321);
+
+    let mut stmt = conn.prepare("SELECT ?1, ?2")?;
+    let (millis, seconds): (i64, i64) = stmt.query((time, UnixSeconds(time)))?.one()?;
+
+    assert_eq!(1_700_000_000_987, millis);
+    assert_eq!(1_700_000_000, seconds);
+    Ok(())
+}
+
+#[test]
+fn system_time_before_epoch() -> Result {
+    let conn = connection()?;
+
+    let time = UNIX_EPOCH - Duration::from_millis(1_500);
+
+    let mut stmt = conn.prepare("SELECT ?1, ?1")?;
+    let (millis, fetched): (i64, UnixMillis) = stmt.query(time)?.one()?;
+
+    assert_eq!(-1_500, millis);
+    assert_eq!(UnixMillis(time), fetched);
+    Ok(())
+}
+
+#[test]
+fn unix_seconds_round_trip() -> Result {
+    let conn = connection()?;
+
+    let time = UnixSeconds(UNIX_EPOCH - Duration::from_secs(86_400));
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    let fetched: UnixSeconds = stmt.query(time)?.one()?;
+
+    assert_eq!(time, fetched);
+    Ok(())
+}
+
+#[test]
+fn duration_round_trip() -> Result {
+    let conn = connection()?;
+
+    let duration = Duration::new(3661, 123_456_789);
+
+    let mut stmt = conn.prepare("SELECT ?, ?1")?;
+    let (fetched, nanos): (Duration, i64) = stmt.query(duration)?.one()?;
+
+    assert_eq!(duration, fetched);
+    assert_eq!(3_661_123_456_789, nanos);
+    Ok(())
+}
+
+#[test]
+fn duration_fetch_negative() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT -1")?;
+    let result = stmt.query(())?.one::<Duration>();
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn duration_bind_overflow() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    let result = stmt.query(Duration::MAX);
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn duration_units() -> Result {
+    let conn = connection()?;
+
+    let duration = Duration::new(3661, 123_456_789);
+
+    let mut stmt = conn.prepare("SELECT ?1, ?2, ?3")?;
+    let (secs, millis, micros): (i64, i64, i64) = stmt
+        .query((Secs(duration), Millis(duration), Micros(duration)))?
+        .one()?;
+
+    assert_eq!(3_661, secs);
+    assert_eq!(3_661_123, millis);
+    assert_eq!(3_661_123_456, micros);
+
+    let mut stmt = conn.prepare("SELECT 90, 1500, 2500000")?;
+    let (secs, millis, micros): (Secs, Millis, Micros) = stmt.query(())?.one()?;
+
+    assert_eq!(Duration::from_secs(90), secs.0);
+    assert_eq!(Duration::from_millis(1_500), millis.0);
+    assert_eq!(Duration::from_micros(2_500_000), micros.0);
+    Ok(())
+}
+
+#[test]
+fn duration_units_range() -> Result {
+    let conn = connection()?;
+
+    let mut stmt = conn.prepare("SELECT ?")?;
+    assert!(stmt.query(Millis(Duration::MAX)).is_err());
+    assert!(stmt.query(Secs(Duration::MAX)).is_err());
+
+    let mut stmt = conn.prepare("SELECT -1")?;
+    assert!(stmt.query(())?.one::<Secs>().is_err());
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use squire::{AsyncConnection, Memory};
+
+type Result<T = ()> = std::result::Result<T, Box<dyn Error>>;
+
+#[tokio::test]
+async fn execute_and_query() -> Result {
+    let connection = AsyncConnection::open(Memory).await?;
+
+    connection
+        .execute(
+            "CREATE TABLE example (id INTEGER PRIMARY KEY, name TEXT);",
+            (),
+        )
+        .await?;
+    let inserted = connection
+        .execute("INSERT INTO example (name) VALUES (?), (?);", ("a", "b"))
+        .await?;
+    assert_eq!(2, inserted);
+
+    let name: String = connection
+        .query_one("SELECT name FROM example WHERE id = ?;", 2)
+        .await?;
+    assert_eq!("b", name);
+
+    let count = connection
+        .call(|connection| -> squire::Result<i64> {
+            connection
+                .prepare("SELECT count(*) FROM example;")?
+                .query(())?
+                .one()
+        })
+        .await??;
+    assert_eq!(2, count);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dropped_call_does_not_wedge_worker() -> Result {
+    let connection = AsyncConnection::open(Memory).await?;
+
+    tokio::select! {
+        biased;
+        _ = connection.call(|_| std::thread::sleep(std::time::Duration::from_millis(20))) => {
+            panic!("call should still be running");
+        }
+        _ = async {} => {}
+    }
+
+    let value: i64 = connection.query_one("SELECT 42;", ()).await?;
+    assert_eq!(42, value);
+
+    Ok(())
+}